@@ -0,0 +1,195 @@
+//! NIP-23 long-form content: articles (kind 30023) and drafts (kind 30024).
+//!
+//! Articles are addressable, so editing one republishes the same `d`
+//! identifier; `published_at` keeps the first publication time across edits
+//! while `created_at` dates each version.
+
+use nostr::prelude::*;
+
+use crate::error::{NuruNuruError, Result};
+use crate::replaceable;
+use crate::tags::{first_tag_value, is_protected, make_tag, tag_values};
+use crate::types::{Article, ArticleInput};
+
+/// Kind of a published article.
+pub const ARTICLE_KIND: u16 = 30023;
+/// Kind of an article draft.
+pub const DRAFT_KIND: u16 = 30024;
+
+/// Build an article (or draft) from `input`.  A missing identifier is
+/// derived from the title and `now`; `published_at` defaults to `now` for
+/// articles and is omitted for drafts unless given.
+pub fn build_article(input: &ArticleInput, draft: bool, now: Timestamp) -> Result<EventBuilder> {
+    if !draft && (input.title.trim().is_empty() || input.content.trim().is_empty()) {
+        return Err(NuruNuruError::ValidationError(
+            "記事にはタイトルと本文が必要です".to_string(),
+        ));
+    }
+    let identifier = match input.identifier.as_deref().map(str::trim) {
+        Some(d) if !d.is_empty() => d.to_string(),
+        _ => new_identifier(&input.title, now),
+    };
+
+    let mut tags = vec![
+        make_tag(["d".to_string(), identifier]),
+        make_tag(["title".to_string(), input.title.trim().to_string()]),
+    ];
+    if !input.summary.trim().is_empty() {
+        tags.push(make_tag(["summary".to_string(), input.summary.trim().to_string()]));
+    }
+    if !input.image.trim().is_empty() {
+        tags.push(make_tag(["image".to_string(), input.image.trim().to_string()]));
+    }
+    let published_at = match (input.published_at, draft) {
+        (Some(at), _) => Some(at),
+        (None, false) => Some(now.as_secs()),
+        (None, true) => None,
+    };
+    if let Some(at) = published_at {
+        tags.push(make_tag(["published_at".to_string(), at.to_string()]));
+    }
+    let mut hashtags: Vec<String> = Vec::new();
+    for t in &input.hashtags {
+        let t = t.trim().trim_start_matches('#').to_lowercase();
+        if !t.is_empty() && !hashtags.contains(&t) {
+            hashtags.push(t);
+        }
+    }
+    tags.extend(hashtags.into_iter().map(|t| make_tag(["t".to_string(), t])));
+
+    let kind = if draft { DRAFT_KIND } else { ARTICLE_KIND };
+    Ok(EventBuilder::new(Kind::Custom(kind), &input.content).tags(tags))
+}
+
+/// Parse a kind-30023 / 30024 event.  `relay` is used as the `naddr` hint.
+pub fn parse_article(event: &Event, relay: Option<&str>) -> Option<Article> {
+    let draft = match event.kind.as_u16() {
+        ARTICLE_KIND => false,
+        DRAFT_KIND => true,
+        _ => return None,
+    };
+    let identifier = first_tag_value(event, "d").unwrap_or("").to_string();
+    let coordinate = Coordinate::new(event.kind, event.pubkey).identifier(identifier.clone());
+    let relays = relay.and_then(|r| RelayUrl::parse(r).ok());
+    let naddr = Nip19Coordinate::new(coordinate, relays)
+        .to_bech32()
+        .unwrap_or_default();
+    let text = |name: &str| first_tag_value(event, name).unwrap_or("").to_string();
+
+    Some(Article {
+        event_id: event.id.to_hex(),
+        pubkey: event.pubkey.to_hex(),
+        identifier,
+        title: text("title"),
+        summary: text("summary"),
+        image: text("image"),
+        published_at: first_tag_value(event, "published_at").and_then(|v| v.parse().ok()),
+        created_at: event.created_at.as_secs(),
+        content: event.content.clone(),
+        hashtags: tag_values(event, "t").filter_map(|v| v.get(1).cloned()).collect(),
+        draft,
+        naddr,
+        is_protected: is_protected(event),
+    })
+}
+
+/// Decode an `naddr` (optionally `nostr:`-prefixed) into its coordinate and
+/// relay hints.
+pub fn parse_naddr(naddr: &str) -> Result<Nip19Coordinate> {
+    let bech32 = naddr.trim().trim_start_matches("nostr:");
+    Nip19Coordinate::from_bech32(bech32)
+        .map_err(|e| NuruNuruError::ValidationError(format!("無効なnaddr: {e}")))
+}
+
+/// Newest version of each article, newest first by `published_at`
+/// (falling back to `created_at`).
+pub fn latest_versions(events: &[Event]) -> Vec<&Event> {
+    let mut latest: Vec<&Event> = replaceable::newest_per_coordinate(events).into_values().collect();
+    let sort_key = |e: &Event| {
+        first_tag_value(e, "published_at")
+            .and_then(|v| v.parse::<u64>().ok())
+            .unwrap_or(e.created_at.as_secs())
+    };
+    latest.sort_by(|a, b| sort_key(b).cmp(&sort_key(a)));
+    latest
+}
+
+/// `d` identifier for a new article: an ASCII slug of the title plus the
+/// creation time, so two articles with the same title never collide.
+fn new_identifier(title: &str, now: Timestamp) -> String {
+    let mut slug = String::new();
+    for c in title.chars() {
+        if c.is_ascii_alphanumeric() {
+            slug.push(c.to_ascii_lowercase());
+        } else if !slug.is_empty() && !slug.ends_with('-') {
+            slug.push('-');
+        }
+    }
+    let slug = slug.trim_end_matches('-');
+    if slug.is_empty() {
+        now.as_secs().to_string()
+    } else {
+        format!("{slug}-{}", now.as_secs())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_article_round_trip_and_edit_keeps_identifier() {
+        let keys = Keys::generate();
+        let input = ArticleInput {
+            title: "Hello Nostr 世界".into(),
+            summary: "要約".into(),
+            content: "# 本文".into(),
+            hashtags: vec!["#Nostr".into(), "nostr".into()],
+            ..Default::default()
+        };
+        let event = build_article(&input, false, Timestamp::from(1_000))
+            .unwrap()
+            .sign_with_keys(&keys)
+            .unwrap();
+        let article = parse_article(&event, Some("wss://relay.example")).unwrap();
+        assert_eq!(article.identifier, "hello-nostr-1000");
+        assert_eq!(article.published_at, Some(1_000));
+        assert_eq!(article.hashtags, vec!["nostr".to_string()]);
+        assert!(!article.draft);
+
+        let decoded = parse_naddr(&format!("nostr:{}", article.naddr)).unwrap();
+        assert_eq!(decoded.coordinate.identifier, "hello-nostr-1000");
+        assert_eq!(decoded.coordinate.public_key, keys.public_key());
+
+        let edit = ArticleInput {
+            identifier: Some(article.identifier.clone()),
+            published_at: article.published_at,
+            ..input
+        };
+        let edited = build_article(&edit, false, Timestamp::from(2_000))
+            .unwrap()
+            .custom_created_at(Timestamp::from(2_000))
+            .sign_with_keys(&keys)
+            .unwrap();
+        let latest = latest_versions(&[event, edited.clone()]);
+        assert_eq!(latest.len(), 1);
+        assert_eq!(latest[0].id, edited.id);
+        assert_eq!(parse_article(&edited, None).unwrap().published_at, Some(1_000));
+    }
+
+    #[test]
+    fn test_draft_allows_empty_title() {
+        let input = ArticleInput {
+            content: "メモ".into(),
+            ..Default::default()
+        };
+        assert!(build_article(&input, false, Timestamp::from(1)).is_err());
+        let draft = build_article(&input, true, Timestamp::from(1))
+            .unwrap()
+            .sign_with_keys(&Keys::generate())
+            .unwrap();
+        let article = parse_article(&draft, None).unwrap();
+        assert!(article.draft);
+        assert_eq!(article.published_at, None);
+    }
+}
@@ -0,0 +1,181 @@
+//! Backups of the user's own events as JSONL (one signed event per line,
+//! oldest first).
+//!
+//! Export pages backwards through each source with `until` so relays that
+//! cap result sizes still return everything.  A page shorter than asked for
+//! proves nothing (relays cap below `PAGE_SIZE`), so paging only stops on an
+//! empty page or one with nothing new.  Import accepts JSONL or the
+//! JSON array written by the JS `exportEventsToJson`, and only keeps events
+//! whose ID and signature verify.
+
+use std::collections::{HashMap, HashSet};
+
+use nostr::prelude::*;
+
+use crate::tags;
+use crate::types::BackupImportError;
+
+/// Events requested per page while exporting.
+pub const PAGE_SIZE: usize = 500;
+
+/// `until` of the page after `page` (fetched with `until`), or `None` once
+/// the source is exhausted: the page is empty, holds nothing not already in
+/// `seen` (this source's events so far, updated here) or reaches `since`.
+///
+/// The next page starts at the oldest second of this one, not before it,
+/// so events sharing that second beyond a relay's cap are not skipped.
+pub fn next_until(
+    page: &[Event],
+    seen: &mut HashSet<EventId>,
+    until: Timestamp,
+    since: Option<Timestamp>,
+) -> Option<Timestamp> {
+    let mut new = 0;
+    for event in page {
+        if seen.insert(event.id) {
+            new += 1;
+        }
+    }
+    let oldest = page.iter().map(|e| e.created_at).min()?;
+    if new == 0 || oldest > until || since.is_some_and(|s| oldest <= s) {
+        return None;
+    }
+    Some(oldest)
+}
+
+/// Merge `page` into `events`.  Returns how many were new.
+pub fn merge(events: &mut HashMap<EventId, Event>, page: impl IntoIterator<Item = Event>) -> usize {
+    let before = events.len();
+    for event in page {
+        events.entry(event.id).or_insert(event);
+    }
+    events.len() - before
+}
+
+/// Serialise `events` as JSONL, oldest first.
+pub fn to_jsonl<'a>(events: impl IntoIterator<Item = &'a Event>) -> String {
+    let mut sorted: Vec<&Event> = events.into_iter().collect();
+    sorted.sort_by_key(|e| (e.created_at, e.id));
+    let mut out = String::new();
+    for event in sorted {
+        out.push_str(&event.as_json());
+        out.push('\n');
+    }
+    out
+}
+
+/// Parse a backup (JSONL or a JSON array) into the events that verify,
+/// each with the 1-based line (or array position) it came from.  Everything
+/// else is reported as an error.
+pub fn parse(data: &str) -> (Vec<(u32, Event)>, Vec<BackupImportError>) {
+    let entries: Vec<(usize, Result<Event, String>)> = if data.trim_start().starts_with('[') {
+        match serde_json::from_str::<Vec<serde_json::Value>>(data) {
+            Ok(values) => values
+                .into_iter()
+                .enumerate()
+                .map(|(i, v)| (i + 1, serde_json::from_value(v).map_err(|e| e.to_string())))
+                .collect(),
+            Err(e) => vec![(1, Err(e.to_string()))],
+        }
+    } else {
+        data.lines()
+            .enumerate()
+            .filter(|(_, line)| !line.trim().is_empty())
+            .map(|(i, line)| (i + 1, Event::from_json(line.trim()).map_err(|e| e.to_string())))
+            .collect()
+    };
+
+    let mut events = Vec::new();
+    let mut errors = Vec::new();
+    for (line, entry) in entries {
+        match entry.and_then(|e| e.verify().map(|_| e).map_err(|err| err.to_string())) {
+            Ok(event) => events.push((line as u32, event)),
+            Err(error) => errors.push(BackupImportError {
+                line: line as u32,
+                event_id: None,
+                error,
+            }),
+        }
+    }
+    (events, errors)
+}
+
+/// Why `event` may not be rebroadcast by `me`: NIP-70 protected events
+/// only go out from their own author.
+pub fn rebroadcast_blocker(event: &Event, me: Option<&PublicKey>) -> Option<&'static str> {
+    if tags::is_protected(event) && me != Some(&event.pubkey) {
+        Some("他のユーザーの保護されたイベントです")
+    } else {
+        None
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_jsonl_round_trip_rejects_tampered_events() {
+        let keys = Keys::generate();
+        let old = EventBuilder::text_note("old")
+            .custom_created_at(Timestamp::from(100))
+            .sign_with_keys(&keys)
+            .unwrap();
+        let new = EventBuilder::text_note("new")
+            .custom_created_at(Timestamp::from(200))
+            .sign_with_keys(&keys)
+            .unwrap();
+
+        let jsonl = to_jsonl([&new, &old]);
+        assert!(jsonl.starts_with(&old.as_json()));
+        let tampered = new.as_json().replace("\"new\"", "\"edited\"");
+        let data = format!("{jsonl}\n{tampered}\nnot json\n");
+
+        let (events, errors) = parse(&data);
+        assert_eq!(events.iter().map(|(_, e)| e.id).collect::<Vec<_>>(), vec![old.id, new.id]);
+        assert_eq!(errors.iter().map(|e| e.line).collect::<Vec<_>>(), vec![4, 5]);
+
+        let array = serde_json::to_string(&vec![&old, &new]).unwrap();
+        assert_eq!(parse(&array).0.len(), 2);
+    }
+
+    #[test]
+    fn test_next_until_pages_past_short_pages() {
+        let keys = Keys::generate();
+        let events: Vec<Event> = (0..30u64)
+            .map(|i| {
+                EventBuilder::text_note(i.to_string())
+                    .custom_created_at(Timestamp::from(1000 + i / 2))
+                    .sign_with_keys(&keys)
+                    .unwrap()
+            })
+            .collect();
+        // A relay capping results at 10, far below `PAGE_SIZE`.
+        let fetch = |until: Timestamp| {
+            let mut page: Vec<Event> = events.iter().filter(|e| e.created_at <= until).cloned().collect();
+            page.sort_by(|a, b| b.created_at.cmp(&a.created_at));
+            page.truncate(10);
+            page
+        };
+
+        let mut seen = HashSet::new();
+        let mut until = Timestamp::from(5000);
+        let mut pages = 0;
+        loop {
+            pages += 1;
+            match next_until(&fetch(until), &mut seen, until, None) {
+                Some(next) => until = next,
+                None => break,
+            }
+        }
+        assert_eq!(seen.len(), events.len());
+        assert!(pages > 3);
+
+        let (mut seen, start) = (HashSet::new(), Timestamp::from(5000));
+        assert_eq!(next_until(&[], &mut seen, start, None), None);
+        let page = fetch(start);
+        assert_eq!(next_until(&page, &mut seen, start, Some(Timestamp::from(1010))), None);
+        // Only events this source already returned: exhausted.
+        assert_eq!(next_until(&page, &mut seen, start, None), None);
+    }
+}
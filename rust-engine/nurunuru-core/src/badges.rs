@@ -0,0 +1,286 @@
+//! NIP-58 badges: definitions (kind 30009), awards (kind 8) and the
+//! profile badges list (kind 30008, `d` = `profile_badges`).
+//!
+//! A profile badge is a consecutive `a` (definition) / `e` (award) tag pair.
+//! It only counts if the award exists, was issued by the definition's
+//! author and names the profile owner; anything else is dropped.
+
+use std::collections::{HashMap, HashSet};
+
+use nostr::prelude::*;
+
+use crate::error::{NuruNuruError, Result};
+use crate::replaceable;
+use crate::tags::{coordinate_of, first_tag_value, make_tag, tag_values};
+use crate::types::{Badge, BadgeDefinition, BadgeDefinitionInput, BadgeThumbnail};
+
+/// Kind of the profile badges list.
+pub const PROFILE_BADGES_KIND: u16 = 30008;
+/// Kind of a badge definition.
+pub const BADGE_DEFINITION_KIND: u16 = 30009;
+/// Kind of a badge award.
+pub const BADGE_AWARD_KIND: u16 = 8;
+/// `d` tag of the profile badges list.
+pub const PROFILE_BADGES_ID: &str = "profile_badges";
+
+/// `(definition coordinate, award event ID)` pairs of a profile badges
+/// list, in display order.  Unpaired `a` tags and repeats are skipped.
+pub fn profile_entries(event: &Event) -> Vec<(String, String)> {
+    let tags: Vec<&[String]> = event.tags.iter().map(|t| t.as_slice()).collect();
+    let mut seen = HashSet::new();
+    let mut entries = Vec::new();
+    for pair in tags.windows(2) {
+        let (a, e) = (pair[0], pair[1]);
+        if a.first().map(String::as_str) != Some("a") || e.first().map(String::as_str) != Some("e") {
+            continue;
+        }
+        let (Some(coordinate), Some(award)) = (a.get(1), e.get(1)) else { continue };
+        if coordinate.starts_with("30009:") && seen.insert(coordinate.clone()) {
+            entries.push((coordinate.clone(), award.clone()));
+        }
+    }
+    entries
+}
+
+/// Tags of a profile badges list showing `entries` in order.
+pub fn profile_badges_tags(entries: &[(String, String)]) -> Vec<Tag> {
+    let mut tags = vec![make_tag(["d", PROFILE_BADGES_ID])];
+    for (coordinate, award) in entries {
+        tags.push(make_tag(["a", coordinate.as_str()]));
+        tags.push(make_tag(["e", award.as_str()]));
+    }
+    tags
+}
+
+/// Tags of `list` (the current profile badges list, if any) with the
+/// `coordinate` / `award` pair appended.  Every existing tag, including
+/// pairs that no longer resolve and relay hints, is kept as is.  `None`
+/// if `coordinate` is already shown.
+pub fn append_entry(list: Option<&Event>, coordinate: &str, award: &str) -> Option<Vec<Tag>> {
+    if list.is_some_and(|l| profile_entries(l).iter().any(|(c, _)| c == coordinate)) {
+        return None;
+    }
+    let mut tags: Vec<Tag> = match list {
+        Some(l) => l.tags.iter().cloned().collect(),
+        None => vec![make_tag(["d", PROFILE_BADGES_ID])],
+    };
+    tags.push(make_tag(["a", coordinate]));
+    tags.push(make_tag(["e", award]));
+    Some(tags)
+}
+
+/// Parse a kind-30009 badge definition.
+pub fn parse_definition(event: &Event) -> Option<BadgeDefinition> {
+    if event.kind.as_u16() != BADGE_DEFINITION_KIND {
+        return None;
+    }
+    let identifier = first_tag_value(event, "d").unwrap_or("").to_string();
+    let image = tag_values(event, "image").next();
+    Some(BadgeDefinition {
+        event_id: event.id.to_hex(),
+        coordinate: coordinate_of(event)?,
+        issuer: event.pubkey.to_hex(),
+        name: first_tag_value(event, "name").unwrap_or(&identifier).to_string(),
+        identifier,
+        description: first_tag_value(event, "description").unwrap_or("").to_string(),
+        image: image.and_then(|v| v.get(1).cloned()),
+        image_dimensions: image.and_then(|v| v.get(2).cloned()),
+        thumbnails: tag_values(event, "thumb")
+            .filter_map(|v| {
+                Some(BadgeThumbnail {
+                    url: v.get(1)?.clone(),
+                    dimensions: v.get(2).cloned(),
+                })
+            })
+            .collect(),
+    })
+}
+
+impl BadgeDefinition {
+    /// Smallest thumbnail at least `min_px` wide (by its `WxH` dimensions),
+    /// falling back to the largest thumbnail, then the full image.
+    pub fn thumbnail(&self, min_px: u32) -> Option<&str> {
+        let width = |t: &BadgeThumbnail| {
+            t.dimensions
+                .as_deref()
+                .and_then(|d| d.split('x').next())
+                .and_then(|w| w.parse::<u32>().ok())
+                .unwrap_or(0)
+        };
+        self.thumbnails
+            .iter()
+            .filter(|t| width(t) >= min_px)
+            .min_by_key(|t| width(t))
+            .or_else(|| self.thumbnails.iter().max_by_key(|t| width(t)))
+            .map(|t| t.url.as_str())
+            .or(self.image.as_deref())
+    }
+}
+
+/// `true` if `award` is a kind-8 award of the definition at `coordinate`,
+/// issued by the definition's author, to `recipient`.
+pub fn is_valid_award(award: &Event, coordinate: &str, recipient: &PublicKey) -> bool {
+    let issuer = coordinate.split(':').nth(1);
+    award.kind.as_u16() == BADGE_AWARD_KIND
+        && issuer == Some(award.pubkey.to_hex().as_str())
+        && tag_values(award, "a").any(|v| v.get(1).is_some_and(|c| c == coordinate))
+        && tag_values(award, "p").any(|v| v.get(1).is_some_and(|p| *p == recipient.to_hex()))
+}
+
+/// Resolve `entries` against fetched `definitions` and `awards`, keeping
+/// only verified badges of `recipient`, in `entries` order.
+pub fn resolve(
+    entries: &[(String, String)],
+    definitions: &[Event],
+    awards: &[Event],
+    recipient: &PublicKey,
+    on_profile: bool,
+) -> Vec<Badge> {
+    let newest = replaceable::newest_per_coordinate(definitions);
+    let awards: HashMap<String, &Event> = awards.iter().map(|e| (e.id.to_hex(), e)).collect();
+
+    entries
+        .iter()
+        .filter_map(|(coordinate, award_id)| {
+            let award = awards.get(award_id)?;
+            if !is_valid_award(award, coordinate, recipient) {
+                return None;
+            }
+            Some(Badge {
+                definition: parse_definition(newest.get(coordinate)?)?,
+                award_event_id: award_id.clone(),
+                awarded_at: award.created_at.as_secs(),
+                on_profile,
+            })
+        })
+        .collect()
+}
+
+/// Kind-30009 definition from `input`.
+pub fn build_definition(input: &BadgeDefinitionInput) -> Result<EventBuilder> {
+    let identifier = input.identifier.trim();
+    if identifier.is_empty() || input.name.trim().is_empty() {
+        return Err(NuruNuruError::ValidationError(
+            "バッジには識別子と名前が必要です".to_string(),
+        ));
+    }
+    let mut tags = vec![
+        make_tag(["d", identifier]),
+        make_tag(["name", input.name.trim()]),
+    ];
+    if !input.description.trim().is_empty() {
+        tags.push(make_tag(["description", input.description.trim()]));
+    }
+    if let Some(image) = input.image.as_deref().filter(|i| !i.is_empty()) {
+        let mut parts = vec!["image".to_string(), image.to_string()];
+        parts.extend(input.image_dimensions.clone());
+        tags.push(make_tag(parts));
+    }
+    for thumb in &input.thumbnails {
+        let mut parts = vec!["thumb".to_string(), thumb.url.clone()];
+        parts.extend(thumb.dimensions.clone());
+        tags.push(make_tag(parts));
+    }
+    Ok(EventBuilder::new(Kind::from(BADGE_DEFINITION_KIND), "").tags(tags))
+}
+
+/// Kind-8 award of the definition at `coordinate` to `recipients`.  Only
+/// the definition's author (`issuer`) may award it.
+pub fn build_award(coordinate: &str, issuer: &PublicKey, recipients: &[PublicKey]) -> Result<EventBuilder> {
+    let parsed = Coordinate::parse(coordinate)
+        .map_err(|e| NuruNuruError::ValidationError(format!("無効なバッジアドレス: {e}")))?;
+    if parsed.kind.as_u16() != BADGE_DEFINITION_KIND {
+        return Err(NuruNuruError::ValidationError(format!(
+            "バッジ定義ではありません (kind {})",
+            parsed.kind.as_u16()
+        )));
+    }
+    if parsed.public_key != *issuer {
+        return Err(NuruNuruError::ValidationError(
+            "自分が作成したバッジのみ授与できます".to_string(),
+        ));
+    }
+    if recipients.is_empty() {
+        return Err(NuruNuruError::ValidationError("授与先がありません".to_string()));
+    }
+    let mut tags = vec![make_tag(["a", coordinate])];
+    tags.extend(recipients.iter().map(|pk| make_tag(["p".to_string(), pk.to_hex()])));
+    Ok(EventBuilder::new(Kind::from(BADGE_AWARD_KIND), "").tags(tags))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn definition(issuer: &Keys) -> Event {
+        let input = BadgeDefinitionInput {
+            identifier: "early".into(),
+            name: "Early Bird".into(),
+            image: Some("https://b.example/early.png".into()),
+            image_dimensions: Some("1024x1024".into()),
+            thumbnails: vec![
+                BadgeThumbnail { url: "https://b.example/256.png".into(), dimensions: Some("256x256".into()) },
+                BadgeThumbnail { url: "https://b.example/64.png".into(), dimensions: Some("64x64".into()) },
+            ],
+            ..Default::default()
+        };
+        build_definition(&input).unwrap().sign_with_keys(issuer).unwrap()
+    }
+
+    #[test]
+    fn test_only_awards_from_definition_author_resolve() {
+        let (issuer, forger, owner) = (Keys::generate(), Keys::generate(), Keys::generate());
+        let def = definition(&issuer);
+        let coordinate = format!("30009:{}:early", issuer.public_key());
+
+        let award = build_award(&coordinate, &issuer.public_key(), &[owner.public_key()])
+            .unwrap()
+            .sign_with_keys(&issuer)
+            .unwrap();
+        assert!(build_award(&coordinate, &forger.public_key(), &[owner.public_key()]).is_err());
+        let forged = EventBuilder::new(Kind::from(BADGE_AWARD_KIND), "")
+            .tags([make_tag(["a", coordinate.as_str()]), make_tag(["p".to_string(), owner.public_key().to_hex()])])
+            .sign_with_keys(&forger)
+            .unwrap();
+
+        let list = EventBuilder::new(Kind::from(PROFILE_BADGES_KIND), "")
+            .tags(profile_badges_tags(&[
+                (coordinate.clone(), forged.id.to_hex()),
+                (coordinate.clone(), award.id.to_hex()),
+            ]))
+            .sign_with_keys(&owner)
+            .unwrap();
+        // The repeated coordinate is dropped, leaving only the forged pair.
+        let entries = profile_entries(&list);
+        assert_eq!(entries.len(), 1);
+        assert!(resolve(&entries, &[def.clone()], &[forged], &owner.public_key(), true).is_empty());
+
+        let entries = vec![(coordinate, award.id.to_hex())];
+        let badges = resolve(&entries, &[def], &[award], &owner.public_key(), true);
+        assert_eq!(badges.len(), 1);
+        assert_eq!(badges[0].definition.name, "Early Bird");
+        assert_eq!(badges[0].definition.thumbnail(48), Some("https://b.example/64.png"));
+        assert_eq!(badges[0].definition.thumbnail(512), Some("https://b.example/256.png"));
+    }
+
+    #[test]
+    fn test_append_entry_keeps_unresolved_pairs_and_hints() {
+        let owner = Keys::generate();
+        let list = EventBuilder::new(Kind::from(PROFILE_BADGES_KIND), "")
+            .tags([
+                make_tag(["d", PROFILE_BADGES_ID]),
+                make_tag(["a", "30009:aa:gone", "wss://r.example"]),
+                make_tag(["e", "11", "wss://r.example"]),
+            ])
+            .sign_with_keys(&owner)
+            .unwrap();
+
+        let tags = append_entry(Some(&list), "30009:bb:new", "22").unwrap();
+        let slices: Vec<&[String]> = tags.iter().map(|t| t.as_slice()).collect();
+        assert_eq!(slices.len(), 5);
+        assert_eq!(slices[1], ["a", "30009:aa:gone", "wss://r.example"]);
+        assert_eq!(slices[3], ["a", "30009:bb:new"]);
+        assert!(append_entry(Some(&list), "30009:aa:gone", "33").is_none());
+        assert_eq!(append_entry(None, "30009:bb:new", "22").unwrap().len(), 3);
+    }
+}
@@ -0,0 +1,258 @@
+//! NIP-51 bookmarks: the default list (kind 10003) and named bookmark sets
+//! (kind 30003).
+//!
+//! Like the mute list, public entries are tags and private entries are an
+//! encrypted tag array in `content`; the engine handles encryption and
+//! publishing, this module only maps between tags and `BookmarkList`.
+
+use std::collections::HashSet;
+
+use nostr::prelude::*;
+
+use crate::types::{BookmarkEntries, BookmarkKind, BookmarkList};
+
+/// Kind of the default bookmark list.
+pub const BOOKMARK_LIST_KIND: u16 = 10003;
+/// Kind of a named bookmark set.
+pub const BOOKMARK_SET_KIND: u16 = 30003;
+
+/// Set metadata tags that are not bookmark entries.
+const SET_METADATA_TAGS: &[&str] = &["d", "title", "description", "image"];
+
+impl BookmarkEntries {
+    /// Parse entries from raw tags, skipping bookmark-set metadata.
+    pub fn from_tags<'a>(tags: impl IntoIterator<Item = &'a [String]>) -> Self {
+        let mut entries = Self::default();
+        for tag in tags {
+            let Some(name) = tag.first() else { continue };
+            if SET_METADATA_TAGS.contains(&name.as_str()) {
+                continue;
+            }
+            entries.source_tags.push(tag.to_vec());
+            match entry_of(tag) {
+                Some((kind, value)) => {
+                    entries.insert(kind, &value);
+                }
+                None => entries.other.push(tag.to_vec()),
+            }
+        }
+        entries
+    }
+
+    /// Raw tags for these entries.  Entries still present keep their
+    /// original tag (relay hints, extra fields) and position; new entries
+    /// follow in `e`, `a`, `t`, `r` order, and unrecognised tags are kept
+    /// verbatim.
+    pub fn to_tags(&self) -> Vec<Vec<String>> {
+        let mut emitted = HashSet::new();
+        let mut tags = Vec::new();
+        for tag in &self.source_tags {
+            match entry_of(tag) {
+                Some((kind, value)) => {
+                    if self.values(kind).contains(&value) && emitted.insert((kind, value)) {
+                        tags.push(tag.clone());
+                    }
+                }
+                None if self.other.contains(tag) => tags.push(tag.clone()),
+                None => {}
+            }
+        }
+        for (name, kind) in [
+            ("e", BookmarkKind::Event),
+            ("a", BookmarkKind::Address),
+            ("t", BookmarkKind::Hashtag),
+            ("r", BookmarkKind::Url),
+        ] {
+            for value in self.values(kind) {
+                if emitted.insert((kind, value.clone())) {
+                    tags.push(vec![name.to_string(), value.clone()]);
+                }
+            }
+        }
+        tags.extend(self.other.iter().filter(|t| !self.source_tags.contains(t)).cloned());
+        tags
+    }
+
+    /// Add an entry.  Returns `false` if it was already present or empty.
+    pub fn insert(&mut self, kind: BookmarkKind, value: &str) -> bool {
+        let value = normalize(kind, value);
+        let values = self.values_mut(kind);
+        if value.is_empty() || values.contains(&value) {
+            return false;
+        }
+        values.push(value);
+        true
+    }
+
+    /// Remove an entry.  Returns `false` if it was not present.
+    pub fn remove(&mut self, kind: BookmarkKind, value: &str) -> bool {
+        let value = normalize(kind, value);
+        let values = self.values_mut(kind);
+        let before = values.len();
+        values.retain(|v| *v != value);
+        values.len() != before
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.event_ids.is_empty()
+            && self.addresses.is_empty()
+            && self.hashtags.is_empty()
+            && self.urls.is_empty()
+            && self.other.is_empty()
+    }
+
+    fn values(&self, kind: BookmarkKind) -> &Vec<String> {
+        match kind {
+            BookmarkKind::Event => &self.event_ids,
+            BookmarkKind::Address => &self.addresses,
+            BookmarkKind::Hashtag => &self.hashtags,
+            BookmarkKind::Url => &self.urls,
+        }
+    }
+
+    fn values_mut(&mut self, kind: BookmarkKind) -> &mut Vec<String> {
+        match kind {
+            BookmarkKind::Event => &mut self.event_ids,
+            BookmarkKind::Address => &mut self.addresses,
+            BookmarkKind::Hashtag => &mut self.hashtags,
+            BookmarkKind::Url => &mut self.urls,
+        }
+    }
+}
+
+/// The entry a tag stands for, normalised; `None` for unrecognised tags.
+fn entry_of(tag: &[String]) -> Option<(BookmarkKind, String)> {
+    let kind = match tag.first()?.as_str() {
+        "e" => BookmarkKind::Event,
+        "a" => BookmarkKind::Address,
+        "t" => BookmarkKind::Hashtag,
+        "r" => BookmarkKind::Url,
+        _ => return None,
+    };
+    Some((kind, normalize(kind, tag.get(1)?)))
+}
+
+fn normalize(kind: BookmarkKind, value: &str) -> String {
+    let value = value.trim();
+    match kind {
+        BookmarkKind::Hashtag => value.trim_start_matches('#').to_lowercase(),
+        _ => value.to_string(),
+    }
+}
+
+impl BookmarkList {
+    /// Event kind this list is published as.
+    pub fn kind(&self) -> u16 {
+        if self.identifier.is_some() {
+            BOOKMARK_SET_KIND
+        } else {
+            BOOKMARK_LIST_KIND
+        }
+    }
+
+    /// Public list from a kind-10003 / 30003 event (private entries are
+    /// filled in by the engine after decryption).
+    pub fn from_event(event: &Event) -> Self {
+        let meta = |name: &str| {
+            event
+                .tags
+                .iter()
+                .map(|t| t.as_slice())
+                .find(|v| v.first().is_some_and(|n| n == name))
+                .and_then(|v| v.get(1).cloned())
+        };
+        Self {
+            identifier: (event.kind.as_u16() == BOOKMARK_SET_KIND)
+                .then(|| meta("d").unwrap_or_default()),
+            title: meta("title"),
+            description: meta("description"),
+            image: meta("image"),
+            public: BookmarkEntries::from_tags(event.tags.iter().map(|t| t.as_slice())),
+            event_id: Some(event.id.to_hex()),
+            created_at: event.created_at.as_secs(),
+            ..Default::default()
+        }
+    }
+
+    /// Public tags of the event to publish: set metadata then entries.
+    pub fn public_tags(&self) -> Vec<Vec<String>> {
+        let mut tags = Vec::new();
+        if let Some(d) = &self.identifier {
+            tags.push(vec!["d".to_string(), d.clone()]);
+            for (name, value) in [
+                ("title", &self.title),
+                ("description", &self.description),
+                ("image", &self.image),
+            ] {
+                if let Some(v) = value {
+                    tags.push(vec![name.to_string(), v.clone()]);
+                }
+            }
+        }
+        tags.extend(self.public.to_tags());
+        tags
+    }
+
+    /// `true` if `event_id` is bookmarked in either section.
+    pub fn contains_event(&self, event_id: &str) -> bool {
+        self.public.event_ids.iter().any(|id| id == event_id)
+            || self.private.event_ids.iter().any(|id| id == event_id)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::tags::make_tag;
+
+    #[test]
+    fn test_set_round_trip_keeps_metadata() {
+        let keys = Keys::generate();
+        let id = EventBuilder::text_note("x").sign_with_keys(&keys).unwrap().id.to_hex();
+        let event = EventBuilder::new(Kind::Custom(BOOKMARK_SET_KIND), "")
+            .tags([
+                make_tag(["d", "reading"]),
+                make_tag(["title", "あとで読む"]),
+                make_tag(["e", id.as_str(), "wss://relay.example"]),
+                make_tag(["t", "Rust"]),
+            ])
+            .sign_with_keys(&keys)
+            .unwrap();
+
+        let list = BookmarkList::from_event(&event);
+        assert_eq!(list.identifier.as_deref(), Some("reading"));
+        assert_eq!(list.kind(), BOOKMARK_SET_KIND);
+        assert_eq!(list.public.hashtags, vec!["rust"]);
+        assert!(list.contains_event(&id));
+        assert_eq!(
+            list.public_tags()[..3],
+            [
+                vec!["d".to_string(), "reading".to_string()],
+                vec!["title".to_string(), "あとで読む".to_string()],
+                vec!["e".to_string(), id.clone(), "wss://relay.example".to_string()],
+            ]
+        );
+
+        // Removing one entry leaves the others' original tags untouched.
+        let mut list = list;
+        assert!(list.public.remove(BookmarkKind::Hashtag, "rust"));
+        assert!(list.public.insert(BookmarkKind::Url, "https://example.com"));
+        assert_eq!(
+            list.public.to_tags(),
+            vec![
+                vec!["e".to_string(), id, "wss://relay.example".to_string()],
+                vec!["r".to_string(), "https://example.com".to_string()],
+            ]
+        );
+    }
+
+    #[test]
+    fn test_default_list_insert_remove() {
+        let mut list = BookmarkList::default();
+        assert_eq!(list.kind(), BOOKMARK_LIST_KIND);
+        assert!(list.private.insert(BookmarkKind::Url, " https://example.com "));
+        assert!(!list.private.insert(BookmarkKind::Url, "https://example.com"));
+        assert!(list.private.remove(BookmarkKind::Url, "https://example.com"));
+        assert!(list.public_tags().is_empty());
+    }
+}
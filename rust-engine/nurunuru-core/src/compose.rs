@@ -0,0 +1,283 @@
+//! Reply (NIP-10), quote / repost (NIP-18) and reaction (NIP-25) composers.
+//!
+//! Builds the `e` / `p` / `q` / `a` / `k` tags clients previously assembled
+//! by hand for `create_unsigned_note_with_tags`.  The builders are pure; the
+//! engine adds relay hints from nostrdb and either signs or returns the
+//! unsigned event.
+
+use std::collections::HashSet;
+
+use nostr::prelude::*;
+
+use crate::error::{NuruNuruError, Result};
+use crate::tags::{coordinate_of, is_shortcode_char, make_tag, tag_values};
+use crate::thread;
+
+/// Tags for a kind-1 reply to `parent` (NIP-10 marked form).
+///
+/// - `root` / `reply` markers: a reply to a top-level note carries only a
+///   `root` marker; deeper replies keep the parent's root and add `reply`.
+/// - `p` tags: the parent's author plus every participant already tagged in
+///   the parent, keeping their relay hints; `author` (the replier) is omitted.
+pub fn reply_tags(parent: &Event, parent_relay: Option<&str>, author: Option<&PublicKey>) -> Vec<Tag> {
+    let parent_id = parent.id.to_hex();
+    let parent_pk = parent.pubkey.to_hex();
+    let relay = parent_relay.unwrap_or("").to_string();
+
+    let mut tags = Vec::new();
+    match thread::thread_refs(parent).root {
+        Some(root) if root.id != parent_id => {
+            tags.push(make_tag([
+                "e".to_string(),
+                root.id,
+                root.relay.unwrap_or_default(),
+                "root".to_string(),
+                root.author.unwrap_or_default(),
+            ]));
+            tags.push(make_tag([
+                "e".to_string(),
+                parent_id,
+                relay,
+                "reply".to_string(),
+                parent_pk.clone(),
+            ]));
+        }
+        _ => {
+            tags.push(make_tag([
+                "e".to_string(),
+                parent_id,
+                relay,
+                "root".to_string(),
+                parent_pk.clone(),
+            ]));
+        }
+    }
+
+    let author_hex = author.map(|pk| pk.to_hex());
+    let mut seen: HashSet<String> = HashSet::new();
+    if let Some(me) = &author_hex {
+        seen.insert(me.clone());
+    }
+
+    if seen.insert(parent_pk.clone()) {
+        tags.push(make_tag(["p".to_string(), parent_pk]));
+    }
+    for p in tag_values(parent, "p") {
+        let Some(pk) = p.get(1) else { continue };
+        if PublicKey::from_hex(pk).is_err() || !seen.insert(pk.clone()) {
+            continue;
+        }
+        match p.get(2).filter(|r| !r.is_empty()) {
+            Some(hint) => tags.push(make_tag(["p".to_string(), pk.clone(), hint.clone()])),
+            None => tags.push(make_tag(["p".to_string(), pk.clone()])),
+        }
+    }
+
+    tags
+}
+
+/// Kind-1 reply to `parent`.
+pub fn build_reply(
+    parent: &Event,
+    content: &str,
+    parent_relay: Option<&str>,
+    author: Option<&PublicKey>,
+) -> EventBuilder {
+    EventBuilder::text_note(content).tags(reply_tags(parent, parent_relay, author))
+}
+
+/// `nostr:nevent1…` reference for `target`, including author, kind and
+/// relay hints.
+pub fn nevent_uri(target: &Event, relays: &[String]) -> Result<String> {
+    let relays: Vec<RelayUrl> = relays.iter().filter_map(|r| RelayUrl::parse(r).ok()).collect();
+    let nevent = Nip19Event {
+        event_id: target.id,
+        author: Some(target.pubkey),
+        kind: Some(target.kind),
+        relays,
+    };
+    let bech32 = nevent
+        .to_bech32()
+        .map_err(|e| NuruNuruError::NostrProtocol(e.to_string()))?;
+    Ok(format!("nostr:{bech32}"))
+}
+
+/// Kind-1 quote of `target` (NIP-18): appends a `nostr:nevent` reference to
+/// the content and adds `q` (with relay hint and author) and `p` tags.
+pub fn build_quote(target: &Event, content: &str, target_relay: Option<&str>) -> Result<EventBuilder> {
+    let relays: Vec<String> = target_relay.map(|r| vec![r.to_string()]).unwrap_or_default();
+    let reference = nevent_uri(target, &relays)?;
+    let content = if content.trim().is_empty() {
+        reference
+    } else {
+        format!("{}\n{reference}", content.trim_end())
+    };
+
+    let target_pk = target.pubkey.to_hex();
+    let tags = vec![
+        make_tag([
+            "q".to_string(),
+            target.id.to_hex(),
+            target_relay.unwrap_or("").to_string(),
+            target_pk.clone(),
+        ]),
+        make_tag(["p".to_string(), target_pk]),
+    ];
+
+    Ok(EventBuilder::text_note(content).tags(tags))
+}
+
+/// `[name, value]` plus the relay hint when there is one.
+fn hinted_tag(name: &str, value: String, relay: Option<&str>) -> Tag {
+    match relay.filter(|r| !r.is_empty()) {
+        Some(r) => make_tag([name.to_string(), value, r.to_string()]),
+        None => make_tag([name.to_string(), value]),
+    }
+}
+
+/// `shortcode` if `reaction` is a NIP-30 `:shortcode:` reaction.
+pub fn emoji_shortcode(reaction: &str) -> Option<&str> {
+    let code = reaction.strip_prefix(':')?.strip_suffix(':')?;
+    (!code.is_empty() && code.chars().all(is_shortcode_char)).then_some(code)
+}
+
+/// Kind-7 reaction to `target` of any kind (NIP-25).
+///
+/// Tags: `e` (relay hint, author), `p`, `a` for addressable targets and `k`
+/// with the target's real kind.  A `:shortcode:` reaction needs `emoji_url`
+/// and gets a NIP-30 `emoji` tag.  An empty reaction is a like (`+`).
+pub fn build_reaction(
+    target: &Event,
+    reaction: &str,
+    emoji_url: Option<&str>,
+    target_relay: Option<&str>,
+) -> Result<EventBuilder> {
+    let reaction = match reaction.trim() {
+        "" => "+",
+        r => r,
+    };
+    let target_pk = target.pubkey.to_hex();
+
+    let mut tags = vec![
+        make_tag([
+            "e".to_string(),
+            target.id.to_hex(),
+            target_relay.unwrap_or("").to_string(),
+            target_pk.clone(),
+        ]),
+        hinted_tag("p", target_pk, target_relay),
+    ];
+    if let Some(coordinate) = coordinate_of(target) {
+        tags.push(hinted_tag("a", coordinate, target_relay));
+    }
+    tags.push(make_tag(["k".to_string(), target.kind.as_u16().to_string()]));
+
+    if let Some(shortcode) = emoji_shortcode(reaction) {
+        let url = emoji_url
+            .filter(|u| !u.is_empty())
+            .ok_or_else(|| NuruNuruError::ValidationError(format!("カスタム絵文字のURLがありません: {reaction}")))?;
+        tags.push(make_tag(["emoji", shortcode, url]));
+    }
+
+    Ok(EventBuilder::new(Kind::Reaction, reaction).tags(tags))
+}
+
+/// Repost of `target` (NIP-18): kind 6 for text notes, generic kind 16 (with
+/// `k` and, for addressable targets, `a`) for everything else.  The content
+/// is the reposted event's JSON.
+pub fn build_repost(target: &Event, target_relay: Option<&str>) -> EventBuilder {
+    let target_pk = target.pubkey.to_hex();
+    let mut tags = vec![
+        make_tag([
+            "e".to_string(),
+            target.id.to_hex(),
+            target_relay.unwrap_or("").to_string(),
+        ]),
+        hinted_tag("p", target_pk, target_relay),
+    ];
+    let kind = if target.kind == Kind::TextNote {
+        Kind::Repost
+    } else {
+        if let Some(coordinate) = coordinate_of(target) {
+            tags.push(hinted_tag("a", coordinate, target_relay));
+        }
+        tags.push(make_tag(["k".to_string(), target.kind.as_u16().to_string()]));
+        Kind::GenericRepost
+    };
+    EventBuilder::new(kind, target.as_json()).tags(tags)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn values(tags: &[Tag]) -> Vec<Vec<String>> {
+        tags.iter().map(|t| t.as_slice().to_vec()).collect()
+    }
+
+    #[test]
+    fn test_reply_to_reply_keeps_root_and_participants() {
+        let alice = Keys::generate();
+        let bob = Keys::generate();
+        let carol = Keys::generate();
+
+        let root = EventBuilder::text_note("root").sign_with_keys(&alice).unwrap();
+        let parent = build_reply(&root, "hi", Some("wss://relay.example"), Some(&bob.public_key()))
+            .sign_with_keys(&bob)
+            .unwrap();
+
+        let tags = values(&reply_tags(&parent, None, Some(&carol.public_key())));
+        assert_eq!(tags[0][1], root.id.to_hex());
+        assert_eq!(tags[0][2], "wss://relay.example");
+        assert_eq!(tags[0][3], "root");
+        assert_eq!(tags[1][1], parent.id.to_hex());
+        assert_eq!(tags[1][3], "reply");
+
+        let p: Vec<&String> = tags.iter().filter(|t| t[0] == "p").map(|t| &t[1]).collect();
+        assert_eq!(p, vec![&bob.public_key().to_hex(), &alice.public_key().to_hex()]);
+    }
+
+    #[test]
+    fn test_reaction_and_repost_of_addressable_event() {
+        let alice = Keys::generate();
+        let article = EventBuilder::new(Kind::LongFormTextNote, "# title")
+            .tags([make_tag(["d", "my-article"])])
+            .sign_with_keys(&alice)
+            .unwrap();
+
+        let reaction = build_reaction(&article, ":nuru:", Some("https://e.example/nuru.png"), Some("wss://r.example"))
+            .unwrap()
+            .sign_with_keys(&Keys::generate())
+            .unwrap();
+        let tags = values(&reaction.tags.iter().cloned().collect::<Vec<_>>());
+        let coordinate = format!("30023:{}:my-article", alice.public_key());
+        assert!(tags.contains(&vec!["a".to_string(), coordinate.clone(), "wss://r.example".to_string()]));
+        assert!(tags.contains(&vec!["k".to_string(), "30023".to_string()]));
+        assert!(tags.contains(&vec![
+            "emoji".to_string(),
+            "nuru".to_string(),
+            "https://e.example/nuru.png".to_string()
+        ]));
+        assert!(build_reaction(&article, ":nuru:", None, None).is_err());
+
+        let repost = build_repost(&article, None).sign_with_keys(&Keys::generate()).unwrap();
+        assert_eq!(repost.kind, Kind::GenericRepost);
+        assert!(repost.tags.iter().any(|t| t.as_slice() == ["a".to_string(), coordinate.clone()]));
+    }
+
+    #[test]
+    fn test_quote_adds_q_tag_and_reference() {
+        let alice = Keys::generate();
+        let target = EventBuilder::text_note("quoted").sign_with_keys(&alice).unwrap();
+        let quote = build_quote(&target, "look", None)
+            .unwrap()
+            .sign_with_keys(&Keys::generate())
+            .unwrap();
+
+        assert!(quote.content.starts_with("look\nnostr:nevent1"));
+        assert!(quote
+            .tags
+            .iter()
+            .any(|t| t.as_slice()[0] == "q" && t.as_slice()[1] == target.id.to_hex()));
+    }
+}
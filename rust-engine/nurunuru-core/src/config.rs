@@ -26,6 +26,14 @@ impl Default for NuruNuruConfig {
     }
 }
 
+impl NuruNuruConfig {
+    /// Path of a small engine-owned JSON store kept next to the nostrdb
+    /// directory, e.g. `store_path("scheduled")` → `"./nurunuru-db_scheduled.json"`.
+    pub fn store_path(&self, name: &str) -> String {
+        format!("{}_{}.json", self.db_path, name)
+    }
+}
+
 /// Relay connection settings (from `WS_CONFIG` in constants.js)
 #[derive(Debug, Clone)]
 pub struct RelayConfig {
@@ -0,0 +1,284 @@
+//! NIP-02 contact list editing.
+//!
+//! Follow / unfollow edits are applied to the raw tags of the newest known
+//! kind-3 event, so petnames, relay hints, non-`p` tags and the `content`
+//! (legacy relay map) survive the round-trip.  `Contact::new` keeps none of
+//! these, which is why the builders here work on tags directly.
+//!
+//! Because kind 3 is replaceable, relays forget every version but the
+//! newest.  `FollowListHistory` keeps each version the engine has seen for
+//! the logged-in user so a bad write can be rolled back.
+
+use std::collections::{HashMap, HashSet};
+
+use nostr::prelude::*;
+use serde::{Deserialize, Serialize};
+
+use crate::error::Result;
+use crate::store;
+use crate::tags::make_tag;
+use crate::types::FollowListVersion;
+
+/// Versions kept per user; the oldest are dropped first.
+const MAX_HISTORY_VERSIONS: usize = 50;
+
+/// Result of applying follow / unfollow changes to a contact list.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ContactListEdit {
+    /// Full tag list of the new kind-3 event.
+    pub tags: Vec<Vec<String>>,
+    /// Content carried over from the base list.
+    pub content: String,
+    /// Pubkeys newly followed by this edit.
+    pub added: Vec<String>,
+    /// Pubkeys removed by this edit.
+    pub removed: Vec<String>,
+}
+
+impl ContactListEdit {
+    /// `true` if the edit leaves the list unchanged.
+    pub fn is_noop(&self) -> bool {
+        self.added.is_empty() && self.removed.is_empty()
+    }
+
+    /// Followed pubkeys after the edit, in tag order.
+    pub fn follows(&self) -> Vec<String> {
+        self.tags
+            .iter()
+            .filter(|t| t.len() >= 2 && t[0] == "p")
+            .map(|t| t[1].clone())
+            .collect()
+    }
+
+    /// Tags ready for an `EventBuilder`.
+    pub fn to_tags(&self) -> Vec<Tag> {
+        self.tags.iter().cloned().map(make_tag).collect()
+    }
+}
+
+/// Followed pubkeys (`p` tag values) of a kind-3 event, in tag order.
+pub fn followed_pubkeys(event: &Event) -> Vec<String> {
+    event
+        .tags
+        .iter()
+        .map(|t| t.as_slice())
+        .filter(|v| v.len() >= 2 && v[0] == "p")
+        .map(|v| v[1].clone())
+        .collect()
+}
+
+/// Apply `follow` / `unfollow` to `base` (the newest known list, or `None`
+/// for a first list).  Existing tags keep their position and extra fields;
+/// new follows are appended as bare `p` tags.  A pubkey in both slices is
+/// unfollowed.
+pub fn apply_edit(base: Option<&Event>, follow: &[PublicKey], unfollow: &[PublicKey]) -> ContactListEdit {
+    let unfollow: HashSet<String> = unfollow.iter().map(|pk| pk.to_hex()).collect();
+
+    let mut tags = Vec::new();
+    let mut present: HashSet<String> = HashSet::new();
+    let mut removed = Vec::new();
+
+    for tag in base.map(|e| e.tags.iter()).into_iter().flatten() {
+        let v = tag.as_slice();
+        if v.len() >= 2 && v[0] == "p" {
+            if unfollow.contains(&v[1]) {
+                if !removed.contains(&v[1]) {
+                    removed.push(v[1].clone());
+                }
+                continue;
+            }
+            // Drop duplicate `p` entries, keeping the first (with its petname).
+            if !present.insert(v[1].clone()) {
+                continue;
+            }
+        }
+        tags.push(v.to_vec());
+    }
+
+    let mut added = Vec::new();
+    for pk in follow {
+        let hex = pk.to_hex();
+        if unfollow.contains(&hex) || !present.insert(hex.clone()) {
+            continue;
+        }
+        tags.push(vec!["p".to_string(), hex.clone()]);
+        added.push(hex);
+    }
+
+    ContactListEdit {
+        tags,
+        content: base.map(|e| e.content.clone()).unwrap_or_default(),
+        added,
+        removed,
+    }
+}
+
+#[derive(Debug, Default, Serialize, Deserialize)]
+struct HistoryFile {
+    /// Owner pubkey hex → kind-3 versions, oldest first.
+    lists: HashMap<String, Vec<Event>>,
+}
+
+/// Local history of a user's kind-3 versions.
+///
+/// Not thread-safe on its own; the engine holds it behind a `Mutex`.
+pub struct FollowListHistory {
+    path: String,
+    file: HistoryFile,
+}
+
+impl FollowListHistory {
+    /// Open (or create) the history stored at `path`.
+    pub fn open(path: &str) -> Self {
+        Self {
+            path: path.to_string(),
+            file: store::load_json(path),
+        }
+    }
+
+    /// Record a kind-3 version.  Returns `true` if it was not known yet.
+    /// Other kinds are ignored.
+    pub fn record(&mut self, event: &Event) -> Result<bool> {
+        if event.kind != Kind::ContactList {
+            return Ok(false);
+        }
+        let versions = self.file.lists.entry(event.pubkey.to_hex()).or_default();
+        if versions.iter().any(|e| e.id == event.id) {
+            return Ok(false);
+        }
+        versions.push(event.clone());
+        versions.sort_by(|a, b| a.created_at.cmp(&b.created_at).then_with(|| b.id.cmp(&a.id)));
+        if versions.len() > MAX_HISTORY_VERSIONS {
+            let excess = versions.len() - MAX_HISTORY_VERSIONS;
+            versions.drain(..excess);
+        }
+        store::save_json(&self.path, &self.file)?;
+        Ok(true)
+    }
+
+    /// Versions of `owner`'s list, newest first, each diffed against the
+    /// version before it.
+    pub fn versions(&self, owner: &PublicKey) -> Vec<FollowListVersion> {
+        let Some(events) = self.file.lists.get(&owner.to_hex()) else {
+            return Vec::new();
+        };
+
+        let mut previous: Vec<String> = Vec::new();
+        let mut out = Vec::with_capacity(events.len());
+        for (i, event) in events.iter().enumerate() {
+            let follows = followed_pubkeys(event);
+            let before: HashSet<&String> = previous.iter().collect();
+            let after: HashSet<&String> = follows.iter().collect();
+            out.push(FollowListVersion {
+                event_id: event.id.to_hex(),
+                created_at: event.created_at.as_secs(),
+                follow_count: follows.len() as u32,
+                added: follows.iter().filter(|pk| !before.contains(pk)).cloned().collect(),
+                removed: previous.iter().filter(|pk| !after.contains(pk)).cloned().collect(),
+                is_current: i + 1 == events.len(),
+            });
+            previous = follows;
+        }
+        out.reverse();
+        out
+    }
+
+    /// A stored version of `owner`'s list by event ID.
+    pub fn get(&self, owner: &PublicKey, event_id: &str) -> Option<&Event> {
+        self.file
+            .lists
+            .get(&owner.to_hex())?
+            .iter()
+            .find(|e| e.id.to_hex() == event_id)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::replaceable::newest;
+
+    fn contact_list(keys: &Keys, tags: Vec<Vec<String>>, content: &str, at: u64) -> Event {
+        EventBuilder::new(Kind::ContactList, content)
+            .tags(tags.into_iter().map(make_tag))
+            .custom_created_at(Timestamp::from(at))
+            .sign_with_keys(keys)
+            .unwrap()
+    }
+
+    #[test]
+    fn test_edit_keeps_petnames_relays_and_content() {
+        let me = Keys::generate();
+        let alice = Keys::generate().public_key().to_hex();
+        let bob = Keys::generate().public_key();
+        let carol = Keys::generate().public_key();
+        let base = contact_list(
+            &me,
+            vec![
+                vec!["p".into(), alice.clone(), "wss://relay.example".into(), "alice".into()],
+                vec!["p".into(), bob.to_hex()],
+                vec!["t".into(), "nostr".into()],
+            ],
+            r#"{"wss://relay.example":{"read":true,"write":true}}"#,
+            100,
+        );
+
+        let edit = apply_edit(Some(&base), &[carol, bob], &[bob]);
+
+        assert_eq!(edit.added, vec![carol.to_hex()]);
+        assert_eq!(edit.removed, vec![bob.to_hex()]);
+        assert_eq!(edit.content, base.content);
+        assert_eq!(
+            edit.tags,
+            vec![
+                vec!["p".to_string(), alice.clone(), "wss://relay.example".into(), "alice".into()],
+                vec!["t".to_string(), "nostr".into()],
+                vec!["p".to_string(), carol.to_hex()],
+            ]
+        );
+        assert_eq!(edit.follows(), vec![alice, carol.to_hex()]);
+    }
+
+    #[test]
+    fn test_newest_and_noop() {
+        let me = Keys::generate();
+        let alice = Keys::generate().public_key();
+        let old = contact_list(&me, vec![], "", 100);
+        let new = contact_list(&me, vec![vec!["p".into(), alice.to_hex()]], "", 200);
+
+        let latest = newest([&old, &new]).unwrap();
+        assert_eq!(latest.id, new.id);
+        assert!(apply_edit(Some(latest), &[alice], &[]).is_noop());
+        assert!(newest(std::iter::empty()).is_none());
+    }
+
+    #[test]
+    fn test_history_diffs_newest_first() {
+        let me = Keys::generate();
+        let alice = Keys::generate().public_key().to_hex();
+        let bob = Keys::generate().public_key().to_hex();
+        let v1 = contact_list(&me, vec![vec!["p".into(), alice.clone()]], "", 100);
+        let v2 = contact_list(
+            &me,
+            vec![vec!["p".into(), alice.clone()], vec!["p".into(), bob.clone()]],
+            "",
+            200,
+        );
+        let v3 = contact_list(&me, vec![], "", 300);
+
+        let path = std::env::temp_dir().join(format!("nurunuru_follow_history_{}.json", std::process::id()));
+        let _ = std::fs::remove_file(&path);
+        let mut history = FollowListHistory::open(path.to_str().unwrap());
+        for v in [&v3, &v1, &v2, &v2] {
+            history.record(v).unwrap();
+        }
+
+        let versions = history.versions(&me.public_key());
+        assert_eq!(versions.len(), 3);
+        assert!(versions[0].is_current);
+        assert_eq!(versions[0].removed.len(), 2);
+        assert_eq!(versions[1].added, vec![bob]);
+        assert_eq!(versions[2].added, vec![alice]);
+        assert!(history.get(&me.public_key(), &v1.id.to_hex()).is_some());
+    }
+}
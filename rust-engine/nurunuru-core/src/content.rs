@@ -0,0 +1,450 @@
+//! Note content tokenizer — replaces `PostContent.kt` and the JS
+//! `parseNostrLink` helpers with one shared implementation.
+//!
+//! Splits content into `ContentSegment`s: plain text, URLs (with image /
+//! video detection by extension), NIP-21 `nostr:` references, hashtags,
+//! NIP-30 custom emoji and Lightning invoices.
+//!
+//! URLs stop at the first non-ASCII character, so Japanese text written
+//! directly after a link (`https://example.comです`) is not swallowed.
+//!
+//! The same segments drive `auto_tags`, which derives the `t` / `p` / `q` /
+//! `emoji` tags a note needs from what the user typed.
+
+use std::collections::{HashMap, HashSet};
+
+use nostr::prelude::*;
+
+use crate::tags::{is_shortcode_char, make_tag, tag_values};
+use crate::types::ContentSegment;
+
+const IMAGE_EXTENSIONS: &[&str] = &["jpg", "jpeg", "png", "gif", "webp", "avif", "svg", "bmp"];
+const VIDEO_EXTENSIONS: &[&str] = &["mp4", "webm", "mov", "m4v", "ogv"];
+
+/// Trailing characters that end a sentence rather than a URL.
+const URL_TRAILING_PUNCT: &[char] = &['.', ',', ';', ':', '!', '?', '\'', '"', '*'];
+
+/// Shortest plausible BOLT-11 invoice.
+const MIN_INVOICE_LEN: usize = 30;
+
+/// NIP-30 `emoji` tags of `event` as `shortcode → image URL`.
+pub fn emoji_map(event: &Event) -> HashMap<String, String> {
+    tag_values(event, "emoji")
+        .filter(|v| v.len() >= 3)
+        .map(|v| (v[1].clone(), v[2].clone()))
+        .collect()
+}
+
+/// NIP-36 content warning of `event`: `Some(reason)` if it carries a
+/// `content-warning` tag (`""` when no reason is given).
+pub fn content_warning(event: &Event) -> Option<String> {
+    tag_values(event, "content-warning")
+        .next()
+        .map(|v| v.get(1).cloned().unwrap_or_default())
+}
+
+/// `["content-warning", reason]`, or just `["content-warning"]` for an
+/// empty reason.
+pub fn content_warning_tag(reason: &str) -> Tag {
+    if reason.is_empty() {
+        make_tag(["content-warning"])
+    } else {
+        make_tag(["content-warning", reason])
+    }
+}
+
+/// Tokenize an event's content, resolving custom emoji against its own tags.
+pub fn tokenize_event(event: &Event) -> Vec<ContentSegment> {
+    tokenize(&event.content, &emoji_map(event))
+}
+
+/// Tokenize `content`. `emojis` maps shortcodes (without colons) to image URLs;
+/// unknown `:shortcodes:` stay plain text.
+pub fn tokenize(content: &str, emojis: &HashMap<String, String>) -> Vec<ContentSegment> {
+    let mut segments = Vec::new();
+    let mut text_start = 0;
+    let mut i = 0;
+
+    while i < content.len() {
+        let rest = &content[i..];
+        let at_boundary = content[..i].chars().next_back().is_none_or(|c| !is_word_char(c));
+
+        if let Some((len, segment)) = match_token(rest, at_boundary, emojis) {
+            if text_start < i {
+                segments.push(ContentSegment::Text {
+                    text: content[text_start..i].to_string(),
+                });
+            }
+            segments.push(segment);
+            i += len;
+            text_start = i;
+        } else {
+            i += rest.chars().next().map(char::len_utf8).unwrap_or(1);
+        }
+    }
+
+    if text_start < content.len() {
+        segments.push(ContentSegment::Text {
+            text: content[text_start..].to_string(),
+        });
+    }
+    segments
+}
+
+/// Tags implied by `content`:
+///
+/// - `t` for each hashtag (lowercased),
+/// - `p` for each profile mention (with its first relay hint),
+/// - `q` (plus a `p` for the author, when known) for each quoted event or
+///   address,
+/// - NIP-30 `emoji` for each `:shortcode:` found in `emojis`.
+///
+/// Tags already in `existing` (same name and first value) are not repeated.
+pub fn auto_tags(content: &str, emojis: &HashMap<String, String>, existing: &[Tag]) -> Vec<Tag> {
+    let mut seen: HashSet<(String, String)> = existing
+        .iter()
+        .filter_map(|t| {
+            let v = t.as_slice();
+            Some((v.first()?.clone(), v.get(1)?.clone()))
+        })
+        .collect();
+    let mut tags = Vec::new();
+    let mut push = |mut parts: Vec<String>| {
+        while parts.len() > 2 && parts.last().is_some_and(|p| p.is_empty()) {
+            parts.pop();
+        }
+        if seen.insert((parts[0].clone(), parts[1].clone())) {
+            tags.push(make_tag(parts));
+        }
+    };
+
+    for segment in tokenize(content, emojis) {
+        match segment {
+            ContentSegment::Hashtag { tag } => push(vec!["t".into(), tag.to_lowercase()]),
+            ContentSegment::Profile { pubkey, relays, .. } => {
+                push(vec!["p".into(), pubkey, first_or_empty(relays)]);
+            }
+            ContentSegment::Event {
+                event_id,
+                author,
+                relays,
+                ..
+            } => {
+                push(vec![
+                    "q".into(),
+                    event_id,
+                    first_or_empty(relays),
+                    author.clone().unwrap_or_default(),
+                ]);
+                if let Some(author) = author {
+                    push(vec!["p".into(), author]);
+                }
+            }
+            ContentSegment::Address {
+                kind,
+                pubkey,
+                identifier,
+                relays,
+                ..
+            } => {
+                push(vec![
+                    "q".into(),
+                    format!("{kind}:{pubkey}:{identifier}"),
+                    first_or_empty(relays),
+                ]);
+                push(vec!["p".into(), pubkey]);
+            }
+            ContentSegment::Emoji { shortcode, url } => push(vec!["emoji".into(), shortcode, url]),
+            _ => {}
+        }
+    }
+    tags
+}
+
+fn first_or_empty(relays: Vec<String>) -> String {
+    relays.into_iter().next().unwrap_or_default()
+}
+
+fn is_word_char(c: char) -> bool {
+    c.is_ascii_alphanumeric() || c == '_'
+}
+
+fn match_token(
+    rest: &str,
+    at_boundary: bool,
+    emojis: &HashMap<String, String>,
+) -> Option<(usize, ContentSegment)> {
+    match rest.as_bytes()[0] {
+        b':' => match_emoji(rest, emojis),
+        b'#' if at_boundary => match_hashtag(rest),
+        _ if at_boundary => match_url(rest)
+            .or_else(|| match_nostr(rest))
+            .or_else(|| match_invoice(rest)),
+        _ => None,
+    }
+}
+
+fn starts_with_ignore_case(s: &str, prefix: &str) -> bool {
+    s.get(..prefix.len())
+        .is_some_and(|p| p.eq_ignore_ascii_case(prefix))
+}
+
+fn match_url(rest: &str) -> Option<(usize, ContentSegment)> {
+    let scheme_len = if starts_with_ignore_case(rest, "https://") {
+        8
+    } else if starts_with_ignore_case(rest, "http://") {
+        7
+    } else {
+        return None;
+    };
+
+    let end = rest
+        .find(|c: char| c.is_whitespace() || !c.is_ascii() || matches!(c, '<' | '>' | '"' | '`'))
+        .unwrap_or(rest.len());
+    let mut url = &rest[..end];
+
+    // Drop sentence punctuation and unbalanced closing brackets.
+    loop {
+        let Some(last) = url.chars().next_back() else { break };
+        let unbalanced = match last {
+            ')' => url.matches('(').count() < url.matches(')').count(),
+            ']' => url.matches('[').count() < url.matches(']').count(),
+            _ => false,
+        };
+        if URL_TRAILING_PUNCT.contains(&last) || unbalanced {
+            url = &url[..url.len() - 1];
+        } else {
+            break;
+        }
+    }
+
+    if url.len() <= scheme_len {
+        return None;
+    }
+
+    let path = url.split(['?', '#']).next().unwrap_or(url);
+    let last_segment = path[scheme_len..].rsplit('/').next().unwrap_or("");
+    let ext = last_segment
+        .rsplit_once('.')
+        .map(|(_, ext)| ext.to_ascii_lowercase())
+        .unwrap_or_default();
+
+    let url_string = url.to_string();
+    let segment = if IMAGE_EXTENSIONS.contains(&ext.as_str()) {
+        ContentSegment::Image { url: url_string }
+    } else if VIDEO_EXTENSIONS.contains(&ext.as_str()) {
+        ContentSegment::Video { url: url_string }
+    } else {
+        ContentSegment::Url { url: url_string }
+    };
+    Some((url.len(), segment))
+}
+
+fn match_nostr(rest: &str) -> Option<(usize, ContentSegment)> {
+    const PREFIX: &str = "nostr:";
+    if !starts_with_ignore_case(rest, PREFIX) {
+        return None;
+    }
+    let body = &rest[PREFIX.len()..];
+    let len = body
+        .find(|c: char| !c.is_ascii_alphanumeric())
+        .unwrap_or(body.len());
+    let bech32 = body[..len].to_ascii_lowercase();
+    let segment = nostr_segment(&bech32)?;
+    Some((PREFIX.len() + len, segment))
+}
+
+/// Decode a NIP-19 entity into a segment. Secret keys are never rendered.
+fn nostr_segment(bech32: &str) -> Option<ContentSegment> {
+    let uri = format!("nostr:{bech32}");
+    let relays = |urls: &[RelayUrl]| urls.iter().map(|u| u.to_string()).collect::<Vec<_>>();
+
+    let segment = match Nip19::from_bech32(bech32).ok()? {
+        Nip19::Pubkey(pk) => ContentSegment::Profile {
+            uri,
+            pubkey: pk.to_hex(),
+            relays: Vec::new(),
+        },
+        Nip19::Profile(profile) => ContentSegment::Profile {
+            uri,
+            pubkey: profile.public_key.to_hex(),
+            relays: relays(&profile.relays),
+        },
+        Nip19::EventId(id) => ContentSegment::Event {
+            uri,
+            event_id: id.to_hex(),
+            author: None,
+            kind: None,
+            relays: Vec::new(),
+        },
+        Nip19::Event(event) => ContentSegment::Event {
+            uri,
+            event_id: event.event_id.to_hex(),
+            author: event.author.map(|pk| pk.to_hex()),
+            kind: event.kind.map(|k| k.as_u16()),
+            relays: relays(&event.relays),
+        },
+        Nip19::Coordinate(addr) => ContentSegment::Address {
+            uri,
+            kind: addr.coordinate.kind.as_u16(),
+            pubkey: addr.coordinate.public_key.to_hex(),
+            identifier: addr.coordinate.identifier.clone(),
+            relays: relays(&addr.relays),
+        },
+        _ => return None,
+    };
+    Some(segment)
+}
+
+fn match_hashtag(rest: &str) -> Option<(usize, ContentSegment)> {
+    let body = &rest[1..];
+    let len = body
+        .find(|c: char| !(c.is_alphanumeric() || c == '_'))
+        .unwrap_or(body.len());
+    if len == 0 {
+        return None;
+    }
+    Some((
+        1 + len,
+        ContentSegment::Hashtag {
+            tag: body[..len].to_string(),
+        },
+    ))
+}
+
+fn match_emoji(rest: &str, emojis: &HashMap<String, String>) -> Option<(usize, ContentSegment)> {
+    let body = &rest[1..];
+    let len = body
+        .find(|c: char| !is_shortcode_char(c))
+        .unwrap_or(body.len());
+    if len == 0 || !body[len..].starts_with(':') {
+        return None;
+    }
+    let shortcode = &body[..len];
+    let url = emojis.get(shortcode)?;
+    Some((
+        len + 2,
+        ContentSegment::Emoji {
+            shortcode: shortcode.to_string(),
+            url: url.clone(),
+        },
+    ))
+}
+
+fn match_invoice(rest: &str) -> Option<(usize, ContentSegment)> {
+    let prefix_len = if starts_with_ignore_case(rest, "lightning:") {
+        "lightning:".len()
+    } else {
+        0
+    };
+    let body = &rest[prefix_len..];
+    if !(starts_with_ignore_case(body, "lnbc") || starts_with_ignore_case(body, "lntb")) {
+        return None;
+    }
+    let len = body
+        .find(|c: char| !c.is_ascii_alphanumeric())
+        .unwrap_or(body.len());
+    if len < MIN_INVOICE_LEN {
+        return None;
+    }
+    Some((
+        prefix_len + len,
+        ContentSegment::Invoice {
+            invoice: body[..len].to_ascii_lowercase(),
+        },
+    ))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_url_stops_at_japanese_and_detects_images() {
+        let segs = tokenize("見て https://example.com/a.JPG?x=1です。", &HashMap::new());
+        assert_eq!(
+            segs,
+            vec![
+                ContentSegment::Text { text: "見て ".into() },
+                ContentSegment::Image {
+                    url: "https://example.com/a.JPG?x=1".into()
+                },
+                ContentSegment::Text { text: "です。".into() },
+            ]
+        );
+    }
+
+    #[test]
+    fn test_hashtag_emoji_and_profile() {
+        let keys = Keys::generate();
+        let npub = keys.public_key().to_bech32().unwrap();
+        let mut emojis = HashMap::new();
+        emojis.insert("nuru".to_string(), "https://e.example/nuru.png".to_string());
+        emojis.insert("ぬるぬる".to_string(), "https://e.example/jp.png".to_string());
+
+        let content = format!("#ぬるぬる :nuru: :unknown: nostr:{npub} :ぬるぬる:");
+        let segs = tokenize(&content, &emojis);
+
+        assert_eq!(segs[0], ContentSegment::Hashtag { tag: "ぬるぬる".into() });
+        assert_eq!(
+            segs[2],
+            ContentSegment::Emoji {
+                shortcode: "nuru".into(),
+                url: "https://e.example/nuru.png".into()
+            }
+        );
+        assert_eq!(segs[3], ContentSegment::Text { text: " :unknown: ".into() });
+        assert!(matches!(
+            &segs[4],
+            ContentSegment::Profile { pubkey, .. } if *pubkey == keys.public_key().to_hex()
+        ));
+        // Same shortcode rule as `:ぬるぬる:` reactions.
+        assert!(matches!(segs.last(), Some(ContentSegment::Emoji { shortcode, .. }) if shortcode == "ぬるぬる"));
+    }
+
+    #[test]
+    fn test_auto_tags_skip_existing_and_unknown_emoji() {
+        let keys = Keys::generate();
+        let npub = keys.public_key().to_bech32().unwrap();
+        let pk = keys.public_key().to_hex();
+        let mut emojis = HashMap::new();
+        emojis.insert("nuru".to_string(), "https://e.example/nuru.png".to_string());
+
+        let existing = vec![make_tag(["t", "nostr"])];
+        let content = format!("#Nostr #Rust :nuru: :nope: nostr:{npub} nostr:{npub}");
+        let tags: Vec<Vec<String>> = auto_tags(&content, &emojis, &existing)
+            .iter()
+            .map(|t| t.as_slice().to_vec())
+            .collect();
+
+        assert_eq!(
+            tags,
+            vec![
+                vec!["t".to_string(), "rust".to_string()],
+                vec!["emoji".to_string(), "nuru".to_string(), "https://e.example/nuru.png".to_string()],
+                vec!["p".to_string(), pk],
+            ]
+        );
+    }
+
+    #[test]
+    fn test_content_warning_reason() {
+        let keys = Keys::generate();
+        let warned = |reason: &str| {
+            EventBuilder::text_note("…")
+                .tags([content_warning_tag(reason)])
+                .sign_with_keys(&keys)
+                .unwrap()
+        };
+        assert_eq!(content_warning(&warned("ネタバレ")).as_deref(), Some("ネタバレ"));
+        assert_eq!(content_warning(&warned("")).as_deref(), Some(""));
+        let plain = EventBuilder::text_note("hi").sign_with_keys(&keys).unwrap();
+        assert_eq!(content_warning(&plain), None);
+    }
+
+    #[test]
+    fn test_no_hashtag_inside_word() {
+        let segs = tokenize("abc#def", &HashMap::new());
+        assert_eq!(segs, vec![ContentSegment::Text { text: "abc#def".into() }]);
+    }
+}
@@ -0,0 +1,244 @@
+//! NIP-09 deletion processing.
+//!
+//! A kind-5 request only counts for events by its own author.  For `e`
+//! targets the author is usually unknown when the request arrives, so the
+//! tombstone records who asked and is matched against the event's pubkey
+//! when filtering.  `a` targets carry the author in the coordinate and
+//! delete every version up to the request's `created_at`.
+//!
+//! nostrdb does not apply kind-5 events itself, so tombstones are persisted
+//! and every read path filters through them.  They are only a cache of the
+//! requests kept in nostrdb (`purge_deleted` re-applies those), so the
+//! oldest are dropped past `MAX_TOMBSTONES`.
+
+use std::collections::{HashMap, HashSet};
+
+use nostr::prelude::*;
+use serde::{Deserialize, Serialize};
+
+use crate::tags::{coordinate_of, tag_values};
+
+/// Deleted event IDs (and, separately, coordinates) kept; the ones with the
+/// oldest requests are dropped first.
+pub const MAX_TOMBSTONES: usize = 10_000;
+
+/// Event IDs and coordinates deleted by their authors.
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct Tombstones {
+    /// Deleted event ID → pubkeys hex of the authors that requested it.
+    #[serde(default)]
+    events: HashMap<String, HashSet<String>>,
+    /// Deleted event ID → newest request `created_at`, for pruning.
+    #[serde(default)]
+    event_times: HashMap<String, u64>,
+    /// Deleted `kind:pubkey:d` coordinate → newest deletion `created_at`.
+    #[serde(default)]
+    coordinates: HashMap<String, u64>,
+    /// Changed since the last `mark_saved`.
+    #[serde(skip)]
+    dirty: bool,
+}
+
+impl Tombstones {
+    /// Record the targets of a kind-5 request.  Returns `true` if anything
+    /// new was recorded; other kinds and `a` targets of other authors are
+    /// ignored.
+    pub fn apply(&mut self, deletion: &Event) -> bool {
+        if deletion.kind != Kind::EventDeletion {
+            return false;
+        }
+        let author = deletion.pubkey.to_hex();
+        let at = deletion.created_at.as_secs();
+        let mut changed = false;
+
+        for v in tag_values(deletion, "e") {
+            let Some(id) = v.get(1).filter(|id| EventId::from_hex(id).is_ok()) else {
+                continue;
+            };
+            // Requests by anyone are kept; only the event's own author's
+            // one matches in `is_deleted`.
+            changed |= self.events.entry(id.clone()).or_default().insert(author.clone());
+            let time = self.event_times.entry(id.clone()).or_insert(0);
+            *time = (*time).max(at);
+        }
+        for v in tag_values(deletion, "a") {
+            let Some(coordinate) = v.get(1) else { continue };
+            if coordinate.split(':').nth(1) != Some(author.as_str()) {
+                continue;
+            }
+            let entry = self.coordinates.entry(coordinate.clone()).or_insert(0);
+            if at > *entry {
+                *entry = at;
+                changed = true;
+            }
+        }
+        if changed {
+            self.prune();
+            self.dirty = true;
+        }
+        changed
+    }
+
+    /// Drop the entries with the oldest requests beyond `MAX_TOMBSTONES`.
+    fn prune(&mut self) {
+        if self.events.len() > MAX_TOMBSTONES {
+            for id in oldest_beyond(&self.event_times, self.events.keys(), MAX_TOMBSTONES) {
+                self.events.remove(&id);
+                self.event_times.remove(&id);
+            }
+        }
+        if self.coordinates.len() > MAX_TOMBSTONES {
+            for c in oldest_beyond(&self.coordinates, self.coordinates.keys(), MAX_TOMBSTONES) {
+                self.coordinates.remove(&c);
+            }
+        }
+    }
+
+    /// `true` if anything changed since the last `mark_saved`.
+    pub fn is_dirty(&self) -> bool {
+        self.dirty
+    }
+
+    pub fn mark_saved(&mut self) {
+        self.dirty = false;
+    }
+
+    /// `true` if `event` was deleted by its author.
+    pub fn is_deleted(&self, event: &Event) -> bool {
+        if self
+            .events
+            .get(&event.id.to_hex())
+            .is_some_and(|authors| authors.contains(&event.pubkey.to_hex()))
+        {
+            return true;
+        }
+        replaceable_coordinate(event)
+            .and_then(|c| self.coordinates.get(&c))
+            .is_some_and(|&at| event.created_at.as_secs() <= at)
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.events.is_empty() && self.coordinates.is_empty()
+    }
+}
+
+/// The keys past the newest `keep` by `times` (missing times count as
+/// oldest).
+fn oldest_beyond<'a>(times: &HashMap<String, u64>, keys: impl Iterator<Item = &'a String>, keep: usize) -> Vec<String> {
+    let mut keys: Vec<(u64, &String)> = keys.map(|k| (times.get(k).copied().unwrap_or(0), k)).collect();
+    keys.sort_unstable_by(|a, b| b.cmp(a));
+    keys.into_iter().skip(keep).map(|(_, k)| k.clone()).collect()
+}
+
+/// Coordinate of an addressable (`kind:pubkey:d`) or replaceable
+/// (`kind:pubkey:`) event.
+pub fn replaceable_coordinate(event: &Event) -> Option<String> {
+    coordinate_of(event).or_else(|| {
+        event
+            .kind
+            .is_replaceable()
+            .then(|| format!("{}:{}:", event.kind.as_u16(), event.pubkey))
+    })
+}
+
+/// Event IDs and coordinates a kind-5 request targets, for purging nostrdb.
+pub fn targets(deletion: &Event) -> (Vec<EventId>, Vec<Coordinate>) {
+    let ids = tag_values(deletion, "e")
+        .filter_map(|v| v.get(1))
+        .filter_map(|id| EventId::from_hex(id).ok())
+        .collect();
+    let coordinates = tag_values(deletion, "a")
+        .filter_map(|v| v.get(1))
+        .filter_map(|c| Coordinate::parse(c).ok())
+        .filter(|c| c.public_key == deletion.pubkey)
+        .collect();
+    (ids, coordinates)
+}
+
+/// Filters matching the events a kind-5 request deletes: its `e` targets
+/// by the same author and every version of its `a` targets up to the
+/// request.
+pub fn target_filters(deletion: &Event) -> Vec<Filter> {
+    let (ids, coordinates) = targets(deletion);
+    let mut filters = Vec::new();
+    if !ids.is_empty() {
+        filters.push(Filter::new().ids(ids).author(deletion.pubkey));
+    }
+    for c in coordinates {
+        let mut f = Filter::new()
+            .author(c.public_key)
+            .kind(c.kind)
+            .until(deletion.created_at);
+        if !c.identifier.is_empty() {
+            f = f.identifier(c.identifier);
+        }
+        filters.push(f);
+    }
+    filters
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::tags::make_tag;
+
+    fn deletion(keys: &Keys, tags: Vec<Vec<String>>, at: u64) -> Event {
+        EventBuilder::new(Kind::EventDeletion, "")
+            .tags(tags.into_iter().map(make_tag))
+            .custom_created_at(Timestamp::from(at))
+            .sign_with_keys(keys)
+            .unwrap()
+    }
+
+    #[test]
+    fn test_only_author_deletions_apply() {
+        let alice = Keys::generate();
+        let mallory = Keys::generate();
+        let note = EventBuilder::text_note("hi").sign_with_keys(&alice).unwrap();
+
+        let mut tombstones = Tombstones::default();
+        assert!(tombstones.apply(&deletion(&mallory, vec![vec!["e".into(), note.id.to_hex()]], 100)));
+        assert!(!tombstones.is_deleted(&note));
+
+        tombstones.apply(&deletion(&alice, vec![vec!["e".into(), note.id.to_hex()]], 100));
+        assert!(tombstones.is_deleted(&note));
+    }
+
+    #[test]
+    fn test_coordinate_deletes_versions_up_to_request() {
+        let alice = Keys::generate();
+        let article = |at: u64| {
+            EventBuilder::new(Kind::LongFormTextNote, "body")
+                .tags([make_tag(["d", "post"])])
+                .custom_created_at(Timestamp::from(at))
+                .sign_with_keys(&alice)
+                .unwrap()
+        };
+        let coordinate = format!("30023:{}:post", alice.public_key());
+
+        let mut tombstones = Tombstones::default();
+        assert!(!tombstones.apply(&deletion(
+            &Keys::generate(),
+            vec![vec!["a".into(), coordinate.clone()]],
+            200
+        )));
+        tombstones.apply(&deletion(&alice, vec![vec!["a".into(), coordinate]], 200));
+        assert!(tombstones.is_deleted(&article(150)));
+        assert!(!tombstones.is_deleted(&article(250)));
+    }
+
+    #[test]
+    fn test_prune_drops_oldest_requests() {
+        let alice = Keys::generate();
+        let note = |i: usize| EventId::from_slice(&[(i % 256) as u8, (i / 256) as u8].repeat(16)).unwrap();
+
+        let mut tombstones = Tombstones::default();
+        tombstones.apply(&deletion(&alice, vec![vec!["e".into(), note(0).to_hex()]], 500));
+        let rest = (1..=MAX_TOMBSTONES).map(|i| vec!["e".into(), note(i).to_hex()]).collect();
+        tombstones.apply(&deletion(&alice, rest, 1_000));
+        assert!(tombstones.is_dirty());
+        assert_eq!(tombstones.events.len(), MAX_TOMBSTONES);
+        assert!(!tombstones.events.contains_key(&note(0).to_hex()));
+        assert!(tombstones.events.contains_key(&note(MAX_TOMBSTONES).to_hex()));
+    }
+}
@@ -32,13 +32,35 @@ use nostr_ndb::NdbDatabase;
 use nostr_sdk::prelude::*;
 use tokio::sync::{Mutex, RwLock};
 
+use crate::articles;
+use crate::backup;
+use crate::badges;
+use crate::compose;
 use crate::config::NuruNuruConfig;
+use crate::contacts::{self, FollowListHistory};
+use crate::content;
+use crate::deletion::{self, Tombstones};
 use crate::error::{NuruNuruError, Result};
+use crate::expiration::{self, ExpiryIndex};
 use crate::filters;
+use crate::labels;
+use crate::live;
+use crate::migration::{self, MigrationLog};
 use crate::mls::MlsManager;
+use crate::mute::{self, MuteFilter};
+use crate::notifications::{self, NotificationStore};
+use crate::reactions;
 use crate::recommendation::RecommendationEngine;
-use crate::relay;
+use crate::relay::{self, RelayCapabilities};
+use crate::replaceable;
+use crate::reports::{self, ReportAction, TrustedReports};
+use crate::scheduler::ScheduledPostStore;
+use crate::store;
+use crate::tags::{self, make_tag};
+use crate::thread;
 use crate::types::*;
+use crate::vanish;
+use crate::zap;
 
 /// Shared buffer type for SSE subscriptions.
 ///
@@ -47,6 +69,30 @@ use crate::types::*;
 /// strong reference is dropped (via `unsubscribe_stream`) the task exits.
 type SubBuffer = Arc<Mutex<VecDeque<String>>>;
 
+/// How often the background scheduler checks for due scheduled posts.
+const SCHEDULER_TICK: Duration = Duration::from_secs(15);
+
+/// Reply generations `fetch_thread` walks below the root (for replies that
+/// only tag their direct parent).
+const THREAD_MAX_ROUNDS: usize = 4;
+
+/// Parent hops `fetch_thread` walks up from the focused event.
+const THREAD_MAX_ANCESTORS: usize = 20;
+
+/// How long each relay gets to answer `fetch_confirmed` with EOSE.
+const CONFIRM_TIMEOUT: Duration = Duration::from_secs(10);
+
+/// How long `connect_targets` waits for each relay to connect.
+const CONNECT_TIMEOUT: Duration = Duration::from_secs(10);
+
+/// How far back the live deletion subscription starts, to catch requests
+/// sent while the app was closed.
+const DELETION_LOOKBACK_HOURS: u64 = 7 * 24;
+
+/// How far back the live report subscription starts; older reports by
+/// trusted accounts no longer affect ranking.
+const REPORT_LOOKBACK_HOURS: u64 = 90 * 24;
+
 /// The main NuruNuru engine.
 ///
 /// Thread-safe (`Send + Sync`), designed to be held as a singleton
@@ -60,6 +106,8 @@ pub struct NuruNuruEngine {
     user_pubkey: RwLock<Option<PublicKey>>,
     follow_list: RwLock<HashSet<String>>,
     muted_pubkeys: RwLock<HashSet<String>>,
+    /// The logged-in user's full NIP-51 mute list (private entries decrypted).
+    mute_list: RwLock<MuteList>,
     second_degree_follows: RwLock<HashSet<String>>,
     engagement_history: RwLock<EngagementHistory>,
     not_interested_posts: RwLock<HashSet<String>>,
@@ -70,6 +118,31 @@ pub struct NuruNuruEngine {
 
     // MLS / NIP-EE manager (None for read-only clients)
     mls: Option<MlsManager>,
+
+    // Scheduled posts queue (persisted next to nostrdb)
+    scheduled_posts: Mutex<ScheduledPostStore>,
+    /// Every kind-3 version seen for the logged-in user.
+    follow_history: Mutex<FollowListHistory>,
+    /// Read / unread state and first-seen followers for notifications.
+    notifications: Mutex<NotificationStore>,
+    /// Targets of NIP-09 deletions seen, filtered out of all reads.
+    tombstones: RwLock<Tombstones>,
+    /// NIP-40 expiring events seen, purged from nostrdb once due.
+    expiry_index: Mutex<ExpiryIndex>,
+    /// How NIP-36 warned content is handled (persisted).
+    content_warning_policy: RwLock<ContentWarningPolicy>,
+    /// NIP-11 capabilities per relay URL, as reported by the platform.
+    relay_capabilities: RwLock<HashMap<String, RelayCapabilities>>,
+    /// Report thresholds for ranking and timelines (NIP-56).
+    report_policy: RwLock<ReportPolicy>,
+    /// Request to vanish awaiting confirmation, with the relays to send it to.
+    pending_vanish: Mutex<Option<(UnsignedEvent, Vec<RelayUrl>)>>,
+    /// Events already rebroadcast by unfinished relay migrations; also
+    /// keeps migrations from running concurrently.
+    migration_log: Mutex<MigrationLog>,
+    /// Live deletion (kind 5) and report (kind 1984) subscriptions for the
+    /// user and their follows.
+    trusted_subscriptions: Mutex<Vec<SubscriptionId>>,
 }
 
 impl NuruNuruEngine {
@@ -123,6 +196,18 @@ impl NuruNuruEngine {
             }
         };
 
+        let scheduled_posts = ScheduledPostStore::open(&config.store_path("scheduled"));
+        let follow_history = FollowListHistory::open(&config.store_path("follow_history"));
+        let notifications = NotificationStore::open(&config.store_path("notifications"));
+        let tombstones: Tombstones = store::load_json(&config.store_path("deleted"));
+        let expiry_index = ExpiryIndex::open(&config.store_path("expiring"));
+        let content_warning_policy: ContentWarningPolicy =
+            store::load_json(&config.store_path("content_warning"));
+        let relay_capabilities: HashMap<String, RelayCapabilities> =
+            store::load_json(&config.store_path("relay_info"));
+        let report_policy: ReportPolicy = store::load_json(&config.store_path("report_policy"));
+        let migration_log: MigrationLog = store::load_json(&config.store_path("migration"));
+
         let engine = Arc::new(Self {
             client,
             config,
@@ -130,14 +215,29 @@ impl NuruNuruEngine {
             user_pubkey: RwLock::new(None),
             follow_list: RwLock::new(HashSet::new()),
             muted_pubkeys: RwLock::new(HashSet::new()),
+            mute_list: RwLock::new(MuteList::default()),
             second_degree_follows: RwLock::new(HashSet::new()),
             engagement_history: RwLock::new(EngagementHistory::default()),
             not_interested_posts: RwLock::new(HashSet::new()),
             author_scores: RwLock::new(HashMap::new()),
             subscription_buffers: Arc::new(Mutex::new(HashMap::new())),
             mls,
+            scheduled_posts: Mutex::new(scheduled_posts),
+            follow_history: Mutex::new(follow_history),
+            notifications: Mutex::new(notifications),
+            tombstones: RwLock::new(tombstones),
+            expiry_index: Mutex::new(expiry_index),
+            content_warning_policy: RwLock::new(content_warning_policy),
+            relay_capabilities: RwLock::new(relay_capabilities),
+            report_policy: RwLock::new(report_policy),
+            pending_vanish: Mutex::new(None),
+            migration_log: Mutex::new(migration_log),
+            trusted_subscriptions: Mutex::new(Vec::new()),
         });
 
+        Self::spawn_scheduler(Arc::downgrade(&engine));
+        Self::spawn_deletion_watcher(&engine);
+
         Ok(engine)
     }
 
@@ -146,10 +246,10 @@ impl NuruNuruEngine {
         self.client.connect().await;
     }
 
-    /// Disconnect from all relays.
+    /// Disconnect from all relays and save pending tombstones.
     pub async fn disconnect(&self) -> Result<()> {
         self.client.disconnect().await;
-        Ok(())
+        self.save_tombstones().await
     }
 
     /// Set the current user's public key and load their data.
@@ -179,6 +279,7 @@ impl NuruNuruEngine {
             let mut ml = self.muted_pubkeys.write().await;
             *ml = mutes.into_iter().collect();
         }
+        self.subscribe_trusted_events(pubkey).await;
 
         Ok(())
     }
@@ -188,6 +289,30 @@ impl NuruNuruEngine {
         *self.user_pubkey.read().await
     }
 
+    /// Ensure the client's signer belongs to the logged-in user.
+    ///
+    /// Read-only clients carry an ephemeral relay-auth key; anything signed
+    /// with it would be published under the wrong identity.
+    async fn require_internal_signer(&self) -> Result<PublicKey> {
+        let my_pk = self
+            .current_pubkey()
+            .await
+            .ok_or(NuruNuruError::NoSigningMethod)?;
+        let signer = self
+            .client
+            .signer()
+            .await
+            .map_err(|_| NuruNuruError::NoSigningMethod)?;
+        let signer_pk = signer
+            .get_public_key()
+            .await
+            .map_err(|_| NuruNuruError::PublicKeyFailed)?;
+        if signer_pk != my_pk {
+            return Err(NuruNuruError::NoSigningMethod);
+        }
+        Ok(my_pk)
+    }
+
     // ─── Profile ──────────────────────────────────────────────
 
     /// Fetch and parse a user profile (kind 0).
@@ -269,19 +394,11 @@ impl NuruNuruEngine {
             .fetch_events(filter, Duration::from_secs(10))
             .await?;
 
-        let follows = events
-            .into_iter()
-            .next()
-            .map(|e| {
-                e.tags
-                    .iter()
-                    .filter(|t| t.kind() == TagKind::p())
-                    .filter_map(|t| t.content().map(|s| s.to_string()))
-                    .collect::<Vec<_>>()
-            })
-            .unwrap_or_default();
-
-        Ok(follows)
+        let newest = replaceable::newest(events.iter());
+        if let Some(event) = newest {
+            self.record_follow_list_version(event).await;
+        }
+        Ok(newest.map(contacts::followed_pubkeys).unwrap_or_default())
     }
 
     /// Follow a user (publish updated kind 3).
@@ -289,32 +406,10 @@ impl NuruNuruEngine {
         &self,
         target_pubkey: PublicKey,
     ) -> Result<()> {
-        let my_pk = self
-            .current_pubkey()
-            .await
-            .ok_or(NuruNuruError::NoSigningMethod)?;
-
-        let current_follows = self.fetch_follow_list(my_pk).await?;
-
-        if current_follows.contains(&target_pubkey.to_hex()) {
+        let update = self.update_follows(&[target_pubkey], &[]).await?;
+        if update.added.is_empty() {
             return Err(NuruNuruError::AlreadyFollowing);
         }
-
-        // Build new contact list
-        let mut contacts: Vec<Contact> = current_follows
-            .iter()
-            .filter_map(|hex| PublicKey::from_hex(hex).ok())
-            .map(|pk| Contact::new(pk))
-            .collect();
-        contacts.push(Contact::new(target_pubkey));
-
-        let builder = EventBuilder::contact_list(contacts);
-        self.client.send_event_builder(builder).await?;
-
-        // Update local state
-        let mut fl = self.follow_list.write().await;
-        fl.insert(target_pubkey.to_hex());
-
         Ok(())
     }
 
@@ -323,64 +418,570 @@ impl NuruNuruEngine {
         &self,
         target_pubkey: PublicKey,
     ) -> Result<()> {
+        self.update_follows(&[], &[target_pubkey]).await?;
+        Ok(())
+    }
+
+    /// Follow and unfollow several users in one kind-3 update.
+    ///
+    /// The edit is applied to the newest list known to nostrdb or the
+    /// relays, keeping every existing tag (petnames, relay hints) and the
+    /// content.  Nothing is published when the list is unchanged, and the
+    /// update is refused with `FollowListUnconfirmed` when the newest list
+    /// cannot be confirmed.
+    pub async fn update_follows(
+        &self,
+        follow: &[PublicKey],
+        unfollow: &[PublicKey],
+    ) -> Result<FollowListUpdate> {
+        let my_pk = self.require_internal_signer().await?;
+        let base = self.confirmed_contact_list(my_pk).await?;
+        let edit = contacts::apply_edit(base.as_ref(), follow, unfollow);
+        let follows = edit.follows();
+
+        let event_id = if edit.is_noop() {
+            None
+        } else {
+            Some(self.publish_contact_list(&edit).await?.to_hex())
+        };
+
+        // Update local state
+        *self.follow_list.write().await = follows.iter().cloned().collect();
+        if !edit.is_noop() {
+            self.subscribe_trusted_events(my_pk).await;
+        }
+
+        Ok(FollowListUpdate {
+            added: edit.added,
+            removed: edit.removed,
+            follow_count: follows.len() as u32,
+            event_id,
+        })
+    }
+
+    /// Newest kind-3 of `pubkey` across nostrdb and the relays.
+    ///
+    /// Errors with `FollowListUnconfirmed` when no relay answered with EOSE,
+    /// or no list is found although follows are known locally.  `Ok(None)`
+    /// means a relay confirmed the account has no list yet.
+    async fn confirmed_contact_list(&self, pubkey: PublicKey) -> Result<Option<Event>> {
+        let filter = filters::follow_list_filter(pubkey);
+        let local = self.query_local(filter.clone()).await?;
+        let remote = self
+            .fetch_confirmed(filter)
+            .await
+            .ok_or(NuruNuruError::FollowListUnconfirmed)?;
+
+        let newest = replaceable::newest(local.iter().chain(remote.iter())).cloned();
+        match &newest {
+            Some(event) => self.record_follow_list_version(event).await,
+            None if !self.follow_list.read().await.is_empty() => {
+                return Err(NuruNuruError::FollowListUnconfirmed);
+            }
+            None => {}
+        }
+        Ok(newest)
+    }
+
+    /// Sign and publish a kind-3 built from `edit`, recording it in the
+    /// follow-list history.
+    async fn publish_contact_list(&self, edit: &contacts::ContactListEdit) -> Result<EventId> {
+        let builder = EventBuilder::new(Kind::ContactList, edit.content.clone()).tags(edit.to_tags());
+        let event = self.client.sign_event_builder(builder).await?;
+        let output = self.client.send_event(&event).await?;
+        self.record_follow_list_version(&event).await;
+        Ok(output.val)
+    }
+
+    /// Keep `event` in the follow-list history if it is the logged-in
+    /// user's kind 3.  Failures are logged, never surfaced.
+    async fn record_follow_list_version(&self, event: &Event) {
+        if self.current_pubkey().await != Some(event.pubkey) {
+            return;
+        }
+        if let Err(e) = self.follow_history.lock().await.record(event) {
+            tracing::warn!("[follow_history] Failed to record version {}: {e}", event.id);
+        }
+    }
+
+    /// Stored versions of the logged-in user's follow list, newest first,
+    /// each with the follows added / removed relative to the one before.
+    pub async fn list_follow_list_versions(&self) -> Result<Vec<FollowListVersion>> {
         let my_pk = self
             .current_pubkey()
             .await
             .ok_or(NuruNuruError::NoSigningMethod)?;
+        // Pick up whatever nostrdb holds that the history has not seen yet.
+        let local = self.query_local(filters::follow_list_filter(my_pk)).await?;
+        for event in &local {
+            self.record_follow_list_version(event).await;
+        }
+        Ok(self.follow_history.lock().await.versions(&my_pk))
+    }
 
-        let current_follows = self.fetch_follow_list(my_pk).await?;
-
-        let target_hex = target_pubkey.to_hex();
-        let contacts: Vec<Contact> = current_follows
-            .iter()
-            .filter(|hex| *hex != &target_hex)
-            .filter_map(|hex| PublicKey::from_hex(hex).ok())
-            .map(|pk| Contact::new(pk))
+    /// Republish a stored follow-list version as a new kind 3.
+    ///
+    /// The version's tags and content are re-signed with a fresh timestamp
+    /// (relays would ignore the old event as outdated).  The list being
+    /// replaced is confirmed and recorded first, so a restore can itself be
+    /// undone.
+    pub async fn restore_follow_list(&self, version_event_id: &str) -> Result<FollowListUpdate> {
+        let my_pk = self.require_internal_signer().await?;
+        let version = self
+            .follow_history
+            .lock()
+            .await
+            .get(&my_pk, version_event_id)
+            .cloned()
+            .ok_or_else(|| {
+                NuruNuruError::ValidationError(format!(
+                    "フォローリストの履歴が見つかりません: {version_event_id}"
+                ))
+            })?;
+
+        let current = self.confirmed_contact_list(my_pk).await?;
+        let before: HashSet<String> = current
+            .as_ref()
+            .map(contacts::followed_pubkeys)
+            .unwrap_or_default()
+            .into_iter()
             .collect();
+        let follows = contacts::followed_pubkeys(&version);
+        let after: HashSet<&String> = follows.iter().collect();
+
+        let edit = contacts::ContactListEdit {
+            tags: version.tags.iter().map(|t| t.as_slice().to_vec()).collect(),
+            content: version.content.clone(),
+            added: follows.iter().filter(|pk| !before.contains(*pk)).cloned().collect(),
+            removed: before.iter().filter(|pk| !after.contains(pk)).cloned().collect(),
+        };
+        let event_id = self.publish_contact_list(&edit).await?;
 
-        let builder = EventBuilder::contact_list(contacts);
-        self.client.send_event_builder(builder).await?;
-
-        // Update local state
-        let mut fl = self.follow_list.write().await;
-        fl.remove(&target_hex);
+        *self.follow_list.write().await = follows.iter().cloned().collect();
+        self.subscribe_trusted_events(my_pk).await;
 
-        Ok(())
+        Ok(FollowListUpdate {
+            added: edit.added,
+            removed: edit.removed,
+            follow_count: follows.len() as u32,
+            event_id: Some(event_id.to_hex()),
+        })
     }
 
     // ─── Mute List (NIP-51) ────────────────────────────────────
 
     /// Fetch mute list (kind 10000).
+    ///
+    /// Returns the muted pubkeys; for the logged-in user this includes the
+    /// private (encrypted) entries.  Use `fetch_full_mute_list` for words,
+    /// hashtags and threads.
     pub async fn fetch_mute_list(&self, pubkey: PublicKey) -> Result<Vec<String>> {
+        let list = self.fetch_full_mute_list(pubkey).await?;
+        let mut seen = HashSet::new();
+        Ok(list
+            .public
+            .pubkeys
+            .into_iter()
+            .chain(list.private.pubkeys)
+            .filter(|pk| seen.insert(pk.clone()))
+            .collect())
+    }
+
+    /// Fetch the full NIP-51 mute list (kind 10000) of `pubkey`.
+    ///
+    /// Private entries are decrypted only for the logged-in user's own list
+    /// (internal signer); fetching it also makes it the active mute list.
+    /// When the relays can't be reached the nostrdb copy is used.
+    pub async fn fetch_full_mute_list(&self, pubkey: PublicKey) -> Result<MuteList> {
         let filter = filters::mute_list_filter(pubkey);
-        let events = self
+        let events = match self
             .client
-            .fetch_events(filter, Duration::from_secs(10))
+            .fetch_events(filter.clone(), Duration::from_secs(10))
+            .await
+        {
+            Ok(events) => events.into_iter().collect(),
+            Err(e) => {
+                tracing::warn!("[mute] Relay fetch failed, using local copy: {e}");
+                Vec::new()
+            }
+        };
+        let local = self.query_local(filter).await.unwrap_or_default();
+
+        let Some(event) = replaceable::newest(events.iter().chain(local.iter())) else {
+            return Ok(MuteList::default());
+        };
+        let list = self.parse_mute_list(event).await;
+        if self.current_pubkey().await == Some(pubkey) {
+            self.set_mute_list(list.clone()).await;
+        }
+        Ok(list)
+    }
+
+    /// The active (logged-in user's) mute list, as last fetched or edited.
+    pub async fn get_mute_list(&self) -> MuteList {
+        self.mute_list.read().await.clone()
+    }
+
+    /// Add an entry to the logged-in user's mute list and re-publish it.
+    ///
+    /// `private` entries are stored in the NIP-44 encrypted section; an
+    /// entry already in the other section is moved.
+    pub async fn mute(&self, kind: MuteKind, value: &str, private: bool) -> Result<MuteList> {
+        match kind {
+            MuteKind::Pubkey => {
+                PublicKey::from_hex(value.trim())
+                    .map_err(|e| NuruNuruError::ValidationError(format!("無効な公開鍵: {e}")))?;
+            }
+            MuteKind::Thread => {
+                EventId::from_hex(value.trim())
+                    .map_err(|e| NuruNuruError::ValidationError(format!("無効なイベントID: {e}")))?;
+            }
+            MuteKind::Word | MuteKind::Hashtag => {}
+        }
+        self.edit_mute_list(|list| {
+            let (from, to) = if private {
+                (&mut list.public, &mut list.private)
+            } else {
+                (&mut list.private, &mut list.public)
+            };
+            let moved = from.remove(kind, value);
+            to.insert(kind, value) || moved
+        })
+        .await
+    }
+
+    /// Remove an entry (public or private) from the logged-in user's mute
+    /// list and re-publish it.
+    pub async fn unmute(&self, kind: MuteKind, value: &str) -> Result<MuteList> {
+        self.edit_mute_list(|list| {
+            let public = list.public.remove(kind, value);
+            let private = list.private.remove(kind, value);
+            public || private
+        })
+        .await
+    }
+
+    /// Apply `edit` to the newest mute list and publish the result,
+    /// re-encrypting the private section.  `edit` returns `false` when
+    /// nothing changed, in which case nothing is published.
+    async fn edit_mute_list(&self, edit: impl FnOnce(&mut MuteList) -> bool) -> Result<MuteList> {
+        let my_pk = self.require_internal_signer().await?;
+        let unconfirmed = || NuruNuruError::ValidationError("最新のミュートリストを確認できません".to_string());
+        let filter = filters::mute_list_filter(my_pk);
+        let local = self.query_local(filter.clone()).await?;
+        let remote = self.fetch_confirmed(filter).await.ok_or_else(unconfirmed)?;
+
+        let mut list = match replaceable::newest(local.iter().chain(remote.iter())) {
+            Some(event) => self.parse_mute_list(event).await,
+            None => {
+                let known = self.mute_list.read().await;
+                if !known.public.is_empty() || !known.private.is_empty() {
+                    return Err(unconfirmed());
+                }
+                MuteList::default()
+            }
+        };
+        if list.private_locked {
+            return Err(NuruNuruError::DecryptionFailed(
+                "非公開ミュートを復号できないため更新できません".to_string(),
+            ));
+        }
+        if !edit(&mut list) {
+            return Ok(list);
+        }
+
+        let event = self
+            .publish_private_list(Kind::MuteList, list.public.to_tags(), list.private.to_tags())
             .await?;
 
-        let muted = events
-            .into_iter()
-            .next()
-            .map(|e| {
-                e.tags
-                    .iter()
-                    .filter(|t| t.kind() == TagKind::p())
-                    .filter_map(|t| t.content().map(|s| s.to_string()))
-                    .collect::<Vec<_>>()
+        list.event_id = Some(event.id.to_hex());
+        list.created_at = event.created_at.as_secs();
+        self.set_mute_list(list.clone()).await;
+        Ok(list)
+    }
+
+    /// Parse a kind-10000 event, decrypting its private section when it is
+    /// the logged-in user's own list.
+    async fn parse_mute_list(&self, event: &Event) -> MuteList {
+        let mut list = MuteList {
+            public: MuteEntries::from_tags(event.tags.iter().map(|t| t.as_slice())),
+            event_id: Some(event.id.to_hex()),
+            created_at: event.created_at.as_secs(),
+            ..Default::default()
+        };
+        if event.content.trim().is_empty() {
+            return list;
+        }
+        match self.decrypt_private_tags(event).await {
+            Ok(tags) => list.private = MuteEntries::from_tags(tags.iter().map(Vec::as_slice)),
+            Err(e) => {
+                tracing::debug!("[mute_list] Private entries unavailable: {e}");
+                list.private_locked = true;
+            }
+        }
+        list
+    }
+
+    /// Decrypt the NIP-51 private tag array in `event.content` (NIP-44, or
+    /// NIP-04 for legacy lists).  Only possible for the user's own lists.
+    async fn decrypt_private_tags(&self, event: &Event) -> Result<Vec<Vec<String>>> {
+        let my_pk = self.require_internal_signer().await?;
+        if event.pubkey != my_pk {
+            return Err(NuruNuruError::DecryptionFailed(
+                "他のユーザーの非公開リストは復号できません".to_string(),
+            ));
+        }
+        let signer = self
+            .client
+            .signer()
+            .await
+            .map_err(|_| NuruNuruError::NoSigningMethod)?;
+        let plaintext = if mute::is_nip04_payload(&event.content) {
+            signer.nip04_decrypt(&my_pk, &event.content).await
+        } else {
+            signer.nip44_decrypt(&my_pk, &event.content).await
+        }
+        .map_err(|e| NuruNuruError::DecryptionFailed(e.to_string()))?;
+        Ok(serde_json::from_str(&plaintext)?)
+    }
+
+    /// Sign and publish a NIP-51 list whose `private_tags` are NIP-44
+    /// encrypted to self in `content` (left empty when there are none).
+    async fn publish_private_list(
+        &self,
+        kind: Kind,
+        public_tags: Vec<Vec<String>>,
+        private_tags: Vec<Vec<String>>,
+    ) -> Result<Event> {
+        let my_pk = self.require_internal_signer().await?;
+        let content = if private_tags.is_empty() {
+            String::new()
+        } else {
+            let json = serde_json::to_string(&private_tags)?;
+            let signer = self
+                .client
+                .signer()
+                .await
+                .map_err(|_| NuruNuruError::NoSigningMethod)?;
+            signer
+                .nip44_encrypt(&my_pk, &json)
+                .await
+                .map_err(|e| NuruNuruError::EncryptionFailed(e.to_string()))?
+        };
+        let builder = EventBuilder::new(kind, content).tags(public_tags.into_iter().map(make_tag));
+        let event = self.client.sign_event_builder(builder).await?;
+        self.client.send_event(&event).await?;
+        Ok(event)
+    }
+
+    /// Make `list` the active mute list.
+    async fn set_mute_list(&self, list: MuteList) {
+        *self.muted_pubkeys.write().await = list
+            .public
+            .pubkeys
+            .iter()
+            .chain(list.private.pubkeys.iter())
+            .cloned()
+            .collect();
+        *self.mute_list.write().await = list;
+    }
+
+    /// Compiled mute rules: the active mute list plus any pubkeys restored
+    /// through `load_user_state`.
+    async fn mute_filter(&self) -> MuteFilter {
+        let mut filter = MuteFilter::new(&*self.mute_list.read().await);
+        filter.add_pubkeys(self.muted_pubkeys.read().await.iter().cloned());
+        filter
+    }
+
+    /// Drop events hidden by the active mute list (users, words, hashtags,
+    /// threads).
+    pub async fn filter_muted(&self, mut events: Vec<Event>) -> Vec<Event> {
+        let filter = self.mute_filter().await;
+        events.retain(|e| !filter.is_muted(e));
+        events
+    }
+
+    // ─── Bookmarks (NIP-51) ────────────────────────────────────
+
+    /// Fetch a user's default bookmark list (kind 10003).  Private entries
+    /// are decrypted only for the logged-in user's own list.
+    pub async fn fetch_bookmarks(&self, pubkey: PublicKey) -> Result<BookmarkList> {
+        let events = self.fetch_cached(filters::bookmark_filter(pubkey), false).await?;
+        Ok(match replaceable::newest(events.iter()) {
+            Some(event) => self.parse_bookmark_list(event).await,
+            None => BookmarkList::default(),
+        })
+    }
+
+    /// Fetch a user's named bookmark sets (kind 30003), newest version of
+    /// each, ordered by identifier.
+    pub async fn fetch_bookmark_sets(&self, pubkey: PublicKey) -> Result<Vec<BookmarkList>> {
+        let events = self
+            .fetch_cached(filters::bookmark_sets_filter(pubkey, None), false)
+            .await?;
+
+        let mut by_identifier: HashMap<&str, &Event> = HashMap::new();
+        for event in &events {
+            let d = tags::first_tag_value(event, "d").unwrap_or("");
+            let newest = match by_identifier.get(d) {
+                Some(current) => replaceable::newest([*current, event]).unwrap_or(event),
+                None => event,
+            };
+            by_identifier.insert(d, newest);
+        }
+
+        let mut sets = Vec::with_capacity(by_identifier.len());
+        for event in by_identifier.into_values() {
+            sets.push(self.parse_bookmark_list(event).await);
+        }
+        sets.sort_by(|a, b| a.identifier.cmp(&b.identifier));
+        Ok(sets)
+    }
+
+    /// Bookmark an entry in the default list, or in the bookmark set `set`
+    /// (created if it does not exist yet).
+    ///
+    /// `private` entries are NIP-44 encrypted to self; an entry already in
+    /// the other section is moved.  Bookmarking a note counts as a
+    /// "bookmark" engagement with its author.
+    pub async fn add_bookmark(
+        &self,
+        kind: BookmarkKind,
+        value: &str,
+        private: bool,
+        set: Option<&str>,
+    ) -> Result<BookmarkList> {
+        let event_id = match kind {
+            BookmarkKind::Event => Some(
+                EventId::from_hex(value.trim())
+                    .map_err(|e| NuruNuruError::ValidationError(format!("無効なイベントID: {e}")))?,
+            ),
+            BookmarkKind::Address => {
+                Coordinate::parse(value.trim())
+                    .map_err(|e| NuruNuruError::ValidationError(format!("無効なアドレス: {e}")))?;
+                None
+            }
+            BookmarkKind::Hashtag | BookmarkKind::Url => None,
+        };
+
+        let list = self
+            .edit_bookmark_list(set, |list| {
+                let (from, to) = if private {
+                    (&mut list.public, &mut list.private)
+                } else {
+                    (&mut list.private, &mut list.public)
+                };
+                let moved = from.remove(kind, value);
+                to.insert(kind, value) || moved
             })
-            .unwrap_or_default();
+            .await?;
+
+        if let Some(id) = event_id {
+            if let Ok(Some(target)) = self.lookup_event(id, false).await {
+                self.record_engagement("bookmark", &target.pubkey.to_hex()).await;
+            }
+        }
+        Ok(list)
+    }
+
+    /// Remove an entry (public or private) from the default list or the
+    /// bookmark set `set`.
+    pub async fn remove_bookmark(
+        &self,
+        kind: BookmarkKind,
+        value: &str,
+        set: Option<&str>,
+    ) -> Result<BookmarkList> {
+        self.edit_bookmark_list(set, |list| {
+            let public = list.public.remove(kind, value);
+            let private = list.private.remove(kind, value);
+            public || private
+        })
+        .await
+    }
+
+    /// Apply `edit` to the newest default list / bookmark set and publish
+    /// the result.  `edit` returns `false` when nothing changed.
+    async fn edit_bookmark_list(
+        &self,
+        set: Option<&str>,
+        edit: impl FnOnce(&mut BookmarkList) -> bool,
+    ) -> Result<BookmarkList> {
+        let my_pk = self.require_internal_signer().await?;
+        let filter = match set {
+            Some(d) => filters::bookmark_sets_filter(my_pk, Some(d)),
+            None => filters::bookmark_filter(my_pk),
+        };
+        let local = self.query_local(filter.clone()).await?;
+        let remote = self.fetch_confirmed(filter).await.ok_or_else(|| {
+            NuruNuruError::RelayError("最新のブックマークをリレーで確認できません".to_string())
+        })?;
+
+        let mut list = match replaceable::newest(local.iter().chain(remote.iter())) {
+            Some(event) => self.parse_bookmark_list(event).await,
+            None => BookmarkList {
+                identifier: set.map(str::to_string),
+                ..Default::default()
+            },
+        };
+        if list.private_locked {
+            return Err(NuruNuruError::DecryptionFailed(
+                "非公開ブックマークを復号できないため更新できません".to_string(),
+            ));
+        }
+        if !edit(&mut list) {
+            return Ok(list);
+        }
+
+        let event = self
+            .publish_private_list(Kind::Custom(list.kind()), list.public_tags(), list.private.to_tags())
+            .await?;
+        list.event_id = Some(event.id.to_hex());
+        list.created_at = event.created_at.as_secs();
+        Ok(list)
+    }
 
-        Ok(muted)
+    /// Parse a kind-10003 / 30003 event, decrypting its private section
+    /// when it is the logged-in user's own list.
+    async fn parse_bookmark_list(&self, event: &Event) -> BookmarkList {
+        let mut list = BookmarkList::from_event(event);
+        if event.content.trim().is_empty() {
+            return list;
+        }
+        match self.decrypt_private_tags(event).await {
+            Ok(tags) => list.private = BookmarkEntries::from_tags(tags.iter().map(Vec::as_slice)),
+            Err(e) => {
+                tracing::debug!("[bookmarks] Private entries unavailable: {e}");
+                list.private_locked = true;
+            }
+        }
+        list
     }
 
     // ─── Timeline ──────────────────────────────────────────────
 
     /// Fetch timeline events (notes + reposts) for the given authors.
+    /// Posts reported by enough followed accounts are dropped.
     pub async fn fetch_timeline(
         &self,
         authors: Option<&[PublicKey]>,
         since: Option<Timestamp>,
         limit: usize,
+    ) -> Result<Vec<Event>> {
+        let mut events = self.fetch_timeline_events(authors, since, limit).await?;
+        let reports = self.trusted_reports(&events).await;
+        events.retain(|e| reports.action(&e.id.to_hex(), &e.pubkey.to_hex()) != ReportAction::Hide);
+        Ok(events)
+    }
+
+    /// `fetch_timeline` without report filtering, for the recommendation
+    /// pipeline (which scores reports itself).
+    async fn fetch_timeline_events(
+        &self,
+        authors: Option<&[PublicKey]>,
+        since: Option<Timestamp>,
+        limit: usize,
     ) -> Result<Vec<Event>> {
         let tl_filters = filters::timeline_filters(
             authors,
@@ -401,6 +1002,8 @@ impl NuruNuruEngine {
 
         // Sort by created_at descending
         all_events.sort_by(|a, b| b.created_at.cmp(&a.created_at));
+        let all_events = self.purge_deleted(all_events).await;
+        let mut all_events = self.filter_muted(all_events).await;
         all_events.truncate(limit);
 
         Ok(all_events)
@@ -432,6 +1035,7 @@ impl NuruNuruEngine {
             self.client.fetch_events(zaps_filter, timeout),
         );
 
+        let tombstones = self.tombstones.read().await;
         let mut engagement: HashMap<String, EngagementData> = HashMap::new();
 
         // Initialize
@@ -441,7 +1045,7 @@ impl NuruNuruEngine {
 
         // Count reactions (likes)
         if let Ok(events) = reactions {
-            for event in events {
+            for event in events.into_iter().filter(|e| !tombstones.is_deleted(e)) {
                 if let Some(target) = event
                     .tags
                     .iter()
@@ -457,7 +1061,7 @@ impl NuruNuruEngine {
 
         // Count reposts
         if let Ok(events) = reposts {
-            for event in events {
+            for event in events.into_iter().filter(|e| !tombstones.is_deleted(e)) {
                 if let Some(target) = event
                     .tags
                     .iter()
@@ -506,6 +1110,143 @@ impl NuruNuruEngine {
         Ok(engagement)
     }
 
+    /// Per-emoji reactions (with reactors and custom emoji URLs) and
+    /// zappers sorted by amount for one event.  Muted and deleted reactions
+    /// are left out.
+    pub async fn fetch_reaction_details(&self, event_id: EventId) -> Result<ReactionDetails> {
+        let (reaction_events, zap_events) = tokio::join!(
+            self.fetch_cached(filters::reaction_filter(&[event_id], 1000), false),
+            self.fetch_cached(filters::zap_filter(&[event_id], 500), false),
+        );
+        let mutes = self.mute_filter().await;
+        let mut reaction_events = reaction_events?;
+        reaction_events.retain(|e| !mutes.is_muted_pubkey(&e.pubkey.to_hex()));
+        let mut zap_events = zap_events?;
+        zap_events.retain(|e| zap::ZapReceipt::parse(e).is_some_and(|z| !mutes.is_muted_pubkey(&z.sender)));
+
+        let me = self.current_pubkey().await.map(|pk| pk.to_hex());
+        Ok(reactions::summarize(
+            &event_id.to_hex(),
+            &reaction_events,
+            &zap_events,
+            me.as_deref(),
+        ))
+    }
+
+    // ─── Threads (NIP-10) ──────────────────────────────────────
+
+    /// Reconstruct the full thread around `event_id`.
+    ///
+    /// Resolves the root from NIP-10 markers (positional fallback for old
+    /// events), walks parents up from the focused event and replies down from
+    /// the root, then returns the thread flattened depth-first with reply
+    /// counts and `missing` placeholders for parents that could not be found.
+    ///
+    /// nostrdb is always read first; with `cache_only` no relay is contacted,
+    /// so the UI can render instantly and refresh with a second call.
+    pub async fn fetch_thread(&self, event_id: EventId, cache_only: bool) -> Result<ThreadView> {
+        let focus = self
+            .lookup_event(event_id, cache_only)
+            .await?
+            .ok_or_else(|| NuruNuruError::EventError(format!("イベントが見つかりません: {event_id}")))?;
+
+        let root_hex = thread::root_id(&focus);
+        let root_id = EventId::from_hex(&root_hex)
+            .map_err(|e| NuruNuruError::NostrProtocol(e.to_string()))?;
+
+        let mut events: HashMap<EventId, Event> = HashMap::new();
+        let mut parent = thread::thread_refs(&focus).parent_id().map(str::to_string);
+        events.insert(focus.id, focus);
+
+        // Ancestors: follow parent links up from the focused event.
+        for _ in 0..THREAD_MAX_ANCESTORS {
+            let Some(pid) = parent.take().and_then(|hex| EventId::from_hex(&hex).ok()) else {
+                break;
+            };
+            if events.contains_key(&pid) {
+                break;
+            }
+            let Some(event) = self.lookup_event(pid, cache_only).await? else {
+                break;
+            };
+            parent = thread::thread_refs(&event).parent_id().map(str::to_string);
+            events.insert(event.id, event);
+        }
+
+        if !events.contains_key(&root_id) {
+            if let Some(root) = self.lookup_event(root_id, cache_only).await? {
+                events.insert(root.id, root);
+            }
+        }
+
+        // Descendants: breadth-first over `#e` references starting at the root.
+        let mut frontier = vec![root_id];
+        let mut queried: HashSet<EventId> = HashSet::new();
+        for _ in 0..THREAD_MAX_ROUNDS {
+            frontier.retain(|id| queried.insert(*id));
+            if frontier.is_empty() {
+                break;
+            }
+            let found = self
+                .fetch_cached(filters::reply_filter(&frontier, 500), cache_only)
+                .await?;
+            frontier = Vec::new();
+            for event in found {
+                if !events.contains_key(&event.id) {
+                    frontier.push(event.id);
+                    events.insert(event.id, event);
+                }
+            }
+        }
+
+        // Muted replies and ancestors are dropped; the focused event stays
+        // since the user opened it.
+        let mutes = self.mute_filter().await;
+        let all: Vec<Event> = events
+            .into_values()
+            .filter(|e| e.id == event_id || !mutes.is_muted(e))
+            .collect();
+        Ok(thread::build_thread(&root_hex, &event_id.to_hex(), &all))
+    }
+
+    /// Look up a single event: nostrdb first, then relays unless `cache_only`.
+    /// Deleted or expired events are not returned.
+    async fn lookup_event(&self, event_id: EventId, cache_only: bool) -> Result<Option<Event>> {
+        let local = self
+            .client
+            .database()
+            .event_by_id(&event_id)
+            .await
+            .map_err(|e| NuruNuruError::DatabaseError(e.to_string()))?;
+        let found: Vec<Event> = match local {
+            Some(event) => vec![event],
+            None if cache_only => return Ok(None),
+            None => self
+                .client
+                .fetch_events(Filter::new().id(event_id).limit(1), Duration::from_secs(10))
+                .await?
+                .into_iter()
+                .collect(),
+        };
+        Ok(self.purge_deleted(found).await.into_iter().next())
+    }
+
+    /// Query nostrdb and, unless `cache_only`, merge in a relay fetch.
+    /// Relay failures are logged and the cached result is returned.
+    async fn fetch_cached(&self, filter: Filter, cache_only: bool) -> Result<Vec<Event>> {
+        let mut events = self.query_db(filter.clone()).await?;
+        if !cache_only {
+            match self.client.fetch_events(filter, Duration::from_secs(10)).await {
+                Ok(remote) => {
+                    let seen: HashSet<EventId> = events.iter().map(|e| e.id).collect();
+                    events.extend(remote.into_iter().filter(|e| !seen.contains(&e.id)));
+                }
+                Err(e) => tracing::warn!("[fetch_cached] Relay fetch failed, using cache only: {e}"),
+            }
+        }
+        Ok(self.purge_deleted(events).await)
+    }
+
     // ─── Recommended Feed ──────────────────────────────────────
 
     /// Shared recommendation pipeline.
@@ -519,7 +1260,7 @@ impl NuruNuruEngine {
         user_geohash: Option<&str>,
     ) -> Result<(HashMap<String, Event>, Vec<ScoredPost>)> {
         let follow_list = self.follow_list.read().await.clone();
-        let muted = self.muted_pubkeys.read().await.clone();
+        let mutes = self.mute_filter().await;
         let second_degree = self.second_degree_follows.read().await.clone();
         let engagement_history = self.engagement_history.read().await.clone();
         let not_interested = self.not_interested_posts.read().await.clone();
@@ -539,21 +1280,25 @@ impl NuruNuruEngine {
         // Parallel fetch: network candidates (follow+2nd-degree, 48h) and
         // out-of-network viral candidates (global, last 1h).
         let (network_result, viral_result) = tokio::join!(
-            self.fetch_timeline(
+            self.fetch_timeline_events(
                 if author_pks.is_empty() { None } else { Some(&author_pks) },
                 Some(since_48h),
                 limit * 2,
             ),
-            self.fetch_timeline(None, Some(since_1h), limit),
+            self.fetch_timeline_events(None, Some(since_1h), limit),
         );
 
         let network_events = network_result.unwrap_or_default();
         let viral_events = viral_result.unwrap_or_default();
+        let hide_warned = *self.content_warning_policy.read().await == ContentWarningPolicy::Hide;
 
         // Merge and deduplicate by event ID
         let mut seen_ids: HashSet<EventId> = HashSet::new();
         let mut all_events: Vec<Event> = Vec::with_capacity(network_events.len() + viral_events.len());
         for event in network_events.into_iter().chain(viral_events.into_iter()) {
+            if hide_warned && content::content_warning(&event).is_some() {
+                continue;
+            }
             if seen_ids.insert(event.id) {
                 all_events.push(event);
             }
@@ -567,11 +1312,12 @@ impl NuruNuruEngine {
             .into_iter()
             .collect();
 
-        // Parallel: engagement data + author profiles
+        // Parallel: engagement data + author profiles + trusted reports
         let event_ids: Vec<EventId> = all_events.iter().map(|e| e.id).collect();
-        let (engagements_result, profiles_result) = tokio::join!(
+        let (engagements_result, profiles_result, reports) = tokio::join!(
             self.fetch_engagement_data(&event_ids),
             self.fetch_profiles(&unique_authors),
+            self.trusted_reports(&all_events),
         );
         let engagements = engagements_result.unwrap_or_default();
         let profiles = profiles_result.unwrap_or_default();
@@ -591,13 +1337,15 @@ impl NuruNuruEngine {
 
         let scored = self.recommendation.rank_feed(
             &posts,
+            &event_map,
             &engagements,
             &follow_list,
             &second_degree,
             &HashSet::new(),
             &engagement_history,
             &profiles,
-            &muted,
+            &mutes,
+            &reports,
             &not_interested,
             &author_scores,
             user_geohash,
@@ -639,85 +1387,2014 @@ impl NuruNuruEngine {
         Ok(ordered)
     }
 
-    // ─── DMs (NIP-17) ──────────────────────────────────────────
+    // ─── Notifications ─────────────────────────────────────────
 
-    /// Send an encrypted DM using NIP-17 gift wrapping.
-    /// All seal/wrap layers are handled by `nostr-sdk`.
-    pub async fn send_dm(
+    /// Fetch notifications for the logged-in user between `since` and
+    /// `until`: mentions, replies, quotes, reactions, reposts, zaps and new
+    /// followers, grouped and with muted users / content removed.
+    pub async fn fetch_notifications(
         &self,
-        recipient: PublicKey,
-        content: &str,
-    ) -> Result<()> {
-        self.client
-            .send_private_msg(recipient, content, [])
+        since: Option<Timestamp>,
+        until: Option<Timestamp>,
+        limit: usize,
+    ) -> Result<NotificationPage> {
+        let my_pk = self
+            .current_pubkey()
+            .await
+            .ok_or(NuruNuruError::NoSigningMethod)?;
+        self.notification_page(my_pk, since, until, limit).await
+    }
+
+    /// Notifications that arrived since the previous call (the last day on
+    /// the first call), for background polling.
+    ///
+    /// Each poll re-queries `POLL_OVERLAP_SECS` before the newest
+    /// notification already returned, so events that reach the relays late
+    /// are still picked up; ones returned before are not repeated.
+    pub async fn fetch_new_notifications(&self, limit: usize) -> Result<NotificationPage> {
+        let my_pk = self
+            .current_pubkey()
+            .await
+            .ok_or(NuruNuruError::NoSigningMethod)?;
+        let since = self
+            .notifications
+            .lock()
+            .await
+            .state(&my_pk)
+            .last_checked
+            .map(|at| Timestamp::from(at.saturating_sub(notifications::POLL_OVERLAP_SECS)))
+            .unwrap_or_else(|| filters::since_days_ago(1));
+
+        let (mut items, oldest_at) = self.notification_items(my_pk, Some(since), None, limit).await?;
+        let mut store = self.notifications.lock().await;
+        store.take_new(&my_pk, &mut items, Timestamp::now().as_secs())?;
+        let mut page = notifications::group(items, &store.state(&my_pk));
+        page.oldest_at = oldest_at;
+        Ok(page)
+    }
+
+    /// Mark notification events (`NotificationGroup::event_ids`) as read.
+    pub async fn mark_notifications_read(&self, event_ids: &[String]) -> Result<()> {
+        let my_pk = self
+            .current_pubkey()
+            .await
+            .ok_or(NuruNuruError::NoSigningMethod)?;
+        self.notifications.lock().await.mark_read(&my_pk, event_ids)
+    }
+
+    /// Mark every notification up to now as read.
+    pub async fn mark_all_notifications_read(&self) -> Result<()> {
+        let my_pk = self
+            .current_pubkey()
+            .await
+            .ok_or(NuruNuruError::NoSigningMethod)?;
+        self.notifications
+            .lock()
+            .await
+            .mark_all_read(&my_pk, Timestamp::now().as_secs())
+    }
+
+    async fn notification_page(
+        &self,
+        my_pk: PublicKey,
+        since: Option<Timestamp>,
+        until: Option<Timestamp>,
+        limit: usize,
+    ) -> Result<NotificationPage> {
+        let (items, oldest_at) = self.notification_items(my_pk, since, until, limit).await?;
+        let mut page = notifications::group(items, &self.notifications.lock().await.state(&my_pk));
+        page.oldest_at = oldest_at;
+        Ok(page)
+    }
+
+    /// Classified notifications in the window, with the oldest `created_at`
+    /// fetched (for paging).
+    async fn notification_items(
+        &self,
+        my_pk: PublicKey,
+        since: Option<Timestamp>,
+        until: Option<Timestamp>,
+        limit: usize,
+    ) -> Result<(Vec<notifications::Notification>, Option<u64>)> {
+        let filter = filters::notification_filter(my_pk, since, until, limit);
+        let events = self.fetch_cached(filter, false).await?;
+        let oldest_at = events.iter().map(|e| e.created_at.as_secs()).min();
+
+        let mutes = self.mute_filter().await;
+        let mut items: Vec<notifications::Notification> = events
+            .iter()
+            .filter(|e| !mutes.is_muted(e))
+            .filter_map(|e| notifications::classify(e, &my_pk))
+            .filter(|n| !mutes.is_muted_pubkey(&n.actor))
+            .collect();
+
+        self.notifications.lock().await.note_followers(&my_pk, &mut items)?;
+        // Follows are re-dated to when they were first seen, which may fall
+        // outside the requested window.
+        let window = since.map_or(0, |t| t.as_secs())..=until.map_or(u64::MAX, |t| t.as_secs());
+        items.retain(|n| window.contains(&n.created_at));
+        Ok((items, oldest_at))
+    }
+
+    // ─── Reports (NIP-56) ──────────────────────────────────────
+
+    /// Report `event` by `author` (internal signer only).  Returns the
+    /// report event ID.
+    pub async fn report_event(
+        &self,
+        event: EventId,
+        author: PublicKey,
+        report_type: ReportType,
+        reason: &str,
+    ) -> Result<EventId> {
+        self.require_internal_signer().await?;
+        self.send_builder(reports::build_report(author, Some(event), report_type, reason))
+            .await
+    }
+
+    /// Report the account `pubkey` (internal signer only).
+    pub async fn report_user(
+        &self,
+        pubkey: PublicKey,
+        report_type: ReportType,
+        reason: &str,
+    ) -> Result<EventId> {
+        self.require_internal_signer().await?;
+        self.send_builder(reports::build_report(pubkey, None, report_type, reason))
+            .await
+    }
+
+    /// Build an **unsigned** report by `reporter` of `pubkey` (or of its
+    /// `event`) for external signers.
+    pub fn build_report(
+        &self,
+        reporter: PublicKey,
+        pubkey: PublicKey,
+        event: Option<EventId>,
+        report_type: ReportType,
+        reason: &str,
+    ) -> UnsignedEvent {
+        reports::build_report(pubkey, event, report_type, reason).build(reporter)
+    }
+
+    /// Reports of `events` and their authors by followed accounts and the
+    /// user, indexed with the current policy.  Read from nostrdb only: the
+    /// live subscription (`subscribe_trusted_events`) keeps it current, so
+    /// loading a timeline costs no relay round-trip.  Lookup failures yield
+    /// no reports rather than failing the feed.
+    async fn trusted_reports(&self, events: &[Event]) -> TrustedReports {
+        let policy = *self.report_policy.read().await;
+        let mut trusted = self.follow_list.read().await.clone();
+        if let Some(me) = self.current_pubkey().await {
+            trusted.insert(me.to_hex());
+        }
+        let disabled = policy.downrank_threshold == 0 && policy.hide_threshold == 0;
+        if events.is_empty() || trusted.is_empty() || disabled {
+            return TrustedReports::default();
+        }
+        let reporters: Vec<PublicKey> = trusted
+            .iter()
+            .filter_map(|hex| PublicKey::from_hex(hex).ok())
+            .collect();
+        let event_ids: Vec<EventId> = events.iter().map(|e| e.id).collect();
+        let authors: Vec<PublicKey> = events
+            .iter()
+            .map(|e| e.pubkey)
+            .collect::<HashSet<_>>()
+            .into_iter()
+            .collect();
+        let mut found = Vec::new();
+        for filter in filters::report_filters(&event_ids, &authors, &reporters) {
+            match self.query_db(filter).await {
+                Ok(events) => found.extend(events),
+                Err(e) => tracing::debug!("[trusted_reports] Local report lookup failed: {e}"),
+            }
+        }
+        let found = self.drop_hidden(found).await;
+        TrustedReports::new(&found, &trusted, policy)
+    }
+
+    // ─── Community Notes (NIP-32) ──────────────────────────────
+
+    /// Community notes on `event_ids`, one summary per event that has any,
+    /// with ratings counted and the shown note picked by bridging.  Notes
+    /// by muted users are dropped.
+    pub async fn fetch_birdwatch_notes(
+        &self,
+        event_ids: &[EventId],
+        cache_only: bool,
+    ) -> Result<Vec<BirdwatchSummary>> {
+        if event_ids.is_empty() {
+            return Ok(Vec::new());
+        }
+        let filter = filters::birdwatch_label_filter(event_ids, event_ids.len() * 10);
+        let notes = self.fetch_cached(filter, cache_only).await?;
+        let notes = self.filter_muted(notes).await;
+        let label_ids: Vec<EventId> = notes.iter().map(|e| e.id).collect();
+        let ratings = if label_ids.is_empty() {
+            Vec::new()
+        } else {
+            let filter = filters::reaction_filter(&label_ids, label_ids.len() * 50);
+            self.fetch_cached(filter, cache_only).await?
+        };
+
+        // The raters' other ratings place them on the viewpoint axis.
+        let raters: Vec<PublicKey> = ratings
+            .iter()
+            .map(|e| e.pubkey)
+            .collect::<HashSet<_>>()
+            .into_iter()
+            .collect();
+        let mut history = Vec::new();
+        for filter in filters::rating_history_filters(&raters, labels::RATING_HISTORY_LIMIT) {
+            match self.fetch_cached(filter, cache_only).await {
+                Ok(events) => history.extend(events),
+                Err(e) => tracing::debug!("[fetch_birdwatch_notes] Rating history fetch failed: {e}"),
+            }
+        }
+        // Ratings need not carry `k` or `p`: resolve what they react to and
+        // keep the community notes, whose authors can't rate their own.
+        let known: HashSet<EventId> = label_ids.iter().copied().collect();
+        let rated: Vec<EventId> = history
+            .iter()
+            .filter_map(labels::parse_rating)
+            .filter_map(|(id, _)| EventId::from_hex(&id).ok())
+            .filter(|id| !known.contains(id))
+            .collect::<HashSet<_>>()
+            .into_iter()
+            .collect();
+        let mut history_labels = Vec::new();
+        for filter in filters::birdwatch_labels_by_id_filters(&rated) {
+            match self.fetch_cached(filter, cache_only).await {
+                Ok(events) => history_labels.extend(events),
+                Err(e) => tracing::debug!("[fetch_birdwatch_notes] Rated note fetch failed: {e}"),
+            }
+        }
+        let me = self.current_pubkey().await.map(|pk| pk.to_hex());
+        Ok(labels::summarize(&notes, &ratings, &history, &history_labels, me.as_deref()))
+    }
+
+    /// Write a community note on `target` (internal signer only).  One note
+    /// per user and event.
+    pub async fn create_birdwatch_note(
+        &self,
+        target: EventId,
+        context: BirdwatchContext,
+        content: &str,
+        source_url: Option<&str>,
+    ) -> Result<BirdwatchNote> {
+        let my_pk = self.require_internal_signer().await?;
+        let builder = self
+            .birdwatch_note_builder(my_pk, target, context, content, source_url)
+            .await?;
+        let event = self.client.sign_event_builder(builder).await?;
+        self.client.send_event(&event).await?;
+        labels::parse_label(&event)
+            .ok_or_else(|| NuruNuruError::EventError("ノートではありません".to_string()))
+    }
+
+    /// Build an **unsigned** community note by `author` for external signers.
+    pub async fn build_birdwatch_note(
+        &self,
+        author: PublicKey,
+        target: EventId,
+        context: BirdwatchContext,
+        content: &str,
+        source_url: Option<&str>,
+    ) -> Result<UnsignedEvent> {
+        Ok(self
+            .birdwatch_note_builder(author, target, context, content, source_url)
+            .await?
+            .build(author))
+    }
+
+    /// Rate the community note `note_id` (internal signer only).  Rating
+    /// again replaces the previous rating.
+    pub async fn rate_birdwatch_note(
+        &self,
+        note_id: EventId,
+        rating: BirdwatchRating,
+    ) -> Result<EventId> {
+        let my_pk = self.require_internal_signer().await?;
+        let builder = self.birdwatch_rating_builder(my_pk, note_id, rating).await?;
+        self.send_builder(builder).await
+    }
+
+    /// Build an **unsigned** rating by `author` for external signers.
+    pub async fn build_birdwatch_rating(
+        &self,
+        author: PublicKey,
+        note_id: EventId,
+        rating: BirdwatchRating,
+    ) -> Result<UnsignedEvent> {
+        Ok(self
+            .birdwatch_rating_builder(author, note_id, rating)
+            .await?
+            .build(author))
+    }
+
+    async fn birdwatch_note_builder(
+        &self,
+        author: PublicKey,
+        target: EventId,
+        context: BirdwatchContext,
+        content: &str,
+        source_url: Option<&str>,
+    ) -> Result<EventBuilder> {
+        let existing = Filter::new()
+            .kind(Kind::Custom(labels::LABEL_KIND))
+            .author(author)
+            .event(target)
+            .custom_tags(SingleLetterTag::uppercase(Alphabet::L), [labels::BIRDWATCH_NAMESPACE]);
+        if !self.fetch_cached(existing, false).await?.is_empty() {
+            return Err(NuruNuruError::ValidationError(
+                "このポストには既にノートを書いています".to_string(),
+            ));
+        }
+        let target_author = self.lookup_event(target, false).await?.map(|e| e.pubkey);
+        labels::build_label(target, target_author, context, content, source_url)
+    }
+
+    async fn birdwatch_rating_builder(
+        &self,
+        rater: PublicKey,
+        note_id: EventId,
+        rating: BirdwatchRating,
+    ) -> Result<EventBuilder> {
+        let note = self
+            .lookup_event(note_id, false)
+            .await?
+            .filter(labels::is_birdwatch_label)
+            .ok_or_else(|| NuruNuruError::EventError("ノートが見つかりません".to_string()))?;
+        if note.pubkey == rater {
+            return Err(NuruNuruError::ValidationError(
+                "自分のノートは評価できません".to_string(),
+            ));
+        }
+        Ok(labels::build_rating(note.id, note.pubkey, rating))
+    }
+
+    // ─── Long-form Articles (NIP-23) ───────────────────────────
+
+    /// Publish a new article, or replace the one named `input.identifier`
+    /// (internal signer only).  An edit without `published_at` keeps the
+    /// published version's.
+    pub async fn publish_article(&self, input: &ArticleInput) -> Result<Article> {
+        let my_pk = self.require_internal_signer().await?;
+        let builder = self.article_builder(my_pk, input, false).await?;
+        self.send_article(builder).await
+    }
+
+    /// Save (or replace) a kind-30024 draft (internal signer only).
+    pub async fn save_article_draft(&self, input: &ArticleInput) -> Result<Article> {
+        let my_pk = self.require_internal_signer().await?;
+        let builder = self.article_builder(my_pk, input, true).await?;
+        self.send_article(builder).await
+    }
+
+    /// Build an **unsigned** article or draft by `author` for external
+    /// signers, with the same `published_at` handling as `publish_article`.
+    pub async fn build_article(
+        &self,
+        author: PublicKey,
+        input: &ArticleInput,
+        draft: bool,
+    ) -> Result<UnsignedEvent> {
+        Ok(self.article_builder(author, input, draft).await?.build(author))
+    }
+
+    async fn article_builder(
+        &self,
+        author: PublicKey,
+        input: &ArticleInput,
+        draft: bool,
+    ) -> Result<EventBuilder> {
+        let mut input = input.clone();
+        if let (false, None, Some(d)) = (draft, input.published_at, input.identifier.clone()) {
+            let filter = filters::article_filter(&[author], false, Some(&d), 1);
+            let published = self.fetch_cached(filter, false).await?;
+            input.published_at = replaceable::newest(published.iter())
+                .and_then(|e| tags::first_tag_value(e, "published_at"))
+                .and_then(|v| v.parse().ok());
+        }
+        articles::build_article(&input, draft, Timestamp::now())
+    }
+
+    async fn send_article(&self, builder: EventBuilder) -> Result<Article> {
+        let event = self.client.sign_event_builder(builder).await?;
+        let output = self.client.send_event(&event).await?;
+        let relay = output.success.iter().next().map(|u| u.to_string());
+        articles::parse_article(&event, relay.as_deref())
+            .ok_or_else(|| NuruNuruError::EventError("記事ではありません".to_string()))
+    }
+
+    /// Resolve an `naddr` to the newest version of the article (or draft)
+    /// it points at: nostrdb, then relays, then the `naddr`'s relay hints.
+    pub async fn fetch_article(&self, naddr: &str, cache_only: bool) -> Result<Option<Article>> {
+        let addr = articles::parse_naddr(naddr)?;
+        let c = &addr.coordinate;
+        if ![articles::ARTICLE_KIND, articles::DRAFT_KIND].contains(&c.kind.as_u16()) {
+            return Err(NuruNuruError::ValidationError(format!(
+                "記事のnaddrではありません (kind {})",
+                c.kind.as_u16()
+            )));
+        }
+        let filter = Filter::new()
+            .kind(c.kind)
+            .author(c.public_key)
+            .identifier(c.identifier.clone());
+        let mut events = self.fetch_cached(filter.clone(), cache_only).await?;
+        if events.is_empty() && !cache_only && !addr.relays.is_empty() {
+            match self
+                .client
+                .fetch_events_from(addr.relays.clone(), filter, Duration::from_secs(10))
+                .await
+            {
+                Ok(remote) => events = self.purge_deleted(remote.into_iter().collect()).await,
+                Err(e) => tracing::debug!("[fetch_article] Hinted relay fetch failed: {e}"),
+            }
+        }
+        let Some(event) = replaceable::newest(events.iter()) else {
+            return Ok(None);
+        };
+        let hint = self.relay_hint(&event.id).await;
+        Ok(articles::parse_article(event, hint.as_deref()))
+    }
+
+    /// `author`'s articles (newest version of each, newest first) from
+    /// nostrdb plus relays unless `cache_only`.
+    pub async fn fetch_articles(
+        &self,
+        author: PublicKey,
+        limit: usize,
+        cache_only: bool,
+    ) -> Result<Vec<Article>> {
+        self.article_list(author, false, limit, cache_only).await
+    }
+
+    /// The logged-in user's article drafts, newest first.
+    pub async fn fetch_article_drafts(&self, limit: usize) -> Result<Vec<Article>> {
+        let my_pk = self
+            .current_pubkey()
+            .await
+            .ok_or(NuruNuruError::NoSigningMethod)?;
+        self.article_list(my_pk, true, limit, false).await
+    }
+
+    async fn article_list(
+        &self,
+        author: PublicKey,
+        draft: bool,
+        limit: usize,
+        cache_only: bool,
+    ) -> Result<Vec<Article>> {
+        let filter = filters::article_filter(&[author], draft, None, limit);
+        let events = self.fetch_cached(filter, cache_only).await?;
+        let mut list = Vec::new();
+        for event in articles::latest_versions(&events).into_iter().take(limit) {
+            let hint = self.relay_hint(&event.id).await;
+            list.extend(articles::parse_article(event, hint.as_deref()));
+        }
+        Ok(list)
+    }
+
+    // ─── Live Activities (NIP-53) ──────────────────────────────
+
+    /// Live activities whose status is in `statuses` (all if empty), newest
+    /// version of each: live first, then planned, then ended.  Activities
+    /// hosted by muted users are dropped.
+    pub async fn fetch_live_activities(
+        &self,
+        statuses: &[LiveStatus],
+        limit: usize,
+        cache_only: bool,
+    ) -> Result<Vec<LiveActivity>> {
+        let now = Timestamp::now().as_secs();
+        // Anything older than the stale cut-off can only be planned or ended.
+        let since = (statuses == [LiveStatus::Live])
+            .then(|| Timestamp::from(now.saturating_sub(live::STALE_AFTER_SECS)));
+        let filter = filters::live_activity_filter(&[], since, limit.saturating_mul(live::FETCH_FACTOR));
+        let events = self.fetch_cached(filter, cache_only).await?;
+        let mutes = self.mute_filter().await;
+        let mut activities = live::latest_activities(&events, now);
+        activities.retain(|a| {
+            (statuses.is_empty() || statuses.contains(&a.status))
+                && !mutes.is_muted_pubkey(a.host())
+                && !mutes.is_muted_pubkey(&a.pubkey)
+        });
+        activities.truncate(limit);
+        Ok(activities)
+    }
+
+    /// The newest version of the activity at `coordinate`
+    /// (`30311:<pubkey>:<d>`).
+    pub async fn fetch_live_activity(
+        &self,
+        coordinate: &str,
+        cache_only: bool,
+    ) -> Result<Option<LiveActivity>> {
+        let c = Coordinate::parse(coordinate)
+            .map_err(|e| NuruNuruError::ValidationError(format!("無効なライブ配信アドレス: {e}")))?;
+        let filter = Filter::new()
+            .kind(c.kind)
+            .author(c.public_key)
+            .identifier(c.identifier);
+        let events = self.fetch_cached(filter, cache_only).await?;
+        Ok(live::latest_activities(&events, Timestamp::now().as_secs())
+            .into_iter()
+            .next())
+    }
+
+    /// Recent chat of the activity at `coordinate`, oldest first, with
+    /// muted users and content removed.
+    pub async fn fetch_live_chat(
+        &self,
+        coordinate: &str,
+        limit: usize,
+        cache_only: bool,
+    ) -> Result<Vec<LiveChatMessage>> {
+        let filter = filters::live_chat_filter(coordinate, None, limit);
+        let events = self.fetch_cached(filter, cache_only).await?;
+        let mut events = self.filter_muted(events).await;
+        events.sort_by(|a, b| a.created_at.cmp(&b.created_at));
+        Ok(events.iter().filter_map(live::parse_chat_message).collect())
+    }
+
+    /// Subscribe to new chat messages of the activity at `coordinate`.
+    /// Drain with `poll_live_chat`; cancel with `unsubscribe_stream`.
+    pub async fn subscribe_live_chat(&self, coordinate: &str) -> Result<String> {
+        let filter = filters::live_chat_filter(coordinate, Some(Timestamp::now()), 0);
+        self.subscribe_stream(filter).await
+    }
+
+    /// Drain up to `max_count` chat messages buffered by
+    /// `subscribe_live_chat`, with muted users and content removed.
+    pub async fn poll_live_chat(&self, sub_id: &str, max_count: usize) -> Vec<LiveChatMessage> {
+        let events: Vec<Event> = self
+            .poll_subscription(sub_id, max_count)
+            .await
+            .iter()
+            .filter_map(|json| Event::from_json(json).ok())
+            .collect();
+        self.filter_muted(events)
+            .await
+            .iter()
+            .filter_map(live::parse_chat_message)
+            .collect()
+    }
+
+    /// Post a chat message to the activity at `coordinate` (internal
+    /// signer only).
+    pub async fn send_live_chat(&self, coordinate: &str, content: &str) -> Result<EventId> {
+        self.require_internal_signer().await?;
+        let hint = self.live_activity_hint(coordinate).await;
+        let builder = live::build_chat_message(coordinate, content, hint.as_deref())?;
+        self.send_builder(builder).await
+    }
+
+    /// Build an **unsigned** chat message by `author` for external signers.
+    pub async fn build_live_chat(
+        &self,
+        author: PublicKey,
+        coordinate: &str,
+        content: &str,
+    ) -> Result<UnsignedEvent> {
+        let hint = self.live_activity_hint(coordinate).await;
+        Ok(live::build_chat_message(coordinate, content, hint.as_deref())?.build(author))
+    }
+
+    /// Relay the cached activity at `coordinate` was seen on.
+    async fn live_activity_hint(&self, coordinate: &str) -> Option<String> {
+        let activity = self.fetch_live_activity(coordinate, true).await.ok()??;
+        let id = EventId::from_hex(&activity.event_id).ok()?;
+        self.relay_hint(&id).await
+    }
+
+    // ─── Badges (NIP-58) ───────────────────────────────────────
+
+    /// Badges `pubkey` shows on their profile, in their order.  Entries
+    /// whose award is missing, was not issued by the definition's author or
+    /// is not addressed to `pubkey` are dropped.
+    pub async fn fetch_profile_badges(&self, pubkey: PublicKey, cache_only: bool) -> Result<Vec<Badge>> {
+        let entries = self.profile_badge_entries(pubkey, cache_only).await?;
+        let ids: Vec<EventId> = entries
+            .iter()
+            .filter_map(|(_, id)| EventId::from_hex(id).ok())
+            .collect();
+        if ids.is_empty() {
+            return Ok(Vec::new());
+        }
+        let filter = Filter::new()
+            .kind(Kind::Custom(badges::BADGE_AWARD_KIND))
+            .ids(ids);
+        let awards = self.fetch_cached(filter, cache_only).await?;
+        self.resolve_badges(&entries, &awards, pubkey, true, cache_only).await
+    }
+
+    /// Every verified badge awarded to `pubkey`, newest award first, one
+    /// per definition.  `on_profile` marks the ones already shown.  Awards
+    /// from muted issuers are dropped.
+    pub async fn fetch_received_badges(
+        &self,
+        pubkey: PublicKey,
+        limit: usize,
+        cache_only: bool,
+    ) -> Result<Vec<Badge>> {
+        let awards = self
+            .fetch_cached(filters::badge_award_filter(pubkey, limit), cache_only)
             .await?;
+        let mut awards = self.filter_muted(awards).await;
+        awards.sort_by(|a, b| b.created_at.cmp(&a.created_at));
+
+        let mut seen = HashSet::new();
+        let entries: Vec<(String, String)> = awards
+            .iter()
+            .filter_map(|award| {
+                let coordinate = tags::first_tag_value(award, "a")?.to_string();
+                (badges::is_valid_award(award, &coordinate, &pubkey) && seen.insert(coordinate.clone()))
+                    .then(|| (coordinate, award.id.to_hex()))
+            })
+            .collect();
+        let shown: HashSet<String> = self
+            .profile_badge_entries(pubkey, cache_only)
+            .await?
+            .into_iter()
+            .map(|(coordinate, _)| coordinate)
+            .collect();
+        let mut list = self.resolve_badges(&entries, &awards, pubkey, false, cache_only).await?;
+        for badge in &mut list {
+            badge.on_profile = shown.contains(&badge.definition.coordinate);
+        }
+        Ok(list)
+    }
+
+    /// Replace the logged-in user's profile badges with the awards in
+    /// `award_event_ids`, in that order (internal signer only).  Used to
+    /// accept, reorder and remove badges; every award must be a valid award
+    /// to the user.
+    pub async fn set_profile_badges(&self, award_event_ids: &[String]) -> Result<Vec<Badge>> {
+        let my_pk = self.require_internal_signer().await?;
+        let (builder, entries, awards) = self.profile_badges_builder(my_pk, award_event_ids).await?;
+        self.send_builder(builder).await?;
+        self.resolve_badges(&entries, &awards, my_pk, true, false).await
+    }
+
+    /// Add the award `award_event_id` to the end of the logged-in user's
+    /// profile badges (internal signer only).  The current list is
+    /// republished with all its tags, including entries that do not
+    /// resolve right now; nothing is sent if the badge is already shown or
+    /// the newest list can't be confirmed with the relays.
+    pub async fn accept_badge(&self, award_event_id: &str) -> Result<Vec<Badge>> {
+        let my_pk = self.require_internal_signer().await?;
+        let id = EventId::from_hex(award_event_id)
+            .map_err(|e| NuruNuruError::ValidationError(format!("無効なイベントID: {e}")))?;
+        let filter = Filter::new()
+            .kind(Kind::Custom(badges::BADGE_AWARD_KIND))
+            .id(id);
+        let award = self
+            .fetch_cached(filter, false)
+            .await?
+            .into_iter()
+            .next()
+            .ok_or_else(|| NuruNuruError::EventError(format!("バッジの授与が見つかりません: {id}")))?;
+        let coordinate = tags::first_tag_value(&award, "a").unwrap_or("").to_string();
+        if !badges::is_valid_award(&award, &coordinate, &my_pk) {
+            return Err(NuruNuruError::ValidationError(format!(
+                "有効なバッジの授与ではありません: {id}"
+            )));
+        }
+
+        let filter = filters::badge_filter(my_pk);
+        let local = self.query_local(filter.clone()).await?;
+        let remote = self.fetch_confirmed(filter).await.ok_or_else(|| {
+            NuruNuruError::ValidationError("最新のプロフィールバッジを確認できません".to_string())
+        })?;
+        let list = replaceable::newest(local.iter().chain(remote.iter()));
+        if let Some(tags) = badges::append_entry(list, &coordinate, &id.to_hex()) {
+            let content = list.map(|l| l.content.clone()).unwrap_or_default();
+            self.send_builder(EventBuilder::new(Kind::Custom(badges::PROFILE_BADGES_KIND), content).tags(tags))
+                .await?;
+        }
+        self.fetch_profile_badges(my_pk, false).await
+    }
+
+    /// Build an **unsigned** profile badges list by `owner` for external
+    /// signers, verified the same way as `set_profile_badges`.
+    pub async fn build_profile_badges(
+        &self,
+        owner: PublicKey,
+        award_event_ids: &[String],
+    ) -> Result<UnsignedEvent> {
+        let (builder, _, _) = self.profile_badges_builder(owner, award_event_ids).await?;
+        Ok(builder.build(owner))
+    }
+
+    /// Create (or replace) a badge definition (internal signer only).
+    pub async fn create_badge_definition(&self, input: &BadgeDefinitionInput) -> Result<BadgeDefinition> {
+        self.require_internal_signer().await?;
+        let event = self
+            .client
+            .sign_event_builder(badges::build_definition(input)?)
+            .await?;
+        self.client.send_event(&event).await?;
+        badges::parse_definition(&event)
+            .ok_or_else(|| NuruNuruError::EventError("バッジ定義ではありません".to_string()))
+    }
+
+    /// Build an **unsigned** badge definition by `issuer` for external signers.
+    pub async fn build_badge_definition(
+        &self,
+        issuer: PublicKey,
+        input: &BadgeDefinitionInput,
+    ) -> Result<UnsignedEvent> {
+        Ok(badges::build_definition(input)?.build(issuer))
+    }
+
+    /// Award the logged-in user's badge at `coordinate` to `recipients`
+    /// (internal signer only).
+    pub async fn award_badge(&self, coordinate: &str, recipients: &[PublicKey]) -> Result<EventId> {
+        let my_pk = self.require_internal_signer().await?;
+        self.send_builder(badges::build_award(coordinate, &my_pk, recipients)?)
+            .await
+    }
+
+    /// Build an **unsigned** badge award by `issuer` for external signers.
+    pub async fn build_badge_award(
+        &self,
+        issuer: PublicKey,
+        coordinate: &str,
+        recipients: &[PublicKey],
+    ) -> Result<UnsignedEvent> {
+        Ok(badges::build_award(coordinate, &issuer, recipients)?.build(issuer))
+    }
+
+    /// `(coordinate, award)` entries of `pubkey`'s newest profile badges list.
+    async fn profile_badge_entries(
+        &self,
+        pubkey: PublicKey,
+        cache_only: bool,
+    ) -> Result<Vec<(String, String)>> {
+        let events = self.fetch_cached(filters::badge_filter(pubkey), cache_only).await?;
+        Ok(replaceable::newest(events.iter())
+            .map(badges::profile_entries)
+            .unwrap_or_default())
+    }
+
+    /// Fetch the definitions of `entries` and resolve them into badges.
+    async fn resolve_badges(
+        &self,
+        entries: &[(String, String)],
+        awards: &[Event],
+        recipient: PublicKey,
+        on_profile: bool,
+        cache_only: bool,
+    ) -> Result<Vec<Badge>> {
+        let coordinates: Vec<Coordinate> = entries
+            .iter()
+            .filter_map(|(c, _)| Coordinate::parse(c).ok())
+            .collect();
+        if coordinates.is_empty() {
+            return Ok(Vec::new());
+        }
+        let issuers: HashSet<PublicKey> = coordinates.iter().map(|c| c.public_key).collect();
+        let identifiers: HashSet<String> = coordinates.iter().map(|c| c.identifier.clone()).collect();
+        let filter = filters::badge_definition_filter(
+            &issuers.into_iter().collect::<Vec<_>>(),
+            &identifiers.into_iter().collect::<Vec<_>>(),
+        );
+        let definitions = self.fetch_cached(filter, cache_only).await?;
+        Ok(badges::resolve(entries, &definitions, awards, &recipient, on_profile))
+    }
+
+    /// Verify `award_event_ids` as awards to `owner` and build the profile
+    /// badges list showing them.  Repeated definitions keep the first award.
+    async fn profile_badges_builder(
+        &self,
+        owner: PublicKey,
+        award_event_ids: &[String],
+    ) -> Result<(EventBuilder, Vec<(String, String)>, Vec<Event>)> {
+        let ids = award_event_ids
+            .iter()
+            .map(|id| {
+                EventId::from_hex(id)
+                    .map_err(|e| NuruNuruError::ValidationError(format!("無効なイベントID: {e}")))
+            })
+            .collect::<Result<Vec<_>>>()?;
+        let awards = if ids.is_empty() {
+            Vec::new()
+        } else {
+            let filter = Filter::new()
+                .kind(Kind::Custom(badges::BADGE_AWARD_KIND))
+                .ids(ids.iter().copied());
+            self.fetch_cached(filter, false).await?
+        };
+
+        let mut seen = HashSet::new();
+        let mut entries = Vec::new();
+        for id in &ids {
+            let award = awards
+                .iter()
+                .find(|e| e.id == *id)
+                .ok_or_else(|| NuruNuruError::EventError(format!("バッジの授与が見つかりません: {id}")))?;
+            let coordinate = tags::first_tag_value(award, "a").unwrap_or("").to_string();
+            if !badges::is_valid_award(award, &coordinate, &owner) {
+                return Err(NuruNuruError::ValidationError(format!(
+                    "有効なバッジの授与ではありません: {id}"
+                )));
+            }
+            if seen.insert(coordinate.clone()) {
+                entries.push((coordinate, id.to_hex()));
+            }
+        }
+        let builder = EventBuilder::new(Kind::Custom(badges::PROFILE_BADGES_KIND), "")
+            .tags(badges::profile_badges_tags(&entries));
+        Ok((builder, entries, awards))
+    }
+
+    // ─── DMs (NIP-17) ──────────────────────────────────────────
+
+    /// Send an encrypted DM using NIP-17 gift wrapping.
+    /// All seal/wrap layers are handled by `nostr-sdk`.
+    pub async fn send_dm(
+        &self,
+        recipient: PublicKey,
+        content: &str,
+    ) -> Result<()> {
+        self.client
+            .send_private_msg(recipient, content, [])
+            .await?;
+        Ok(())
+    }
+
+    /// Send a NIP-17 DM that expires (NIP-40) `ttl_secs` from now.  Both the
+    /// rumor and the gift wrap carry the `expiration` tag, so relays can
+    /// drop the wrap and the recipient's client can hide the message.
+    ///
+    /// `send_private_msg` only tags the rumor, so the wrap is built here.
+    pub async fn send_expiring_dm(
+        &self,
+        recipient: PublicKey,
+        content: &str,
+        ttl_secs: u64,
+    ) -> Result<()> {
+        let my_pk = self.require_internal_signer().await?;
+        let signer = self
+            .client
+            .signer()
+            .await
+            .map_err(|_| NuruNuruError::NoSigningMethod)?;
+        let exp = expiration::expiration_tag(Timestamp::now(), ttl_secs);
+        let rumor = EventBuilder::private_msg_rumor(recipient, content)
+            .tags([exp.clone()])
+            .build(my_pk);
+        let wrap = EventBuilder::gift_wrap(&signer, &recipient, rumor, [exp])
+            .await
+            .map_err(|e| NuruNuruError::EventError(e.to_string()))?;
+        self.client.send_event(&wrap).await?;
         Ok(())
     }
 
-    /// Fetch DM events (gift-wrapped, kind 1059).
-    pub async fn fetch_dms(
+    /// Fetch DM events (gift-wrapped, kind 1059).  Expired wraps are dropped.
+    pub async fn fetch_dms(
+        &self,
+        since: Option<Timestamp>,
+        limit: usize,
+    ) -> Result<Vec<Event>> {
+        let my_pk = self
+            .current_pubkey()
+            .await
+            .ok_or(NuruNuruError::NoSigningMethod)?;
+
+        let filter = filters::dm_filter(my_pk, since, limit);
+        let events = self
+            .client
+            .fetch_events(filter, Duration::from_secs(15))
+            .await?;
+
+        Ok(self.drop_hidden(events.into_iter().collect()).await)
+    }
+
+    // ─── Publishing ─────────────────────────────────────────────
+
+    /// Publish a text note (kind 1).
+    pub async fn publish_note(&self, content: &str, tags: Vec<Tag>) -> Result<EventId> {
+        self.publish_note_with_options(content, tags, &NoteOptions::default())
+            .await
+    }
+
+    /// Publish a text note (kind 1) with `options` applied to its tags.
+    pub async fn publish_note_with_options(
+        &self,
+        content: &str,
+        tags: Vec<Tag>,
+        options: &NoteOptions,
+    ) -> Result<EventId> {
+        let author = self.current_pubkey().await;
+        let tags = self.note_tags(author, content, tags, options).await;
+        self.send_builder(EventBuilder::text_note(content).tags(tags))
+            .await
+    }
+
+    /// Build an **unsigned** text note (kind 1) by `author` for external
+    /// signers, with `options` applied to its tags.
+    pub async fn build_note(
+        &self,
+        author: PublicKey,
+        content: &str,
+        tags: Vec<Tag>,
+        options: &NoteOptions,
+    ) -> UnsignedEvent {
+        let tags = self.note_tags(Some(author), content, tags, options).await;
+        EventBuilder::text_note(content).tags(tags).build(author)
+    }
+
+    /// `tags` plus, with `options.auto_tag`, the tags implied by `content`,
+    /// with `options.expires_in` a NIP-40 `expiration` tag and with
+    /// `options.content_warning` a NIP-36 `content-warning` tag.
+    /// Custom emoji are resolved against `author`'s NIP-30 emoji list.
+    async fn note_tags(
+        &self,
+        author: Option<PublicKey>,
+        content: &str,
+        mut tags: Vec<Tag>,
+        options: &NoteOptions,
+    ) -> Vec<Tag> {
+        if options.auto_tag {
+            // A `:shortcode:` needs at least two colons; skip the lookup otherwise.
+            let emojis = match author {
+                Some(pk) if content.matches(':').count() >= 2 => self.emoji_map(pk).await,
+                _ => HashMap::new(),
+            };
+            let implied = content::auto_tags(content, &emojis, &tags);
+            tags.extend(implied);
+        }
+        if let Some(ttl) = options.expires_in {
+            tags.push(expiration::expiration_tag(Timestamp::now(), ttl));
+        }
+        if let Some(reason) = &options.content_warning {
+            if !tags.iter().any(|t| t.as_slice().first().is_some_and(|n| n == "content-warning")) {
+                tags.push(content::content_warning_tag(reason));
+            }
+        }
+        tags
+    }
+
+    /// Build an **unsigned** NIP-10 reply to `parent` for the logged-in user.
+    ///
+    /// Carries root/reply markers, the thread participants' `p` tags and a
+    /// relay hint for the parent (from where nostrdb saw it).  For external
+    /// signers; internal signers can call `publish_reply` directly.
+    pub async fn build_reply(&self, parent: &Event, content: &str) -> Result<UnsignedEvent> {
+        let my_pk = self
+            .current_pubkey()
+            .await
+            .ok_or(NuruNuruError::NoSigningMethod)?;
+        let hint = self.relay_hint(&parent.id).await;
+        Ok(compose::build_reply(parent, content, hint.as_deref(), Some(&my_pk)).build(my_pk))
+    }
+
+    /// Sign and publish a NIP-10 reply to `parent` (internal signer only).
+    pub async fn publish_reply(&self, parent: &Event, content: &str) -> Result<EventId> {
+        let my_pk = self.require_internal_signer().await?;
+        let hint = self.relay_hint(&parent.id).await;
+        let builder = compose::build_reply(parent, content, hint.as_deref(), Some(&my_pk));
+        let event_id = self.send_builder(builder).await?;
+        self.record_engagement("reply", &parent.pubkey.to_hex()).await;
+        Ok(event_id)
+    }
+
+    /// Build an **unsigned** NIP-18 quote of `target` for the logged-in user
+    /// (`q` tag + `nostr:nevent` reference in the content).
+    pub async fn build_quote(&self, target: &Event, content: &str) -> Result<UnsignedEvent> {
+        let my_pk = self
+            .current_pubkey()
+            .await
+            .ok_or(NuruNuruError::NoSigningMethod)?;
+        let hint = self.relay_hint(&target.id).await;
+        Ok(compose::build_quote(target, content, hint.as_deref())?.build(my_pk))
+    }
+
+    /// Sign and publish a NIP-18 quote of `target` (internal signer only).
+    pub async fn publish_quote(&self, target: &Event, content: &str) -> Result<EventId> {
+        self.require_internal_signer().await?;
+        let hint = self.relay_hint(&target.id).await;
+        let builder = compose::build_quote(target, content, hint.as_deref())?;
+        self.send_builder(builder).await
+    }
+
+    /// A relay the event was seen on, for use as a tag relay hint.
+    async fn relay_hint(&self, event_id: &EventId) -> Option<String> {
+        self.client
+            .database()
+            .event_seen_on_relays(event_id)
+            .await
+            .ok()
+            .flatten()
+            .and_then(|relays| relays.into_iter().next())
+            .map(|url| url.to_string())
+    }
+
+    /// Publish a reaction (kind 7, NIP-25).
+    ///
+    /// The target is looked up (nostrdb, then relays) so the reaction
+    /// carries its real kind, coordinate and a relay hint; if it cannot be
+    /// found only the `e` / `p` tags are written.
+    pub async fn react(&self, event_id: EventId, author: PublicKey, reaction: &str) -> Result<EventId> {
+        if let Some(target) = self.lookup_event(event_id, false).await? {
+            return self.react_to_event(&target, reaction, None).await;
+        }
+        let target = ReactionTarget {
+            event_id,
+            public_key: author,
+            coordinate: None,
+            kind: None,
+            relay_hint: self
+                .relay_hint(&event_id)
+                .await
+                .and_then(|r| RelayUrl::parse(&r).ok()),
+        };
+        let builder = EventBuilder::reaction(target, reaction);
+        let output = self.client.send_event_builder(builder).await?;
+        Ok(output.val)
+    }
+
+    /// Build an **unsigned** reaction to `target` for the logged-in user.
+    ///
+    /// `:shortcode:` reactions use `emoji_url`, or the URL from the user's
+    /// NIP-30 emoji list when `None`.
+    pub async fn build_reaction(
+        &self,
+        target: &Event,
+        reaction: &str,
+        emoji_url: Option<&str>,
+    ) -> Result<UnsignedEvent> {
+        let my_pk = self
+            .current_pubkey()
+            .await
+            .ok_or(NuruNuruError::NoSigningMethod)?;
+        Ok(self
+            .reaction_builder(my_pk, target, reaction, emoji_url)
+            .await?
+            .build(my_pk))
+    }
+
+    /// Sign and publish a reaction to `target` of any kind (internal signer
+    /// only).  See `build_reaction` for custom emoji handling.
+    pub async fn react_to_event(
+        &self,
+        target: &Event,
+        reaction: &str,
+        emoji_url: Option<&str>,
+    ) -> Result<EventId> {
+        let my_pk = self.require_internal_signer().await?;
+        let builder = self.reaction_builder(my_pk, target, reaction, emoji_url).await?;
+        self.send_builder(builder).await
+    }
+
+    async fn reaction_builder(
+        &self,
+        my_pk: PublicKey,
+        target: &Event,
+        reaction: &str,
+        emoji_url: Option<&str>,
+    ) -> Result<EventBuilder> {
+        let hint = self.relay_hint(&target.id).await;
+        let emoji_url = match (emoji_url, compose::emoji_shortcode(reaction.trim())) {
+            (Some(url), _) => Some(url.to_string()),
+            (None, Some(shortcode)) => self.emoji_map(my_pk).await.remove(shortcode),
+            (None, None) => None,
+        };
+        compose::build_reaction(target, reaction, emoji_url.as_deref(), hint.as_deref())
+    }
+
+    /// Build an **unsigned** repost of `target`: kind 6 for text notes,
+    /// NIP-18 generic repost (kind 16) for other kinds.
+    pub async fn build_repost(&self, target: &Event) -> Result<UnsignedEvent> {
+        let my_pk = self
+            .current_pubkey()
+            .await
+            .ok_or(NuruNuruError::NoSigningMethod)?;
+        let hint = self.relay_hint(&target.id).await;
+        Ok(compose::build_repost(target, hint.as_deref()).build(my_pk))
+    }
+
+    /// Repost an event (kind 6, or kind 16 for non-text-note targets, NIP-18).
+    pub async fn repost(&self, event: &Event) -> Result<EventId> {
+        let hint = self.relay_hint(&event.id).await;
+        self.send_builder(compose::build_repost(event, hint.as_deref())).await
+    }
+
+    /// Delete an event (kind 5, NIP-09).
+    pub async fn delete_event(&self, event_id: EventId, reason: Option<&str>) -> Result<EventId> {
+        self.delete_events(&[event_id], &[], reason).await
+    }
+
+    /// Delete several events and / or addressable events (every version up
+    /// to now) in one kind-5 request, and hide them locally right away.
+    pub async fn delete_events(
+        &self,
+        event_ids: &[EventId],
+        coordinates: &[Coordinate],
+        reason: Option<&str>,
+    ) -> Result<EventId> {
+        if event_ids.is_empty() && coordinates.is_empty() {
+            return Err(NuruNuruError::ValidationError(
+                "削除対象がありません".to_string(),
+            ));
+        }
+        let mut request = EventDeletionRequest::new()
+            .ids(event_ids.iter().copied())
+            .coordinates(coordinates.iter().cloned());
+        if let Some(r) = reason {
+            request = request.reason(r);
+        }
+        self.send_deletion(EventBuilder::delete(request)).await
+    }
+
+    /// Undo a reaction: delete (NIP-09) the logged-in user's reactions to
+    /// `event_id` and hide them locally.  Returns the deletion event ID.
+    pub async fn unreact(&self, event_id: EventId) -> Result<EventId> {
+        self.retract_own(&[Kind::Reaction], event_id).await
+    }
+
+    /// Undo a repost: delete (NIP-09) the logged-in user's reposts of
+    /// `event_id` and hide them locally.  Returns the deletion event ID.
+    pub async fn unrepost(&self, event_id: EventId) -> Result<EventId> {
+        self.retract_own(&[Kind::Repost, Kind::GenericRepost], event_id).await
+    }
+
+    /// Find the user's own `kinds` events referencing `target` (nostrdb
+    /// first, then relays) and delete them in one request.
+    async fn retract_own(&self, kinds: &[Kind], target: EventId) -> Result<EventId> {
+        let my_pk = self.require_internal_signer().await?;
+        let filter = Filter::new()
+            .kinds(kinds.iter().copied())
+            .author(my_pk)
+            .event(target);
+
+        let mut own = self.query_local(filter.clone()).await?;
+        if own.is_empty() {
+            let remote = self
+                .client
+                .fetch_events(filter, Duration::from_secs(10))
+                .await?;
+            own = self.purge_deleted(remote.into_iter().collect()).await;
+        }
+        if own.is_empty() {
+            return Err(NuruNuruError::EventError(
+                "取り消す対象が見つかりません".to_string(),
+            ));
+        }
+
+        let mut kind_tags: Vec<u16> = own.iter().map(|e| e.kind.as_u16()).collect();
+        kind_tags.sort_unstable();
+        kind_tags.dedup();
+        let builder = EventBuilder::delete(EventDeletionRequest::new().ids(own.iter().map(|e| e.id)))
+            .tags(kind_tags.into_iter().map(|k| make_tag(["k".to_string(), k.to_string()])));
+        self.send_deletion(builder).await
+    }
+
+    /// Sign and send a kind-5 request, then apply it locally.
+    async fn send_deletion(&self, builder: EventBuilder) -> Result<EventId> {
+        let event = self.client.sign_event_builder(builder).await?;
+        self.client.send_event(&event).await?;
+        self.apply_deletions(std::slice::from_ref(&event)).await?;
+        Ok(event.id)
+    }
+
+    // ─── Deletions (NIP-09) ────────────────────────────────────
+
+    /// Apply the kind-5 requests among `events` (other kinds are ignored):
+    /// record tombstones and purge the author's targets from nostrdb where
+    /// the backend supports it.  Returns how many requests added anything.
+    ///
+    /// Tombstones are written to disk by `save_tombstones` on the scheduler
+    /// tick, not per request.
+    pub async fn apply_deletions(&self, events: &[Event]) -> Result<usize> {
+        let deletions: Vec<&Event> = events
+            .iter()
+            .filter(|e| e.kind == Kind::EventDeletion)
+            .collect();
+        if deletions.is_empty() {
+            return Ok(0);
+        }
+
+        let mut applied = Vec::new();
+        {
+            let mut tombstones = self.tombstones.write().await;
+            for deletion in deletions {
+                if tombstones.apply(deletion) {
+                    applied.push(deletion);
+                }
+            }
+        }
+
+        for deletion in &applied {
+            for f in deletion::target_filters(deletion) {
+                if let Err(e) = self.client.database().delete(f).await {
+                    tracing::debug!("[apply_deletions] nostrdb delete unavailable, tombstone only: {e}");
+                }
+            }
+        }
+        Ok(applied.len())
+    }
+
+    /// Look up deletion requests by the authors of `events` in nostrdb,
+    /// apply them and drop every deleted or expired event.  Relays are not
+    /// asked: requests arrive through the live subscription
+    /// (`subscribe_trusted_events`) or alongside the events themselves.
+    async fn purge_deleted(&self, events: Vec<Event>) -> Vec<Event> {
+        if events.is_empty() {
+            return events;
+        }
+        let ids: Vec<EventId> = events.iter().map(|e| e.id).collect();
+        let coordinates: Vec<String> = events
+            .iter()
+            .filter_map(deletion::replaceable_coordinate)
+            .collect();
+        let authors: Vec<PublicKey> = events
+            .iter()
+            .map(|e| e.pubkey)
+            .collect::<HashSet<_>>()
+            .into_iter()
+            .collect();
+
+        let mut requests = Vec::new();
+        for filter in filters::deletion_filters(&ids, &coordinates, &authors) {
+            match self.query_db(filter).await {
+                Ok(local) => requests.extend(local),
+                Err(e) => tracing::warn!("[purge_deleted] Local deletion lookup failed: {e}"),
+            }
+        }
+        if let Err(e) = self.apply_deletions(&requests).await {
+            tracing::warn!("[purge_deleted] Failed to record deletions: {e}");
+        }
+        self.drop_hidden(events).await
+    }
+
+    /// Persist the tombstones if any were recorded since the last save.
+    async fn save_tombstones(&self) -> Result<()> {
+        let mut tombstones = self.tombstones.write().await;
+        if tombstones.is_dirty() {
+            store::save_json(&self.config.store_path("deleted"), &*tombstones)?;
+            tombstones.mark_saved();
+        }
+        Ok(())
+    }
+
+    /// `true` if nostrdb holds an event `deletion` would delete.
+    async fn holds_deletion_target(&self, deletion: &Event) -> bool {
+        for filter in deletion::target_filters(deletion) {
+            match self.query_db(filter.limit(1)).await {
+                Ok(events) if !events.is_empty() => return true,
+                Ok(_) => {}
+                Err(e) => tracing::warn!("[deletion_watcher] Local target lookup failed: {e}"),
+            }
+        }
+        false
+    }
+
+    /// (Re)subscribe to deletion requests and reports by `me` and the
+    /// follow list.  Both land in nostrdb, where `purge_deleted` and
+    /// `trusted_reports` look them up.
+    async fn subscribe_trusted_events(&self, me: PublicKey) {
+        let mut authors: Vec<PublicKey> = self
+            .follow_list
+            .read()
+            .await
+            .iter()
+            .filter_map(|pk| PublicKey::from_hex(pk).ok())
+            .collect();
+        authors.push(me);
+
+        let mut subscriptions = self.trusted_subscriptions.lock().await;
+        for id in subscriptions.drain(..) {
+            self.client.unsubscribe(&id).await;
+        }
+        let deletions = filters::live_deletion_filters(&authors, filters::since_hours_ago(DELETION_LOOKBACK_HOURS));
+        let reports = filters::live_report_filters(&authors, filters::since_hours_ago(REPORT_LOOKBACK_HOURS));
+        for filter in deletions.into_iter().chain(reports) {
+            match self.client.subscribe(filter, None).await {
+                Ok(output) => subscriptions.push(output.val),
+                Err(e) => tracing::warn!("[subscribe_trusted_events] {e}"),
+            }
+        }
+    }
+
+    /// Background task: record the deletion requests the relays deliver,
+    /// whichever subscription they arrived on, when nostrdb holds one of
+    /// their targets.  The rest stay in nostrdb for `purge_deleted` to
+    /// apply if a target is fetched later.
+    ///
+    /// Holds only a `Weak` reference between notifications, like
+    /// `spawn_scheduler`.
+    fn spawn_deletion_watcher(engine: &Arc<Self>) {
+        let mut notif_rx = engine.client.notifications();
+        let engine = Arc::downgrade(engine);
+        tokio::spawn(async move {
+            loop {
+                match notif_rx.recv().await {
+                    Ok(RelayPoolNotification::Event { event, .. }) => {
+                        if event.kind != Kind::EventDeletion {
+                            continue;
+                        }
+                        let Some(engine) = engine.upgrade() else {
+                            break;
+                        };
+                        if !engine.holds_deletion_target(&event).await {
+                            continue;
+                        }
+                        if let Err(e) = engine.apply_deletions(std::slice::from_ref(&*event)).await {
+                            tracing::warn!("[deletion_watcher] {e}");
+                        }
+                    }
+                    Ok(RelayPoolNotification::Shutdown) => break,
+                    Ok(_) => {}
+                    Err(tokio::sync::broadcast::error::RecvError::Lagged(n)) => {
+                        tracing::warn!("[deletion_watcher] Missed {n} notifications");
+                    }
+                    Err(tokio::sync::broadcast::error::RecvError::Closed) => break,
+                }
+            }
+        });
+    }
+
+    /// Drop events covered by a recorded tombstone or expired (NIP-40).
+    /// Expiring events still shown are remembered for the background purge.
+    async fn drop_hidden(&self, mut events: Vec<Event>) -> Vec<Event> {
+        {
+            let tombstones = self.tombstones.read().await;
+            if !tombstones.is_empty() {
+                events.retain(|e| !tombstones.is_deleted(e));
+            }
+        }
+        let now = Timestamp::now().as_secs();
+        events.retain(|e| !expiration::is_expired(e, now));
+        if let Err(e) = self.expiry_index.lock().await.track(&events) {
+            tracing::warn!("[drop_hidden] Failed to record expiring events: {e}");
+        }
+        events
+    }
+
+    // ─── Request to Vanish (NIP-62) ────────────────────────────
+
+    /// Prepare a request asking `relay_urls` (every relay when empty) to
+    /// delete all of the user's events, and summarise what sending it will
+    /// do.  Nothing is sent until `confirm_vanish` / `publish_vanish_request`.
+    pub async fn prepare_vanish(&self, relay_urls: &[String], reason: &str) -> Result<VanishPreview> {
+        let my_pk = self
+            .current_pubkey()
+            .await
+            .ok_or(NuruNuruError::NoSigningMethod)?;
+        let requested: Vec<RelayUrl> = relay_urls
+            .iter()
+            .map(|u| relay::parse_relay_url(u))
+            .collect::<Result<_>>()?;
+        let pool: Vec<RelayUrl> = self.client.relays().await.into_keys().collect();
+        let fallback: Vec<RelayUrl> = self
+            .config
+            .relay
+            .fallback_relays
+            .iter()
+            .filter_map(|u| relay::parse_relay_url(u).ok())
+            .collect();
+        let targets = vanish::publish_targets(&requested, &pool, &fallback);
+        if targets.is_empty() {
+            return Err(NuruNuruError::ValidationError("送信先のリレーがありません".to_string()));
+        }
+
+        let mut unsigned = vanish::build_request(&requested, reason)
+            .custom_created_at(Timestamp::now())
+            .build(my_pk);
+        unsigned.ensure_id();
+        let event_id = unsigned
+            .id
+            .ok_or_else(|| NuruNuruError::EventError("イベントIDを計算できません".to_string()))?;
+
+        let local_event_count = self
+            .client
+            .database()
+            .count(Filter::new().author(my_pk))
+            .await
+            .map_err(|e| NuruNuruError::DatabaseError(e.to_string()))? as u64;
+        let mls_group_count = match &self.mls {
+            Some(mls) => mls.list_groups().map(|g| g.len() as u32).unwrap_or(0),
+            None => 0,
+        };
+
+        let preview = VanishPreview {
+            event_id: event_id.to_hex(),
+            all_relays: requested.is_empty(),
+            target_relays: requested.iter().map(|u| u.to_string()).collect(),
+            publish_relays: targets.iter().map(|u| u.to_string()).collect(),
+            reason: unsigned.content.clone(),
+            local_event_count,
+            mls_group_count,
+            unsigned_event_json: unsigned.as_json(),
+        };
+        *self.pending_vanish.lock().await = Some((unsigned, targets));
+        Ok(preview)
+    }
+
+    /// Sign and send the request prepared by `prepare_vanish` (its
+    /// `event_id` guards against confirming a different request), then
+    /// purge the user's data locally.
+    pub async fn confirm_vanish(&self, event_id: &EventId) -> Result<VanishResult> {
+        self.require_internal_signer().await?;
+        let (unsigned, targets) = self.take_pending_vanish(event_id).await?;
+        let signer = self
+            .client
+            .signer()
+            .await
+            .map_err(|_| NuruNuruError::NoSigningMethod)?;
+        let event = signer
+            .sign_event(unsigned)
+            .await
+            .map_err(|e| NuruNuruError::EventError(e.to_string()))?;
+        self.finish_vanish(event, targets).await
+    }
+
+    /// Send a request prepared by `prepare_vanish` and signed externally
+    /// (NIP-07 / NIP-46 / Amber), then purge the user's data locally.
+    pub async fn publish_vanish_request(&self, event: Event) -> Result<VanishResult> {
+        let my_pk = self
+            .current_pubkey()
+            .await
+            .ok_or(NuruNuruError::NoSigningMethod)?;
+        if event.kind.as_u16() != vanish::VANISH_KIND || event.pubkey != my_pk {
+            return Err(NuruNuruError::ValidationError(
+                "自分の削除リクエスト (kind 62) ではありません".to_string(),
+            ));
+        }
+        event
+            .verify()
+            .map_err(|e| NuruNuruError::EventError(e.to_string()))?;
+        let (_, targets) = self.take_pending_vanish(&event.id).await?;
+        self.finish_vanish(event, targets).await
+    }
+
+    /// Take the pending request if it is `event_id`.
+    async fn take_pending_vanish(&self, event_id: &EventId) -> Result<(UnsignedEvent, Vec<RelayUrl>)> {
+        let mut pending = self.pending_vanish.lock().await;
+        match pending.take() {
+            Some((unsigned, targets)) if unsigned.id == Some(*event_id) => Ok((unsigned, targets)),
+            other => {
+                *pending = other;
+                Err(NuruNuruError::ValidationError(
+                    "確認待ちの削除リクエストがありません".to_string(),
+                ))
+            }
+        }
+    }
+
+    /// Send `event` to `targets`, recording each relay's answer, then drop
+    /// the user's events from nostrdb, cancel queued scheduled posts and
+    /// reset the MLS database.  If no relay accepted the request nothing
+    /// local is touched.
+    async fn finish_vanish(&self, event: Event, targets: Vec<RelayUrl>) -> Result<VanishResult> {
+        let added = self.connect_targets(&targets).await;
+        let output = self.client.send_event_to(targets, &event).await;
+        self.remove_temporary_relays(added).await;
+        let output = output?;
+        if output.success.is_empty() {
+            let reasons: Vec<String> = output
+                .failed
+                .iter()
+                .map(|(url, reason)| format!("{url}: {reason}"))
+                .collect();
+            return Err(NuruNuruError::RelayError(format!(
+                "削除リクエストを受け付けたリレーがありません: {}",
+                reasons.join(", ")
+            )));
+        }
+
+        let mut local_events_purged = true;
+        for filter in [
+            Filter::new().author(event.pubkey),
+            Filter::new().kind(Kind::GiftWrap).pubkey(event.pubkey),
+        ] {
+            if let Err(e) = self.client.database().delete(filter).await {
+                tracing::warn!("[finish_vanish] nostrdb delete failed: {e}");
+                local_events_purged = false;
+            }
+        }
+        let scheduled_posts_cancelled = match self.scheduled_posts.lock().await.cancel_all() {
+            Ok(n) => n as u32,
+            Err(e) => {
+                tracing::warn!("[finish_vanish] Failed to cancel scheduled posts: {e}");
+                0
+            }
+        };
+        let mls_wiped = match &self.mls {
+            Some(mls) => match mls.wipe() {
+                Ok(()) => true,
+                Err(e) => {
+                    tracing::warn!("[finish_vanish] MLS wipe failed: {e}");
+                    false
+                }
+            },
+            None => false,
+        };
+
+        Ok(VanishResult {
+            event_id: output.val.to_hex(),
+            accepted: output.success.iter().map(|u| u.to_string()).collect(),
+            rejected: output
+                .failed
+                .into_iter()
+                .map(|(url, reason)| RelayRejection {
+                    url: url.to_string(),
+                    reason,
+                })
+                .collect(),
+            local_events_purged,
+            scheduled_posts_cancelled,
+            mls_wiped,
+        })
+    }
+
+    // ─── Event Backup ───────────────────────────────────────────
+
+    /// Every event by the user matching `options`, from nostrdb and each
+    /// relay in the pool, oldest first.  Each source is paged backwards
+    /// with `until`; `on_progress` is called after every page.
+    pub async fn fetch_all_user_events(
         &self,
-        since: Option<Timestamp>,
-        limit: usize,
+        options: &BackupOptions,
+        on_progress: impl Fn(BackupProgress) + Send + Sync,
+    ) -> Result<Vec<Event>> {
+        self.collect_user_events(options, None, on_progress).await
+    }
+
+    /// `fetch_all_user_events`, leaving out the relay `exclude`.
+    async fn collect_user_events(
+        &self,
+        options: &BackupOptions,
+        exclude: Option<&RelayUrl>,
+        on_progress: impl Fn(BackupProgress) + Send + Sync,
     ) -> Result<Vec<Event>> {
         let my_pk = self
             .current_pubkey()
             .await
             .ok_or(NuruNuruError::NoSigningMethod)?;
+        let mut base = Filter::new().author(my_pk);
+        if !options.kinds.is_empty() {
+            base = base.kinds(options.kinds.iter().map(|k| Kind::from(*k)));
+        }
+        let since = options.since.map(Timestamp::from);
+        if let Some(since) = since {
+            base = base.since(since);
+        }
 
-        let filter = filters::dm_filter(my_pk, since, limit);
-        let events = self
-            .client
-            .fetch_events(filter, Duration::from_secs(15))
-            .await?;
+        let mut sources = vec![None];
+        sources.extend(
+            self.client
+                .relays()
+                .await
+                .into_keys()
+                .filter(|url| Some(url) != exclude)
+                .map(Some),
+        );
+        let mut events = HashMap::new();
+        let mut batch = 0;
+        for source in sources {
+            let mut until = options.until.map(Timestamp::from).unwrap_or_else(Timestamp::now);
+            let mut seen = HashSet::new();
+            loop {
+                let filter = base.clone().until(until).limit(backup::PAGE_SIZE);
+                let page: Vec<Event> = match &source {
+                    None => self.query_db(filter).await?,
+                    Some(url) => match self
+                        .client
+                        .fetch_events_from([url.clone()], filter, self.config.relay.eose_timeout)
+                        .await
+                    {
+                        Ok(page) => page.into_iter().collect(),
+                        Err(e) => {
+                            tracing::warn!("[fetch_all_user_events] {url} failed: {e}");
+                            break;
+                        }
+                    },
+                };
+                let next = backup::next_until(&page, &mut seen, until, since);
+                backup::merge(&mut events, page);
+                batch += 1;
+                on_progress(BackupProgress {
+                    processed: events.len() as u64,
+                    total: 0,
+                    batch,
+                });
+                match next {
+                    Some(next) => until = next,
+                    None => break,
+                }
+            }
+        }
 
-        Ok(events.into_iter().collect())
+        let mut events: Vec<Event> = events.into_values().collect();
+        events.sort_by_key(|e| (e.created_at, e.id));
+        Ok(events)
     }
 
-    // ─── Publishing ─────────────────────────────────────────────
+    /// Write every event by the user matching `options` to `path` as JSONL.
+    /// Returns how many events were written.
+    pub async fn export_events(
+        &self,
+        options: &BackupOptions,
+        path: &str,
+        on_progress: impl Fn(BackupProgress) + Send + Sync,
+    ) -> Result<u64> {
+        let events = self.fetch_all_user_events(options, on_progress).await?;
+        std::fs::write(path, backup::to_jsonl(&events))
+            .map_err(|e| NuruNuruError::DatabaseError(e.to_string()))?;
+        Ok(events.len() as u64)
+    }
 
-    /// Publish a text note (kind 1).
-    pub async fn publish_note(&self, content: &str, tags: Vec<Tag>) -> Result<EventId> {
-        let mut builder = EventBuilder::text_note(content);
-        for tag in tags {
-            builder = builder.tag(tag);
+    /// Import a backup (JSONL or a JSON array of events).  Events whose
+    /// signature does not verify are rejected.  With no `relay_urls` the
+    /// events are stored in nostrdb; otherwise they are rebroadcast to
+    /// those relays.
+    pub async fn import_events(
+        &self,
+        data: &str,
+        relay_urls: &[String],
+        on_progress: impl Fn(BackupProgress) + Send + Sync,
+    ) -> Result<BackupImportResult> {
+        let targets: Vec<RelayUrl> = relay_urls
+            .iter()
+            .map(|u| relay::parse_relay_url(u))
+            .collect::<Result<_>>()?;
+        let added = self.connect_targets(&targets).await;
+
+        let (events, errors) = backup::parse(data);
+        let mut result = BackupImportResult {
+            total: events.len() as u64,
+            errors,
+            ..Default::default()
+        };
+        let my_pk = self.current_pubkey().await;
+        for (i, (line, event)) in events.iter().enumerate() {
+            let blocker = if targets.is_empty() {
+                None
+            } else {
+                backup::rebroadcast_blocker(event, my_pk.as_ref())
+            };
+            // `None` = skipped, `Some(Err)` = failed.
+            let outcome = if blocker.is_some() {
+                None
+            } else if targets.is_empty() {
+                Some(
+                    self.client
+                        .database()
+                        .save_event(event)
+                        .await
+                        .map(|_| ())
+                        .map_err(|e| e.to_string()),
+                )
+            } else {
+                Some(match self.client.send_event_to(targets.clone(), event).await {
+                    Ok(output) if !output.success.is_empty() => Ok(()),
+                    Ok(output) => Err(output
+                        .failed
+                        .into_iter()
+                        .map(|(url, reason)| format!("{url}: {reason}"))
+                        .collect::<Vec<_>>()
+                        .join(", ")),
+                    Err(e) => Err(e.to_string()),
+                })
+            };
+            let error = match outcome {
+                Some(Ok(())) => {
+                    result.imported += 1;
+                    None
+                }
+                Some(Err(error)) => {
+                    result.failed += 1;
+                    Some(error)
+                }
+                None => {
+                    result.skipped += 1;
+                    blocker.map(str::to_string)
+                }
+            };
+            if let Some(error) = error {
+                result.errors.push(BackupImportError {
+                    line: *line,
+                    event_id: Some(event.id.to_hex()),
+                    error,
+                });
+            }
+            on_progress(BackupProgress {
+                processed: i as u64 + 1,
+                total: result.total,
+                batch: 0,
+            });
         }
-        let output = self.client.send_event_builder(builder).await?;
-        Ok(output.val)
+        self.remove_temporary_relays(added).await;
+        if targets.is_empty() {
+            let events: Vec<Event> = events.into_iter().map(|(_, e)| e).collect();
+            self.apply_deletions(&events).await?;
+        }
+        Ok(result)
     }
 
-    /// Publish a reaction (kind 7, NIP-25).
-    pub async fn react(&self, event_id: EventId, author: PublicKey, reaction: &str) -> Result<EventId> {
-        let target = ReactionTarget {
-            event_id,
-            public_key: author,
-            coordinate: None,
-            kind: Some(Kind::TextNote),
-            relay_hint: None,
+    // ─── Relay Migration ────────────────────────────────────────
+
+    /// Rebroadcast the user's history (`kinds` empty = all, from `since`)
+    /// to `url`, typically a relay just added with `add_relay`.  Events are
+    /// gathered from nostrdb and the other relays (never the target itself,
+    /// which would only report what it already has), deleted or expired ones
+    /// and those the target already has are skipped, and the rest are sent
+    /// one at a time, backing off when the relay reports `rate-limited:`.
+    /// An interrupted migration resumes where it stopped.
+    pub async fn migrate_history_to_relay(
+        &self,
+        url: &str,
+        kinds: &[u16],
+        since: Option<u64>,
+        on_progress: impl Fn(MigrationProgress) + Send + Sync,
+    ) -> Result<MigrationReport> {
+        let target = relay::parse_relay_url(url)?;
+        let target_key = target.to_string();
+        let mut log = self.migration_log.lock().await;
+        if self.client.relay(&target).await.is_err() {
+            self.client
+                .add_relay(target.clone())
+                .await
+                .map_err(|e| NuruNuruError::RelayError(e.to_string()))?;
+        }
+        self.connect_targets(std::slice::from_ref(&target)).await;
+
+        let options = BackupOptions {
+            kinds: kinds.to_vec(),
+            since,
+            until: None,
         };
-        let builder = EventBuilder::reaction(target, reaction);
-        let output = self.client.send_event_builder(builder).await?;
-        Ok(output.val)
+        let events = self.collect_user_events(&options, Some(&target), |_| {}).await?;
+        let events = self.drop_hidden(events).await;
+
+        let mut on_relay = HashSet::new();
+        let ids: Vec<EventId> = events.iter().map(|e| e.id).collect();
+        for chunk in ids.chunks(migration::ID_CHUNK) {
+            let filter = Filter::new().ids(chunk.iter().copied());
+            match self
+                .client
+                .fetch_events_from([target.clone()], filter, self.config.relay.eose_timeout)
+                .await
+            {
+                Ok(found) => on_relay.extend(found.into_iter().map(|e| e.id)),
+                Err(e) => tracing::warn!("[migrate_history_to_relay] {target} lookup failed: {e}"),
+            }
+        }
+
+        let mut report = MigrationReport {
+            relay_url: target_key.clone(),
+            found: events.len() as u64,
+            already_on_relay: on_relay.len() as u64,
+            ..Default::default()
+        };
+        let missing: Vec<&Event> = events.iter().filter(|e| !on_relay.contains(&e.id)).collect();
+        let total = missing.len() as u64;
+        for (i, event) in missing.into_iter().enumerate() {
+            let id = event.id.to_hex();
+            if log.is_done(&target_key, &id) {
+                report.resumed += 1;
+            } else {
+                match self.send_with_backoff(&target, event).await {
+                    Ok(()) => {
+                        report.published += 1;
+                        log.mark(&target_key, &id);
+                        if report.published as usize % migration::SAVE_EVERY == 0 {
+                            store::save_json(&self.config.store_path("migration"), &*log)?;
+                        }
+                    }
+                    Err(reason) => report.failed.push(MigrationFailure { event_id: id, reason }),
+                }
+                tokio::time::sleep(migration::PUBLISH_INTERVAL).await;
+            }
+            on_progress(MigrationProgress {
+                processed: i as u64 + 1,
+                total,
+                published: report.published,
+                failed: report.failed.len() as u64,
+            });
+        }
+
+        if report.failed.is_empty() {
+            log.clear(&target_key);
+        }
+        store::save_json(&self.config.store_path("migration"), &*log)?;
+        Ok(report)
     }
 
-    /// Repost an event (kind 6, NIP-18).
-    pub async fn repost(&self, event: &Event) -> Result<EventId> {
-        let builder = EventBuilder::repost(event, None);
-        let output = self.client.send_event_builder(builder).await?;
-        Ok(output.val)
+    /// Send `event` to `target` only, retrying with a doubling pause while
+    /// the relay answers `rate-limited:`.  Returns the relay's last reason
+    /// on failure.
+    async fn send_with_backoff(&self, target: &RelayUrl, event: &Event) -> std::result::Result<(), String> {
+        let mut backoff = migration::RATE_LIMIT_BACKOFF;
+        let mut attempt = 0;
+        loop {
+            let reason = match self.client.send_event_to([target.clone()], event).await {
+                Ok(output) if !output.success.is_empty() => return Ok(()),
+                Ok(output) => output.failed.into_values().next().unwrap_or_default(),
+                Err(e) => e.to_string(),
+            };
+            if !migration::is_rate_limited(&reason) || attempt >= migration::MAX_RETRIES {
+                return Err(reason);
+            }
+            attempt += 1;
+            tokio::time::sleep(backoff).await;
+            backoff *= 2;
+        }
     }
 
-    /// Delete an event (kind 5, NIP-09).
-    pub async fn delete_event(&self, event_id: EventId, reason: Option<&str>) -> Result<EventId> {
-        let mut request = EventDeletionRequest::new().id(event_id);
-        if let Some(r) = reason {
-            request = request.reason(r);
+    // ─── Expiration (NIP-40) ───────────────────────────────────
+
+    /// Delete expired events from nostrdb.  Returns how many were due.
+    /// Run periodically by the background task; reads hide expired events
+    /// regardless.
+    pub async fn purge_expired(&self) -> Result<usize> {
+        let due = self
+            .expiry_index
+            .lock()
+            .await
+            .take_due(Timestamp::now().as_secs())?;
+        if due.is_empty() {
+            return Ok(0);
+        }
+        let count = due.len();
+        if let Err(e) = self.client.database().delete(Filter::new().ids(due)).await {
+            tracing::debug!("[purge_expired] nostrdb delete unavailable: {e}");
+        }
+        Ok(count)
+    }
+
+    // ─── Scheduled Posts ────────────────────────────────────────
+
+    /// Queue an event template to be signed and published at `publish_at`.
+    ///
+    /// Internal signers only — the event is signed when it falls due so its
+    /// `created_at` matches the real publish time.  External signers use
+    /// `schedule_signed_event` instead.
+    pub async fn schedule_post(
+        &self,
+        kind: Kind,
+        content: &str,
+        tags: Vec<Tag>,
+        publish_at: Timestamp,
+    ) -> Result<ScheduledPost> {
+        self.require_internal_signer().await?;
+        let payload = ScheduledPayload::Unsigned {
+            kind: kind.as_u16(),
+            content: content.to_string(),
+            tags: tags.iter().map(|t| t.as_slice().to_vec()).collect(),
+        };
+        self.scheduled_posts
+            .lock()
+            .await
+            .insert(payload, publish_at.as_secs(), Timestamp::now().as_secs())
+    }
+
+    /// Queue an already-signed event (NIP-07 / Amber / NIP-46) to be
+    /// broadcast at `publish_at`.  The signature is verified up front.
+    pub async fn schedule_signed_event(
+        &self,
+        event: Event,
+        publish_at: Timestamp,
+    ) -> Result<ScheduledPost> {
+        event
+            .verify()
+            .map_err(|e| NuruNuruError::EventError(e.to_string()))?;
+        let payload = ScheduledPayload::Signed {
+            event_json: serde_json::to_string(&event)?,
+        };
+        self.scheduled_posts
+            .lock()
+            .await
+            .insert(payload, publish_at.as_secs(), Timestamp::now().as_secs())
+    }
+
+    /// List all scheduled posts (pending, failed and recently published),
+    /// ordered by publish time.
+    pub async fn list_scheduled_posts(&self) -> Vec<ScheduledPost> {
+        self.scheduled_posts.lock().await.list()
+    }
+
+    /// Cancel a pending scheduled post.
+    pub async fn cancel_scheduled_post(&self, id: &str) -> Result<()> {
+        self.scheduled_posts.lock().await.cancel(id)
+    }
+
+    /// Move a pending or failed scheduled post to a new publish time.
+    pub async fn reschedule_post(&self, id: &str, publish_at: Timestamp) -> Result<ScheduledPost> {
+        self.scheduled_posts
+            .lock()
+            .await
+            .reschedule(id, publish_at.as_secs())
+    }
+
+    /// Publish every scheduled post whose time has come.
+    ///
+    /// Called by the background scheduler; platforms may also call it
+    /// directly (e.g. from a WorkManager job).  Due posts are taken under
+    /// the queue lock with `take_due`, which marks them in flight: cancel
+    /// and reschedule refuse them and a concurrent pass skips them.  The
+    /// lock is released while publishing and retaken to record each
+    /// outcome, which clears the in-flight mark.
+    /// Returns the number of posts published.
+    pub async fn publish_due_scheduled_posts(&self) -> Result<usize> {
+        let due = self
+            .scheduled_posts
+            .lock()
+            .await
+            .take_due(Timestamp::now().as_secs());
+        let max_attempts = self.config.relay.retry.max_attempts;
+
+        let mut outcomes = Vec::with_capacity(due.len());
+        for post in due {
+            let outcome = self.publish_scheduled_payload(&post.payload).await;
+            outcomes.push((post.id, outcome));
+        }
+
+        let mut queue = self.scheduled_posts.lock().await;
+        let mut published = 0;
+        let mut first_error = None;
+        for (id, outcome) in outcomes {
+            let marked = match outcome {
+                Ok(event_id) => {
+                    published += 1;
+                    queue.mark_published(&id, event_id.to_hex())
+                }
+                Err(e) => {
+                    tracing::warn!("[scheduler] Failed to publish scheduled post {id}: {e}");
+                    queue.mark_attempt_failed(&id, e.to_string(), max_attempts)
+                }
+            };
+            if let Err(e) = marked {
+                first_error.get_or_insert(e);
+            }
+        }
+
+        match first_error {
+            Some(e) => Err(e),
+            None => Ok(published),
+        }
+    }
+
+    async fn publish_scheduled_payload(&self, payload: &ScheduledPayload) -> Result<EventId> {
+        let event = match payload {
+            ScheduledPayload::Signed { event_json } => serde_json::from_str::<Event>(event_json)?,
+            ScheduledPayload::Unsigned { kind, content, tags } => {
+                self.require_internal_signer().await?;
+                let tags: Vec<Tag> = tags
+                    .iter()
+                    .filter_map(|t| Tag::parse(t.clone()).ok())
+                    .collect();
+                let builder = EventBuilder::new(Kind::from(*kind), content).tags(tags);
+                self.client.sign_event_builder(builder).await?
+            }
+        };
+
+        let output = self.client.send_event(&event).await?;
+        if output.success.is_empty() {
+            return Err(NuruNuruError::RelayError(format!(
+                "no relay accepted event {}",
+                event.id
+            )));
         }
-        let builder = EventBuilder::delete(request);
-        let output = self.client.send_event_builder(builder).await?;
         Ok(output.val)
     }
 
+    /// Background task: purge expired events and publish due scheduled
+    /// posts while the engine is alive.
+    ///
+    /// Holds only a `Weak` reference so the task exits once the last strong
+    /// `Arc<NuruNuruEngine>` is dropped.
+    fn spawn_scheduler(engine: Weak<Self>) {
+        tokio::spawn(async move {
+            let mut ticker = tokio::time::interval(SCHEDULER_TICK);
+            loop {
+                ticker.tick().await;
+                let Some(engine) = engine.upgrade() else {
+                    break;
+                };
+                if let Err(e) = engine.purge_expired().await {
+                    tracing::warn!("[scheduler] {e}");
+                }
+                if let Err(e) = engine.save_tombstones().await {
+                    tracing::warn!("[scheduler] {e}");
+                }
+                // Don't burn retry attempts while offline.
+                if engine.connection_stats().await.connected_relays == 0 {
+                    continue;
+                }
+                if let Err(e) = engine.publish_due_scheduled_posts().await {
+                    tracing::warn!("[scheduler] {e}");
+                }
+            }
+        });
+    }
+
     // ─── Search (NIP-50) ────────────────────────────────────────
 
     /// Full-text search via NIP-50.
@@ -727,7 +3404,7 @@ impl NuruNuruEngine {
             .client
             .fetch_events(filter, Duration::from_secs(10))
             .await?;
-        Ok(events.into_iter().collect())
+        Ok(self.filter_muted(events.into_iter().collect()).await)
     }
 
     // ─── Custom Emoji (NIP-30) ──────────────────────────────────
@@ -762,8 +3439,53 @@ impl NuruNuruEngine {
         Ok(emojis)
     }
 
+    /// `shortcode → URL` map of `pubkey`'s emoji list, from nostrdb when
+    /// cached and from relays (`fetch_emoji_set`) otherwise.
+    async fn emoji_map(&self, pubkey: PublicKey) -> HashMap<String, String> {
+        let cached = self
+            .client
+            .database()
+            .query(filters::emoji_filter(pubkey))
+            .await
+            .ok()
+            .and_then(|events| replaceable::newest(events.iter()).cloned());
+        if let Some(event) = cached {
+            return content::emoji_map(&event);
+        }
+        match self.fetch_emoji_set(pubkey).await {
+            Ok(set) => set.into_iter().collect(),
+            Err(e) => {
+                tracing::warn!("[emoji_map] Emoji set fetch failed: {e}");
+                HashMap::new()
+            }
+        }
+    }
+
     // ─── User Preferences (local state) ─────────────────────────
 
+    /// How NIP-36 warned content is handled.
+    pub async fn content_warning_policy(&self) -> ContentWarningPolicy {
+        *self.content_warning_policy.read().await
+    }
+
+    /// Set (and persist) how NIP-36 warned content is handled.
+    /// `Hide` drops warned posts from the recommended feed.
+    pub async fn set_content_warning_policy(&self, policy: ContentWarningPolicy) -> Result<()> {
+        *self.content_warning_policy.write().await = policy;
+        store::save_json(&self.config.store_path("content_warning"), &policy)
+    }
+
+    /// Current NIP-56 report thresholds.
+    pub async fn report_policy(&self) -> ReportPolicy {
+        *self.report_policy.read().await
+    }
+
+    /// Set (and persist) the NIP-56 report thresholds.
+    pub async fn set_report_policy(&self, policy: ReportPolicy) -> Result<()> {
+        *self.report_policy.write().await = policy;
+        store::save_json(&self.config.store_path("report_policy"), &policy)
+    }
+
     /// Mark a post as "not interested" for recommendation filtering.
     pub async fn mark_not_interested(&self, event_id: &str, author_pubkey: &str) {
         let mut ni = self.not_interested_posts.write().await;
@@ -797,6 +3519,12 @@ impl NuruNuruEngine {
                     .entry(author_pubkey.to_string())
                     .or_insert(0) += 1;
             }
+            "bookmark" => {
+                *history
+                    .bookmarked_authors
+                    .entry(author_pubkey.to_string())
+                    .or_insert(0) += 1;
+            }
             _ => {}
         }
     }
@@ -889,6 +3617,45 @@ impl NuruNuruEngine {
         Ok(())
     }
 
+    /// Add the relays of `targets` missing from the pool and wait (up to
+    /// `CONNECT_TIMEOUT`) until each is connected: `send_event_to` only
+    /// reaches connected pool relays.  Returns the relays added here, to be
+    /// dropped again with `remove_temporary_relays`.
+    async fn connect_targets(&self, targets: &[RelayUrl]) -> Vec<RelayUrl> {
+        let mut added = Vec::new();
+        for url in targets {
+            if self.client.relay(url).await.is_ok() {
+                continue;
+            }
+            match self.client.add_relay(url.clone()).await {
+                Ok(_) => added.push(url.clone()),
+                Err(e) => tracing::warn!("[connect_targets] Cannot add {url}: {e}"),
+            }
+        }
+
+        let mut connecting = tokio::task::JoinSet::new();
+        for url in targets {
+            let client = self.client.clone();
+            let url = url.clone();
+            connecting.spawn(async move {
+                if let Err(e) = client.try_connect_relay(url.clone(), CONNECT_TIMEOUT).await {
+                    tracing::debug!("[connect_targets] {url} did not connect: {e}");
+                }
+            });
+        }
+        while connecting.join_next().await.is_some() {}
+        added
+    }
+
+    /// Drop relays `connect_targets` added for a one-off send.
+    async fn remove_temporary_relays(&self, added: Vec<RelayUrl>) {
+        for url in added {
+            if let Err(e) = self.client.remove_relay(url.clone()).await {
+                tracing::debug!("[remove_temporary_relays] {url}: {e}");
+            }
+        }
+    }
+
     /// Disconnect and reconnect to all relays.
     pub async fn reconnect(&self) -> Result<()> {
         self.client.disconnect().await;
@@ -897,7 +3664,47 @@ impl NuruNuruEngine {
     }
 
     /// Query local nostrdb cache without hitting relays.
+    ///
+    /// Events deleted by their authors (NIP-09) are left out.
     pub async fn query_local(&self, filter: Filter) -> Result<Vec<Event>> {
+        let events = self.query_db(filter).await?;
+        Ok(self.purge_deleted(events).await)
+    }
+
+    /// Query every connected relay separately and return what they sent,
+    /// or `None` when no relay finished with EOSE.  A timed-out fetch looks
+    /// exactly like an empty account, so lists that are edited and
+    /// republished must be read through this.
+    async fn fetch_confirmed(&self, filter: Filter) -> Option<Vec<Event>> {
+        let mut fetches = tokio::task::JoinSet::new();
+        for relay in self.client.relays().await.into_values() {
+            if !relay.is_connected() {
+                continue;
+            }
+            let filter = filter.clone();
+            fetches.spawn(async move {
+                let url = relay.url().clone();
+                (url, relay.fetch_events(filter, CONFIRM_TIMEOUT, ReqExitPolicy::ExitOnEOSE).await)
+            });
+        }
+
+        let mut answered = false;
+        let mut events = Vec::new();
+        while let Some(joined) = fetches.join_next().await {
+            match joined {
+                Ok((_, Ok(found))) => {
+                    answered = true;
+                    events.extend(found);
+                }
+                Ok((url, Err(e))) => tracing::debug!("[fetch_confirmed] {url} did not answer: {e}"),
+                Err(e) => tracing::warn!("[fetch_confirmed] Fetch task failed: {e}"),
+            }
+        }
+        answered.then_some(events)
+    }
+
+    /// Raw nostrdb query (no deletion filtering).
+    async fn query_db(&self, filter: Filter) -> Result<Vec<Event>> {
         let events = self
             .client
             .database()
@@ -911,13 +3718,15 @@ impl NuruNuruEngine {
     ///
     /// Unlike `query_local` (which reads only the local nostrdb cache),
     /// this method issues a real REQ to all connected relays and waits
-    /// up to `timeout_secs` seconds for responses.
+    /// up to `timeout_secs` seconds for responses.  Muted users and words
+    /// hide content kinds only; profiles and lists always come through.
     pub async fn fetch_events_raw(&self, filter: Filter, timeout_secs: u64) -> Result<Vec<Event>> {
         let events = self
             .client
             .fetch_events(filter, Duration::from_secs(timeout_secs))
             .await?;
-        Ok(events.into_iter().collect())
+        let mutes = self.mute_filter().await;
+        Ok(events.into_iter().filter(|e| !mutes.hides(e)).collect())
     }
 
     /// Send any `EventBuilder` — used by the FFI's generic `publish_event`.
@@ -936,10 +3745,12 @@ impl NuruNuruEngine {
     /// Returns the event ID on success.
     pub async fn publish_raw_event(&self, event: Event) -> Result<EventId> {
         let output = self.client.send_event(&event).await?;
+        self.apply_deletions(std::slice::from_ref(&event)).await?;
         Ok(output.val)
     }
 
-    /// Publish a note to specific relays only (NIP-70 relay selection).
+    /// Publish a note to specific relays only.  For NIP-70 protected
+    /// notes use `publish_protected_note`.
     pub async fn publish_note_to_relays(
         &self,
         content: &str,
@@ -959,6 +3770,114 @@ impl NuruNuruEngine {
         Ok(output.val)
     }
 
+    /// Record a relay's NIP-11 information document (JSON), fetched by the
+    /// platform, for capability-based relay selection.
+    pub async fn set_relay_information(&self, url: &str, nip11_json: &str) -> Result<()> {
+        let url = relay::parse_relay_url(url)?.to_string();
+        let caps = RelayCapabilities::from_nip11(nip11_json)?;
+        let mut all = self.relay_capabilities.write().await;
+        all.insert(url, caps);
+        store::save_json(&self.config.store_path("relay_info"), &*all)
+    }
+
+    /// Publish a NIP-70 protected note (`["-"]` tag) from the internal
+    /// signer, only to relays whose NIP-11 document lists NIP-70 and NIP-42.
+    ///
+    /// Capabilities come from `set_relay_information`; the engine does not
+    /// fetch NIP-11 itself, so a relay the platform never reported is
+    /// skipped.  `relay_urls` narrows the candidates (empty = every pool
+    /// relay).  Relays answering `auth-required` are authenticated by the
+    /// client (NIP-42) and retried; whatever still refuses is reported.
+    pub async fn publish_protected_note(
+        &self,
+        content: &str,
+        tags: Vec<Tag>,
+        relay_urls: Vec<String>,
+    ) -> Result<ProtectedPublishResult> {
+        self.require_internal_signer().await?;
+        let (targets, skipped) = self.protected_targets(relay_urls).await?;
+        let builder = EventBuilder::text_note(content).tags(tags::with_protected_tag(tags));
+        let event = self.client.sign_event_builder(builder).await?;
+        self.send_protected(&event, targets, skipped).await
+    }
+
+    /// Build an **unsigned** protected note by `author` for external
+    /// signers; publish the signed event with `publish_protected_event`.
+    pub fn build_protected_note(&self, author: PublicKey, content: &str, tags: Vec<Tag>) -> UnsignedEvent {
+        EventBuilder::text_note(content)
+            .tags(tags::with_protected_tag(tags))
+            .build(author)
+    }
+
+    /// Publish an externally signed protected event to the same relays
+    /// `publish_protected_note` would pick.  NIP-42 authentication goes
+    /// through the client's signer, which must be the event's author.
+    pub async fn publish_protected_event(
+        &self,
+        event: Event,
+        relay_urls: Vec<String>,
+    ) -> Result<ProtectedPublishResult> {
+        if !tags::is_protected(&event) {
+            return Err(NuruNuruError::ValidationError(
+                "保護タグ (NIP-70) のないイベントです".to_string(),
+            ));
+        }
+        let (targets, skipped) = self.protected_targets(relay_urls).await?;
+        self.send_protected(&event, targets, skipped).await
+    }
+
+    /// Split `relay_urls` (empty = every pool relay) into relays that
+    /// accept protected events and the rest.  Errors if none do.
+    async fn protected_targets(&self, relay_urls: Vec<String>) -> Result<(Vec<RelayUrl>, Vec<RelayUrl>)> {
+        let candidates: Vec<RelayUrl> = if relay_urls.is_empty() {
+            self.client.relays().await.into_keys().collect()
+        } else {
+            relay_urls
+                .iter()
+                .map(|u| relay::parse_relay_url(u))
+                .collect::<Result<_>>()?
+        };
+
+        let (targets, skipped): (Vec<RelayUrl>, Vec<RelayUrl>) = {
+            let caps = self.relay_capabilities.read().await;
+            candidates.into_iter().partition(|url| {
+                caps.get(&url.to_string())
+                    .is_some_and(RelayCapabilities::accepts_protected)
+            })
+        };
+        if targets.is_empty() {
+            return Err(NuruNuruError::ValidationError(
+                "NIP-70 と NIP-42 に対応したリレーがありません".to_string(),
+            ));
+        }
+        Ok((targets, skipped))
+    }
+
+    async fn send_protected(
+        &self,
+        event: &Event,
+        targets: Vec<RelayUrl>,
+        skipped: Vec<RelayUrl>,
+    ) -> Result<ProtectedPublishResult> {
+        let added = self.connect_targets(&targets).await;
+        let output = self.client.send_event_to(targets, event).await;
+        self.remove_temporary_relays(added).await;
+        let output = output?;
+        Ok(ProtectedPublishResult {
+            event_id: output.val.to_hex(),
+            accepted: output.success.iter().map(|u| u.to_string()).collect(),
+            rejected: output
+                .failed
+                .into_iter()
+                .map(|(url, reason)| RelayRejection {
+                    url: url.to_string(),
+                    reason,
+                })
+                .collect(),
+            skipped: skipped.iter().map(|u| u.to_string()).collect(),
+        })
+    }
+
     /// Store a raw event directly into nostrdb (bypasses relay network).
     ///
     /// Used by `/api/ingest` to persist browser-received events so they are
@@ -973,6 +3892,7 @@ impl NuruNuruEngine {
             .save_event(&event)
             .await
             .map_err(|e| NuruNuruError::DatabaseError(e.to_string()))?;
+        self.apply_deletions(std::slice::from_ref(&event)).await?;
         Ok(status.is_success())
     }
 
@@ -1058,18 +3978,27 @@ impl NuruNuruEngine {
     /// Drain up to `max_count` buffered events from a streaming subscription.
     ///
     /// Returns event JSON strings (empty vec when the buffer is empty or the
-    /// subscription ID is unknown).
+    /// subscription ID is unknown).  Muted events are drained but not
+    /// returned, so fewer than `max_count` may come back.
     pub async fn poll_subscription(&self, sub_id: &str, max_count: usize) -> Vec<String> {
         let map = self.subscription_buffers.lock().await;
-        if let Some(buf) = map.get(sub_id) {
+        let drained: Vec<String> = if let Some(buf) = map.get(sub_id) {
             let buf = buf.clone(); // clone Arc so we can drop the map lock
             drop(map);
             let mut guard = buf.lock().await;
             let count = max_count.min(guard.len());
             guard.drain(..count).collect()
         } else {
-            vec![]
-        }
+            return vec![];
+        };
+
+        let mutes = self.mute_filter().await;
+        drained
+            .into_iter()
+            .filter(|json| {
+                serde_json::from_str::<Event>(json).map_or(true, |e| !mutes.hides(&e))
+            })
+            .collect()
     }
 
     /// Cancel a streaming subscription and clean up all resources.
@@ -60,6 +60,11 @@ pub enum NuruNuruError {
     #[error("フォローリストがありません")]
     NoFollowList,
 
+    /// The newest kind-3 could not be confirmed; publishing would risk
+    /// overwriting the user's follows with a stale or empty list.
+    #[error("最新のフォローリストを確認できません")]
+    FollowListUnconfirmed,
+
     // --- Database ---
     #[error("データベースエラー: {0}")]
     DatabaseError(String),
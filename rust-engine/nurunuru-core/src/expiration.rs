@@ -0,0 +1,111 @@
+//! NIP-40 expiration.
+//!
+//! Expired events are hidden from every read.  nostrdb cannot query by tag
+//! value, so `ExpiryIndex` remembers the expiration of each expiring event
+//! the engine has seen and the background task purges them once due.
+
+use std::collections::HashMap;
+
+use nostr::prelude::*;
+
+use crate::error::Result;
+use crate::store;
+use crate::tags::{first_tag_value, make_tag};
+
+/// `["expiration", now + ttl_secs]`.
+pub fn expiration_tag(now: Timestamp, ttl_secs: u64) -> Tag {
+    make_tag([
+        "expiration".to_string(),
+        now.as_secs().saturating_add(ttl_secs).to_string(),
+    ])
+}
+
+/// Expiration time of `event`, if it has a valid `expiration` tag.
+pub fn expires_at(event: &Event) -> Option<u64> {
+    first_tag_value(event, "expiration")?.parse().ok()
+}
+
+/// `true` if `event` has expired at `now`.
+pub fn is_expired(event: &Event, now: u64) -> bool {
+    expires_at(event).is_some_and(|at| at <= now)
+}
+
+/// Expiring events seen by the engine, pending purge from nostrdb.
+///
+/// Not thread-safe on its own; the engine holds it behind a `Mutex`.
+pub struct ExpiryIndex {
+    path: String,
+    /// Event ID hex → expiration (unix seconds).
+    entries: HashMap<String, u64>,
+}
+
+impl ExpiryIndex {
+    /// Open (or create) the index stored at `path`.
+    pub fn open(path: &str) -> Self {
+        Self {
+            path: path.to_string(),
+            entries: store::load_json(path),
+        }
+    }
+
+    /// Remember every expiring event in `events`.
+    pub fn track<'a>(&mut self, events: impl IntoIterator<Item = &'a Event>) -> Result<()> {
+        let mut changed = false;
+        for event in events {
+            if let Some(at) = expires_at(event) {
+                changed |= self.entries.insert(event.id.to_hex(), at).is_none();
+            }
+        }
+        if changed {
+            store::save_json(&self.path, &self.entries)?;
+        }
+        Ok(())
+    }
+
+    /// Remove and return the events expired at `now`.
+    pub fn take_due(&mut self, now: u64) -> Result<Vec<EventId>> {
+        let due: Vec<String> = self
+            .entries
+            .iter()
+            .filter(|(_, &at)| at <= now)
+            .map(|(id, _)| id.clone())
+            .collect();
+        if due.is_empty() {
+            return Ok(Vec::new());
+        }
+        for id in &due {
+            self.entries.remove(id);
+        }
+        store::save_json(&self.path, &self.entries)?;
+        Ok(due.iter().filter_map(|id| EventId::from_hex(id).ok()).collect())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_expiry_index_takes_due_events() {
+        let keys = Keys::generate();
+        let note = |ttl: u64| {
+            EventBuilder::text_note("bye")
+                .tags([expiration_tag(Timestamp::from(1_000), ttl)])
+                .sign_with_keys(&keys)
+                .unwrap()
+        };
+        let (soon, later) = (note(10), note(100));
+        let plain = EventBuilder::text_note("stay").sign_with_keys(&keys).unwrap();
+        assert!(is_expired(&soon, 1_010));
+        assert!(!is_expired(&plain, u64::MAX));
+
+        let path = std::env::temp_dir().join(format!("nurunuru_expiring_{}.json", std::process::id()));
+        let _ = std::fs::remove_file(&path);
+        let mut index = ExpiryIndex::open(path.to_str().unwrap());
+        index.track([&soon, &later, &plain]).unwrap();
+
+        assert_eq!(index.take_due(1_050).unwrap(), vec![soon.id]);
+        assert!(index.take_due(1_050).unwrap().is_empty());
+        assert_eq!(index.take_due(1_100).unwrap(), vec![later.id]);
+    }
+}
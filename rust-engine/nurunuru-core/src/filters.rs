@@ -111,9 +111,125 @@ pub fn badge_filter(pubkey: PublicKey) -> Filter {
     Filter::new()
         .kind(Kind::Custom(30008))
         .author(pubkey)
+        .identifier("profile_badges")
         .limit(1)
 }
 
+/// Badge definitions (kind 30009, NIP-58) by `issuers` named `identifiers`.
+pub fn badge_definition_filter(issuers: &[PublicKey], identifiers: &[String]) -> Filter {
+    Filter::new()
+        .kind(Kind::Custom(30009))
+        .authors(issuers.iter().copied())
+        .identifiers(identifiers.iter().cloned())
+}
+
+/// Badge awards (kind 8, NIP-58) received by `pubkey`.
+pub fn badge_award_filter(pubkey: PublicKey, limit: usize) -> Filter {
+    Filter::new()
+        .kind(Kind::Custom(8))
+        .pubkey(pubkey)
+        .limit(limit)
+}
+
+/// Bookmark list (kind 10003, NIP-51).
+pub fn bookmark_filter(pubkey: PublicKey) -> Filter {
+    Filter::new()
+        .kind(Kind::Custom(10003))
+        .author(pubkey)
+        .limit(1)
+}
+
+/// Bookmark sets (kind 30003, NIP-51) — all of them, or the one named
+/// `identifier`.
+pub fn bookmark_sets_filter(pubkey: PublicKey, identifier: Option<&str>) -> Filter {
+    let filter = Filter::new().kind(Kind::Custom(30003)).author(pubkey);
+    match identifier {
+        Some(d) => filter.identifier(d),
+        None => filter,
+    }
+}
+
+/// Long-form articles (kind 30023) or drafts (kind 30024) by `authors`
+/// (NIP-23) — all of them, or the one named `identifier`.
+pub fn article_filter(
+    authors: &[PublicKey],
+    draft: bool,
+    identifier: Option<&str>,
+    limit: usize,
+) -> Filter {
+    let kind = if draft { 30024 } else { 30023 };
+    let filter = Filter::new()
+        .kind(Kind::Custom(kind))
+        .authors(authors.iter().copied())
+        .limit(limit);
+    match identifier {
+        Some(d) => filter.identifier(d),
+        None => filter,
+    }
+}
+
+/// Live activities (kind 30311, NIP-53), optionally by `authors`.
+pub fn live_activity_filter(authors: &[PublicKey], since: Option<Timestamp>, limit: usize) -> Filter {
+    let mut filter = Filter::new().kind(Kind::Custom(30311)).limit(limit);
+    if !authors.is_empty() {
+        filter = filter.authors(authors.iter().copied());
+    }
+    if let Some(since) = since {
+        filter = filter.since(since);
+    }
+    filter
+}
+
+/// Live chat messages (kind 1311) of the activity at `coordinate`.
+pub fn live_chat_filter(coordinate: &str, since: Option<Timestamp>, limit: usize) -> Filter {
+    let mut filter = Filter::new()
+        .kind(Kind::Custom(1311))
+        .custom_tags(SingleLetterTag::lowercase(Alphabet::A), [coordinate])
+        .limit(limit);
+    if let Some(since) = since {
+        filter = filter.since(since);
+    }
+    filter
+}
+
+/// Community notes (kind 1985, NIP-32 `birdwatch` namespace) on `event_ids`.
+pub fn birdwatch_label_filter(event_ids: &[EventId], limit: usize) -> Filter {
+    Filter::new()
+        .kind(Kind::Custom(1985))
+        .events(event_ids.iter().copied())
+        .custom_tags(SingleLetterTag::uppercase(Alphabet::L), ["birdwatch"])
+        .limit(limit)
+}
+
+/// Reactions (kind 7) by `raters`, one filter per `AUTHOR_CHUNK` raters.
+/// Not narrowed by `#k`: `lib/nostr.js` ratings carry only the `e` tag, so
+/// community-note ratings are picked out by resolving it
+/// (`birdwatch_labels_by_id_filters`).
+pub fn rating_history_filters(raters: &[PublicKey], limit: usize) -> Vec<Filter> {
+    raters
+        .chunks(AUTHOR_CHUNK)
+        .map(|chunk| {
+            Filter::new()
+                .kind(Kind::Reaction)
+                .authors(chunk.iter().copied())
+                .limit(limit)
+        })
+        .collect()
+}
+
+/// Community notes (kind 1985, `birdwatch` namespace) among `ids`, one
+/// filter per `AUTHOR_CHUNK` IDs.
+pub fn birdwatch_labels_by_id_filters(ids: &[EventId]) -> Vec<Filter> {
+    ids.chunks(AUTHOR_CHUNK)
+        .map(|chunk| {
+            Filter::new()
+                .kind(Kind::Custom(1985))
+                .ids(chunk.iter().copied())
+                .custom_tags(SingleLetterTag::uppercase(Alphabet::L), ["birdwatch"])
+        })
+        .collect()
+}
+
 /// Relay list (kind 10002, NIP-65).
 pub fn relay_list_filter(pubkey: PublicKey) -> Filter {
     Filter::new()
@@ -130,6 +246,111 @@ pub fn dm_relay_list_filter(pubkey: PublicKey) -> Filter {
         .limit(1)
 }
 
+/// Fetch events that notify `pubkey`: mentions / replies / quotes (kind 1),
+/// reposts (kinds 6, 16), reactions (kind 7), zap receipts (kind 9735) and
+/// follow lists (kind 3) carrying a `p` tag for the user.
+pub fn notification_filter(
+    pubkey: PublicKey,
+    since: Option<Timestamp>,
+    until: Option<Timestamp>,
+    limit: usize,
+) -> Filter {
+    let mut f = Filter::new()
+        .kinds([
+            Kind::TextNote,
+            Kind::Repost,
+            Kind::GenericRepost,
+            Kind::Reaction,
+            Kind::ZapReceipt,
+            Kind::ContactList,
+        ])
+        .pubkey(pubkey)
+        .limit(limit);
+    if let Some(since) = since {
+        f = f.since(since);
+    }
+    if let Some(until) = until {
+        f = f.until(until);
+    }
+    f
+}
+
+/// Authors per filter; many relays reject longer `authors` lists.
+pub const AUTHOR_CHUNK: usize = 250;
+
+/// Live subscription to deletion requests (kind 5) by `authors` from
+/// `since` on, one filter per `AUTHOR_CHUNK` authors.
+pub fn live_deletion_filters(authors: &[PublicKey], since: Timestamp) -> Vec<Filter> {
+    authors
+        .chunks(AUTHOR_CHUNK)
+        .map(|chunk| {
+            Filter::new()
+                .kind(Kind::EventDeletion)
+                .authors(chunk.iter().copied())
+                .since(since)
+        })
+        .collect()
+}
+
+/// Live subscription to reports (kind 1984) by `authors` from `since` on,
+/// one filter per `AUTHOR_CHUNK` authors.
+pub fn live_report_filters(authors: &[PublicKey], since: Timestamp) -> Vec<Filter> {
+    authors
+        .chunks(AUTHOR_CHUNK)
+        .map(|chunk| {
+            Filter::new()
+                .kind(Kind::Custom(1984))
+                .authors(chunk.iter().copied())
+                .since(since)
+        })
+        .collect()
+}
+
+/// Fetch deletion requests (kind 5, NIP-09) by `authors` targeting
+/// `event_ids` (`#e`) or addressable `coordinates` (`#a`).  One filter per
+/// tag, since tag conditions within a filter must all match.
+pub fn deletion_filters(
+    event_ids: &[EventId],
+    coordinates: &[String],
+    authors: &[PublicKey],
+) -> Vec<Filter> {
+    let base = Filter::new()
+        .kind(Kind::EventDeletion)
+        .authors(authors.iter().copied());
+    let mut out = Vec::new();
+    if !event_ids.is_empty() {
+        out.push(base.clone().events(event_ids.iter().copied()));
+    }
+    if !coordinates.is_empty() {
+        out.push(base.custom_tags(
+            SingleLetterTag::lowercase(Alphabet::A),
+            coordinates.iter().cloned(),
+        ));
+    }
+    out
+}
+
+/// Reports (kind 1984, NIP-56) by `authors` of `event_ids` (`#e`) or of
+/// the accounts `pubkeys` (`#p`).  One filter per tag, as in
+/// `deletion_filters`.  Meant for nostrdb: relays get `live_report_filters`.
+pub fn report_filters(
+    event_ids: &[EventId],
+    pubkeys: &[PublicKey],
+    authors: &[PublicKey],
+) -> Vec<Filter> {
+    let base = Filter::new()
+        .kind(Kind::Custom(1984))
+        .authors(authors.iter().copied());
+    let mut out = Vec::new();
+    if !event_ids.is_empty() {
+        out.push(base.clone().events(event_ids.iter().copied()));
+    }
+    if !pubkeys.is_empty() {
+        out.push(base.pubkeys(pubkeys.iter().copied()));
+    }
+    out
+}
+
 /// Calculate "since" timestamp for N hours ago.
 pub fn since_hours_ago(hours: u64) -> Timestamp {
     Timestamp::now() - hours * 3600
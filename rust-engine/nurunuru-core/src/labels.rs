@@ -0,0 +1,483 @@
+//! NIP-32 labels (kind 1985) in the `birdwatch` namespace: community notes
+//! attached to events, rated with kind-7 reactions (`+` helpful, `-` not
+//! helpful).  Ratings written by `lib/nostr.js` carry only the `e` tag, so
+//! nothing here relies on the `p` and `k` tags `build_rating` adds.
+//!
+//! Which note is shown is decided by bridging, as in X's Community Notes:
+//! ratings are factorised as `r ≈ μ + i_rater + i_note + f_rater·f_note`.
+//! The factor absorbs agreement explained by a rater's viewpoint, so only
+//! notes rated helpful across viewpoints keep a high intercept.  Raters'
+//! other community-note ratings are fitted alongside, since the ratings of
+//! a handful of notes say little about where a rater stands.
+
+use std::collections::HashMap;
+
+use nostr::prelude::*;
+
+use crate::error::{NuruNuruError, Result};
+use crate::tags::{first_tag_value, make_tag, tag_values};
+use crate::types::{BirdwatchContext, BirdwatchNote, BirdwatchRating, BirdwatchStatus, BirdwatchSummary};
+
+/// Kind of a NIP-32 label.
+pub const LABEL_KIND: u16 = 1985;
+/// Label namespace (`L` tag) of community notes.
+pub const BIRDWATCH_NAMESPACE: &str = "birdwatch";
+/// Ratings a note needs before it can be scored.
+pub const MIN_RATINGS: usize = 5;
+/// Minimum note intercept for `Helpful`.
+pub const HELPFUL_INTERCEPT: f64 = 0.30;
+/// Other reactions fetched per chunk of raters to fit their viewpoints.
+pub const RATING_HISTORY_LIMIT: usize = 2000;
+
+/// Notes leaning further than this along the viewpoint axis are one-sided.
+const MAX_HELPFUL_FACTOR: f64 = 0.5;
+/// Raters closer than this to the mean rater factor have no side: starting
+/// factors are at most 0.1 apart from it, and agreement shared by every
+/// rater moves all factors together.
+const MIN_SIDE_FACTOR: f64 = 0.2;
+/// `NotHelpful` below `NOT_HELPFUL_INTERCEPT - NOT_HELPFUL_SLOPE * |f_note|`.
+const NOT_HELPFUL_INTERCEPT: f64 = -0.05;
+const NOT_HELPFUL_SLOPE: f64 = 0.8;
+
+const EPOCHS: usize = 200;
+const LEARNING_RATE: f64 = 0.05;
+const INTERCEPT_REG: f64 = 0.15;
+const FACTOR_REG: f64 = 0.03;
+
+/// Suffix `lib/nostr.js` appends to the note text for a source URL.
+const SOURCE_PREFIX: &str = "\n\nソース: ";
+
+/// Kind-1985 note on `target` (with its author, if known).
+pub fn build_label(
+    target: EventId,
+    target_author: Option<PublicKey>,
+    context: BirdwatchContext,
+    content: &str,
+    source_url: Option<&str>,
+) -> Result<EventBuilder> {
+    let content = content.trim();
+    if content.is_empty() {
+        return Err(NuruNuruError::ValidationError("コンテンツが必要です".to_string()));
+    }
+    let content = match source_url.map(str::trim).filter(|u| !u.is_empty()) {
+        Some(url) => format!("{content}{SOURCE_PREFIX}{url}"),
+        None => content.to_string(),
+    };
+    let mut tags = vec![
+        make_tag(["L", BIRDWATCH_NAMESPACE]),
+        make_tag(["l", context.as_str(), BIRDWATCH_NAMESPACE]),
+        make_tag(["e".to_string(), target.to_hex()]),
+    ];
+    tags.extend(target_author.map(|pk| make_tag(["p".to_string(), pk.to_hex()])));
+    Ok(EventBuilder::new(Kind::Custom(LABEL_KIND), content).tags(tags))
+}
+
+/// `true` if `event` is a community note.
+pub fn is_birdwatch_label(event: &Event) -> bool {
+    event.kind.as_u16() == LABEL_KIND
+        && tag_values(event, "L").any(|v| v.get(1).is_some_and(|ns| ns == BIRDWATCH_NAMESPACE))
+}
+
+/// Parse a community note, unscored.
+pub fn parse_label(event: &Event) -> Option<BirdwatchNote> {
+    if !is_birdwatch_label(event) {
+        return None;
+    }
+    let context = tag_values(event, "l")
+        .find(|v| v.get(2).is_some_and(|ns| ns == BIRDWATCH_NAMESPACE))
+        .and_then(|v| BirdwatchContext::parse(v.get(1)?))
+        .unwrap_or_default();
+    let (content, source_url) = match event.content.rsplit_once(SOURCE_PREFIX) {
+        Some((text, url)) if !url.trim().is_empty() => (text.to_string(), Some(url.trim().to_string())),
+        _ => (event.content.clone(), None),
+    };
+    Some(BirdwatchNote {
+        event_id: event.id.to_hex(),
+        target_event_id: first_tag_value(event, "e")?.to_string(),
+        author: event.pubkey.to_hex(),
+        context,
+        content,
+        source_url,
+        created_at: event.created_at.as_secs(),
+        helpful_count: 0,
+        not_helpful_count: 0,
+        score: None,
+        status: BirdwatchStatus::NeedsMoreRatings,
+        my_rating: None,
+    })
+}
+
+/// Kind-7 rating of the note `label` by `label_author`.
+pub fn build_rating(label: EventId, label_author: PublicKey, rating: BirdwatchRating) -> EventBuilder {
+    let content = match rating {
+        BirdwatchRating::Helpful => "+",
+        BirdwatchRating::NotHelpful => "-",
+    };
+    EventBuilder::new(Kind::Reaction, content).tags([
+        make_tag(["e".to_string(), label.to_hex()]),
+        make_tag(["p".to_string(), label_author.to_hex()]),
+        make_tag(["k".to_string(), LABEL_KIND.to_string()]),
+    ])
+}
+
+/// `(note ID, rating)` of a kind-7 rating; other reactions are `None`.
+pub fn parse_rating(event: &Event) -> Option<(String, BirdwatchRating)> {
+    if event.kind != Kind::Reaction {
+        return None;
+    }
+    let rating = match event.content.trim() {
+        "+" => BirdwatchRating::Helpful,
+        "-" => BirdwatchRating::NotHelpful,
+        _ => return None,
+    };
+    // NIP-25: the reacted-to event is the last `e` tag.
+    let note = tag_values(event, "e").filter_map(|v| v.get(1)).last()?;
+    Some((note.clone(), rating))
+}
+
+/// One rater's rating of one note.
+#[derive(Debug, Clone)]
+pub struct Rating {
+    pub rater: String,
+    pub note: String,
+    pub helpful: bool,
+}
+
+/// Fitted parameters and status of a note.
+#[derive(Debug, Clone, Copy)]
+pub struct NoteScore {
+    pub intercept: f64,
+    pub factor: f64,
+    pub status: BirdwatchStatus,
+}
+
+/// Score every note in `ratings` by matrix factorisation.  A note is
+/// `Helpful` only with `MIN_RATINGS` ratings, a high intercept and a small
+/// factor; if any of its helpful raters has a side (`MIN_SIDE_FACTOR`),
+/// raters from both sides must have found it helpful.  Without ratings of
+/// other notes nobody has a side and the check is moot.
+pub fn score_notes(ratings: &[Rating]) -> HashMap<String, NoteScore> {
+    let mut mu = 0.0;
+    let mut rater_i: HashMap<&str, f64> = HashMap::new();
+    let mut rater_f: HashMap<&str, f64> = HashMap::new();
+    let mut note_i: HashMap<&str, f64> = HashMap::new();
+    let mut note_f: HashMap<&str, f64> = HashMap::new();
+    for r in ratings {
+        rater_i.entry(&r.rater).or_insert(0.0);
+        rater_f.entry(&r.rater).or_insert_with(|| initial_factor(&r.rater));
+        note_i.entry(&r.note).or_insert(0.0);
+        note_f.entry(&r.note).or_insert_with(|| initial_factor(&r.note));
+    }
+
+    for _ in 0..EPOCHS {
+        for r in ratings {
+            let target = if r.helpful { 1.0 } else { 0.0 };
+            let (iu, fu) = (rater_i[r.rater.as_str()], rater_f[r.rater.as_str()]);
+            let (inn, fnn) = (note_i[r.note.as_str()], note_f[r.note.as_str()]);
+            let err = target - (mu + iu + inn + fu * fnn);
+            mu += LEARNING_RATE * err;
+            rater_i.insert(&r.rater, iu + LEARNING_RATE * (err - INTERCEPT_REG * iu));
+            note_i.insert(&r.note, inn + LEARNING_RATE * (err - INTERCEPT_REG * inn));
+            rater_f.insert(&r.rater, fu + LEARNING_RATE * (err * fnn - FACTOR_REG * fu));
+            note_f.insert(&r.note, fnn + LEARNING_RATE * (err * fu - FACTOR_REG * fnn));
+        }
+    }
+
+    let centre = rater_f.values().sum::<f64>() / rater_f.len().max(1) as f64;
+    note_i
+        .iter()
+        .map(|(&note, &intercept)| {
+            let factor = note_f[note];
+            let of_note: Vec<&Rating> = ratings.iter().filter(|r| r.note == note).collect();
+            let sides: Vec<bool> = of_note
+                .iter()
+                .filter(|r| r.helpful)
+                .map(|r| rater_f[r.rater.as_str()] - centre)
+                .filter(|f| f.abs() >= MIN_SIDE_FACTOR)
+                .map(|f| f > 0.0)
+                .collect();
+            let one_sided = !sides.is_empty() && (sides.iter().all(|&s| s) || sides.iter().all(|&s| !s));
+            let status = if of_note.len() < MIN_RATINGS {
+                BirdwatchStatus::NeedsMoreRatings
+            } else if intercept >= HELPFUL_INTERCEPT && factor.abs() < MAX_HELPFUL_FACTOR && !one_sided {
+                BirdwatchStatus::Helpful
+            } else if intercept <= NOT_HELPFUL_INTERCEPT - NOT_HELPFUL_SLOPE * factor.abs() {
+                BirdwatchStatus::NotHelpful
+            } else {
+                BirdwatchStatus::NeedsMoreRatings
+            };
+            (note.to_string(), NoteScore { intercept, factor, status })
+        })
+        .collect()
+}
+
+/// Small deterministic starting factor in `[-0.1, 0.1]` (FNV-1a of `id`),
+/// so the factorisation does not start at its zero-gradient saddle.
+fn initial_factor(id: &str) -> f64 {
+    let mut hash: u64 = 0xcbf2_9ce4_8422_2325;
+    for b in id.bytes() {
+        hash ^= u64::from(b);
+        hash = hash.wrapping_mul(0x0000_0100_0000_01b3);
+    }
+    ((hash % 1000) as f64 / 999.0 - 0.5) * 0.2
+}
+
+/// Group `labels` by target event, count and score `ratings` (newest per
+/// rater and note; authors cannot rate their own notes) and pick the shown
+/// note of each target.  `history` holds the raters' other reactions
+/// (`filters::rating_history_filters`) and `history_labels` the notes they
+/// point to; it only informs the fit.  `me` fills `my_rating`.
+pub fn summarize(
+    labels: &[Event],
+    ratings: &[Event],
+    history: &[Event],
+    history_labels: &[Event],
+    me: Option<&str>,
+) -> Vec<BirdwatchSummary> {
+    let notes: HashMap<String, BirdwatchNote> = labels
+        .iter()
+        .filter_map(parse_label)
+        .map(|n| (n.event_id.clone(), n))
+        .collect();
+
+    let mut latest: HashMap<(String, String), (Timestamp, BirdwatchRating)> = HashMap::new();
+    for event in ratings {
+        let Some((note_id, rating)) = parse_rating(event) else { continue };
+        let rater = event.pubkey.to_hex();
+        if notes.get(&note_id).is_none_or(|n| n.author == rater) {
+            continue;
+        }
+        let key = (rater, note_id);
+        if latest.get(&key).is_none_or(|(at, _)| *at < event.created_at) {
+            latest.insert(key, (event.created_at, rating));
+        }
+    }
+    let mut fitted: Vec<Rating> = latest
+        .iter()
+        .map(|((rater, note), (_, rating))| Rating {
+            rater: rater.clone(),
+            note: note.clone(),
+            helpful: *rating == BirdwatchRating::Helpful,
+        })
+        .collect();
+    // Fixed order, so the fit does not depend on hash map iteration.
+    fitted.sort_by(|a, b| (&a.note, &a.rater).cmp(&(&b.note, &b.rater)));
+
+    let mut with_history = history_ratings(history, history_labels, &notes);
+    with_history.extend(fitted.iter().cloned());
+    let scores = score_notes(&with_history);
+
+    let mut by_target: HashMap<String, Vec<BirdwatchNote>> = HashMap::new();
+    for (id, mut note) in notes {
+        for r in fitted.iter().filter(|r| r.note == id) {
+            if r.helpful {
+                note.helpful_count += 1;
+            } else {
+                note.not_helpful_count += 1;
+            }
+        }
+        if let Some(score) = scores.get(&id) {
+            note.score = Some(score.intercept);
+            note.status = score.status;
+        }
+        note.my_rating = me.and_then(|me| latest.get(&(me.to_string(), id.clone())).map(|(_, r)| *r));
+        by_target.entry(note.target_event_id.clone()).or_default().push(note);
+    }
+
+    by_target
+        .into_iter()
+        .map(|(target_event_id, mut notes)| {
+            notes.sort_by(|a, b| {
+                let rank = |n: &BirdwatchNote| (n.status != BirdwatchStatus::Helpful) as u8;
+                rank(a)
+                    .cmp(&rank(b))
+                    .then_with(|| b.score.unwrap_or(0.0).total_cmp(&a.score.unwrap_or(0.0)))
+                    .then_with(|| b.created_at.cmp(&a.created_at))
+            });
+            let shown_note_id = notes
+                .first()
+                .filter(|n| n.status == BirdwatchStatus::Helpful)
+                .map(|n| n.event_id.clone());
+            BirdwatchSummary {
+                target_event_id,
+                notes,
+                shown_note_id,
+            }
+        })
+        .collect()
+}
+
+/// Ratings among `history` of community notes in `history_labels` other
+/// than `current`: newest per rater and note, without authors rating their
+/// own notes.  Reactions to anything else are dropped.
+fn history_ratings(history: &[Event], history_labels: &[Event], current: &HashMap<String, BirdwatchNote>) -> Vec<Rating> {
+    let note_authors: HashMap<String, String> = history_labels
+        .iter()
+        .filter(|e| is_birdwatch_label(e))
+        .map(|e| (e.id.to_hex(), e.pubkey.to_hex()))
+        .collect();
+
+    let mut latest: HashMap<(String, String), (Timestamp, bool)> = HashMap::new();
+    for event in history {
+        let Some((note_id, rating)) = parse_rating(event) else { continue };
+        let rater = event.pubkey.to_hex();
+        if current.contains_key(&note_id) || note_authors.get(&note_id).is_none_or(|author| *author == rater) {
+            continue;
+        }
+        let key = (rater, note_id);
+        if latest.get(&key).is_none_or(|(at, _)| *at < event.created_at) {
+            latest.insert(key, (event.created_at, rating == BirdwatchRating::Helpful));
+        }
+    }
+    let mut ratings: Vec<Rating> = latest
+        .into_iter()
+        .map(|((rater, note), (_, helpful))| Rating { rater, note, helpful })
+        .collect();
+    // Fixed order, so the fit does not depend on hash map iteration.
+    ratings.sort_by(|a, b| (&a.note, &a.rater).cmp(&(&b.note, &b.rater)));
+    ratings
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_bridging_note_beats_one_sided_note() {
+        let camp_a: Vec<String> = (0..5).map(|i| format!("a{i}")).collect();
+        let camp_b: Vec<String> = (0..5).map(|i| format!("b{i}")).collect();
+        let mut ratings = Vec::new();
+        let mut rate = |raters: &[String], note: &str, helpful: bool| {
+            for rater in raters {
+                ratings.push(Rating { rater: rater.clone(), note: note.to_string(), helpful });
+            }
+        };
+        // Rating history that splits the raters into two camps.
+        for k in 0..4 {
+            rate(&camp_a, &format!("pa{k}"), true);
+            rate(&camp_b, &format!("pa{k}"), false);
+            rate(&camp_a, &format!("pb{k}"), false);
+            rate(&camp_b, &format!("pb{k}"), true);
+        }
+        rate(&camp_a, "bridge", true);
+        rate(&camp_b, "bridge", true);
+        rate(&camp_a, "one_sided", true);
+        rate(&camp_b, "one_sided", false);
+
+        let scores = score_notes(&ratings);
+        assert_eq!(scores["bridge"].status, BirdwatchStatus::Helpful);
+        assert_ne!(scores["one_sided"].status, BirdwatchStatus::Helpful);
+        assert!(scores["bridge"].intercept > scores["one_sided"].intercept);
+        assert!(scores["one_sided"].factor.abs() > MAX_HELPFUL_FACTOR);
+    }
+
+    #[test]
+    fn test_note_rated_across_camps_needs_rater_history() {
+        let (author, target) = (Keys::generate(), Keys::generate());
+        let raters: Vec<Keys> = (0..6).map(|_| Keys::generate()).collect();
+        let post = EventBuilder::text_note("post").sign_with_keys(&target).unwrap();
+        let label = build_label(post.id, Some(post.pubkey), BirdwatchContext::Misleading, "誤りです", None)
+            .unwrap()
+            .sign_with_keys(&author)
+            .unwrap();
+        let ratings: Vec<Event> = raters
+            .iter()
+            .map(|k| {
+                build_rating(label.id, author.public_key(), BirdwatchRating::Helpful)
+                    .sign_with_keys(k)
+                    .unwrap()
+            })
+            .collect();
+        // Other notes the raters rated: even and odd raters always disagree.
+        let others: Vec<Event> = (0..6)
+            .map(|k| {
+                build_label(post.id, None, BirdwatchContext::MissingContext, &k.to_string(), None)
+                    .unwrap()
+                    .sign_with_keys(&author)
+                    .unwrap()
+            })
+            .collect();
+        let history: Vec<Event> = raters
+            .iter()
+            .enumerate()
+            .flat_map(|(j, keys)| {
+                others.iter().enumerate().map(move |(k, other)| {
+                    let content = if (j + k) % 2 == 0 { "+" } else { "-" };
+                    // `lib/nostr.js` ratings: only the `e` tag.
+                    EventBuilder::new(Kind::Reaction, content)
+                        .tags([make_tag(["e".to_string(), other.id.to_hex()])])
+                        .sign_with_keys(keys)
+                        .unwrap()
+                })
+            })
+            .collect();
+
+        // Alone, the global mean absorbs the agreement.
+        let alone = summarize(std::slice::from_ref(&label), &ratings, &[], &[], None);
+        assert_eq!(alone[0].notes[0].status, BirdwatchStatus::NeedsMoreRatings);
+        // Without the notes they point to, the reactions are not ratings.
+        let unresolved = summarize(std::slice::from_ref(&label), &ratings, &history, &[], None);
+        assert_eq!(unresolved[0].notes[0].status, BirdwatchStatus::NeedsMoreRatings);
+
+        let summary = summarize(std::slice::from_ref(&label), &ratings, &history, &others, None);
+        assert_eq!(summary.len(), 1);
+        assert_eq!(summary[0].shown_note_id, Some(label.id.to_hex()));
+        assert_eq!(summary[0].notes[0].helpful_count, 6);
+    }
+
+    #[test]
+    fn test_label_round_trip_and_self_rating_ignored() {
+        let (author, rater) = (Keys::generate(), Keys::generate());
+        let target = EventBuilder::text_note("post").sign_with_keys(&rater).unwrap();
+        let label = build_label(
+            target.id,
+            Some(target.pubkey),
+            BirdwatchContext::Outdated,
+            "古い情報です",
+            Some("https://example.com/news"),
+        )
+        .unwrap()
+        .sign_with_keys(&author)
+        .unwrap();
+        let self_rating = build_rating(label.id, author.public_key(), BirdwatchRating::Helpful)
+            .sign_with_keys(&author)
+            .unwrap();
+        let rating = build_rating(label.id, author.public_key(), BirdwatchRating::NotHelpful)
+            .sign_with_keys(&rater)
+            .unwrap();
+
+        let rater_hex = rater.public_key().to_hex();
+        let summary = summarize(&[label], &[self_rating, rating], &[], &[], Some(&rater_hex));
+        assert_eq!(summary.len(), 1);
+        assert_eq!(summary[0].target_event_id, target.id.to_hex());
+        assert_eq!(summary[0].shown_note_id, None);
+        let note = &summary[0].notes[0];
+        assert_eq!(note.context, BirdwatchContext::Outdated);
+        assert_eq!(note.content, "古い情報です");
+        assert_eq!(note.source_url.as_deref(), Some("https://example.com/news"));
+        assert_eq!((note.helpful_count, note.not_helpful_count), (0, 1));
+        assert_eq!(note.my_rating, Some(BirdwatchRating::NotHelpful));
+    }
+
+    #[test]
+    fn test_history_keeps_only_others_ratings_of_notes() {
+        let (author, rater) = (Keys::generate(), Keys::generate());
+        let post = EventBuilder::text_note("post").sign_with_keys(&rater).unwrap();
+        let note = build_label(post.id, None, BirdwatchContext::Satire, "風刺です", None)
+            .unwrap()
+            .sign_with_keys(&author)
+            .unwrap();
+        let react = |keys: &Keys, target: EventId| {
+            EventBuilder::new(Kind::Reaction, "+")
+                .tags([make_tag(["e".to_string(), target.to_hex()])])
+                .sign_with_keys(keys)
+                .unwrap()
+        };
+        let history = [react(&rater, note.id), react(&author, note.id), react(&rater, post.id)];
+
+        let ratings = history_ratings(&history, &[note.clone(), post], &HashMap::new());
+        assert_eq!(ratings.len(), 1);
+        assert_eq!(ratings[0].rater, rater.public_key().to_hex());
+        assert_eq!(ratings[0].note, note.id.to_hex());
+    }
+}
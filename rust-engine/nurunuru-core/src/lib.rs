@@ -23,14 +23,37 @@
 //!    UniFFI (Kotlin)  UniFFI (Swift)   WASM (Web)
 //! ```
 
+pub mod articles;
+pub mod backup;
+pub mod badges;
+pub mod bookmarks;
+pub mod compose;
 pub mod config;
+pub mod contacts;
+pub mod content;
+pub mod deletion;
 pub mod engine;
 pub mod error;
+pub mod expiration;
 pub mod filters;
+pub mod labels;
+pub mod live;
+pub mod migration;
 pub mod mls;
+pub mod mute;
+pub mod notifications;
+pub mod reactions;
 pub mod recommendation;
 pub mod relay;
+pub mod replaceable;
+pub mod reports;
+pub mod scheduler;
+pub mod store;
+pub mod tags;
+pub mod thread;
 pub mod types;
+pub mod vanish;
+pub mod zap;
 
 pub use config::NuruNuruConfig;
 pub use engine::NuruNuruEngine;
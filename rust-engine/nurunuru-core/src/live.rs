@@ -0,0 +1,199 @@
+//! NIP-53 live activities (kind 30311) and live chat (kind 1311).
+//!
+//! A live activity is addressable and re-published by its host as the
+//! stream progresses (status, viewer counts).  Chat messages reference the
+//! activity by its `a` coordinate.
+
+use nostr::prelude::*;
+
+use crate::error::{NuruNuruError, Result};
+use crate::replaceable;
+use crate::tags::{coordinate_of, first_tag_value, is_protected, make_tag, tag_values};
+use crate::types::{LiveActivity, LiveChatMessage, LiveParticipant, LiveStatus};
+
+/// Kind of a live activity.
+pub const LIVE_ACTIVITY_KIND: u16 = 30311;
+/// Kind of a live chat message.
+pub const LIVE_CHAT_KIND: u16 = 1311;
+
+/// Events fetched per activity asked for: older versions of the same
+/// activity and activities in other states use up the relay `limit`.
+pub const FETCH_FACTOR: usize = 4;
+
+/// A `live` activity not updated for this long is treated as ended
+/// (NIP-53: hosts that crash never publish `ended`).
+pub const STALE_AFTER_SECS: u64 = 60 * 60;
+
+/// Parse a kind-30311 event.  `now` is used to expire stale `live` status.
+pub fn parse_activity(event: &Event, now: u64) -> Option<LiveActivity> {
+    if event.kind.as_u16() != LIVE_ACTIVITY_KIND {
+        return None;
+    }
+    let coordinate = coordinate_of(event)?;
+    let text = |name: &str| first_tag_value(event, name).map(str::to_string);
+    let number = |name: &str| first_tag_value(event, name).and_then(|v| v.trim().parse::<u64>().ok());
+
+    let mut status = text("status")
+        .and_then(|s| LiveStatus::parse(&s))
+        .unwrap_or(LiveStatus::Planned);
+    if status == LiveStatus::Live && now.saturating_sub(event.created_at.as_secs()) > STALE_AFTER_SECS {
+        status = LiveStatus::Ended;
+    }
+
+    let participants = tag_values(event, "p")
+        .filter_map(|v| {
+            let pubkey = PublicKey::from_hex(v.get(1)?).ok()?;
+            Some(LiveParticipant {
+                pubkey: pubkey.to_hex(),
+                relay: v.get(2).filter(|r| !r.is_empty()).cloned(),
+                role: v.get(3).cloned().unwrap_or_else(|| "Participant".to_string()),
+                proof: v.get(4).filter(|p| !p.is_empty()).cloned(),
+            })
+        })
+        .collect();
+
+    Some(LiveActivity {
+        event_id: event.id.to_hex(),
+        pubkey: event.pubkey.to_hex(),
+        identifier: first_tag_value(event, "d").unwrap_or("").to_string(),
+        coordinate,
+        title: text("title").unwrap_or_default(),
+        summary: text("summary").unwrap_or_default(),
+        image: text("image").unwrap_or_default(),
+        streaming_url: text("streaming"),
+        recording_url: text("recording"),
+        status,
+        starts_at: number("starts"),
+        ends_at: number("ends"),
+        current_participants: number("current_participants"),
+        total_participants: number("total_participants"),
+        participants,
+        hashtags: tag_values(event, "t").filter_map(|v| v.get(1).cloned()).collect(),
+        created_at: event.created_at.as_secs(),
+    })
+}
+
+impl LiveActivity {
+    /// Pubkey of the participant with the `Host` role, or the event author.
+    pub fn host(&self) -> &str {
+        self.participants
+            .iter()
+            .find(|p| p.role.eq_ignore_ascii_case("host"))
+            .map(|p| p.pubkey.as_str())
+            .unwrap_or(&self.pubkey)
+    }
+}
+
+/// Newest version of each activity in `events`, parsed, ordered live
+/// first, then planned (soonest first), then ended (most recent first).
+pub fn latest_activities(events: &[Event], now: u64) -> Vec<LiveActivity> {
+    let mut activities: Vec<LiveActivity> = replaceable::newest_per_coordinate(events)
+        .into_values()
+        .filter_map(|e| parse_activity(e, now))
+        .collect();
+    let rank = |s: LiveStatus| match s {
+        LiveStatus::Live => 0,
+        LiveStatus::Planned => 1,
+        LiveStatus::Ended => 2,
+    };
+    activities.sort_by(|a, b| {
+        rank(a.status).cmp(&rank(b.status)).then_with(|| {
+            let (a_at, b_at) = (a.starts_at.unwrap_or(a.created_at), b.starts_at.unwrap_or(b.created_at));
+            match a.status {
+                LiveStatus::Planned => a_at.cmp(&b_at),
+                _ => b_at.cmp(&a_at),
+            }
+        })
+    });
+    activities
+}
+
+/// Kind-1311 chat message for the activity at `coordinate`
+/// (`30311:<pubkey>:<d>`), with an optional relay hint.
+pub fn build_chat_message(coordinate: &str, content: &str, relay: Option<&str>) -> Result<EventBuilder> {
+    let parsed = Coordinate::parse(coordinate)
+        .map_err(|e| NuruNuruError::ValidationError(format!("無効なライブ配信アドレス: {e}")))?;
+    if parsed.kind.as_u16() != LIVE_ACTIVITY_KIND {
+        return Err(NuruNuruError::ValidationError(format!(
+            "ライブ配信ではありません (kind {})",
+            parsed.kind.as_u16()
+        )));
+    }
+    if content.trim().is_empty() {
+        return Err(NuruNuruError::ValidationError("メッセージが空です".to_string()));
+    }
+    Ok(EventBuilder::new(Kind::Custom(LIVE_CHAT_KIND), content).tags([make_tag([
+        "a",
+        coordinate,
+        relay.unwrap_or(""),
+        "root",
+    ])]))
+}
+
+/// Parse a kind-1311 chat message.
+pub fn parse_chat_message(event: &Event) -> Option<LiveChatMessage> {
+    if event.kind.as_u16() != LIVE_CHAT_KIND {
+        return None;
+    }
+    Some(LiveChatMessage {
+        event_id: event.id.to_hex(),
+        activity: first_tag_value(event, "a")?.to_string(),
+        pubkey: event.pubkey.to_hex(),
+        content: event.content.clone(),
+        created_at: event.created_at.as_secs(),
+        reply_to: first_tag_value(event, "e").map(str::to_string),
+        is_protected: is_protected(event),
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn activity(keys: &Keys, d: &str, status: &str, at: u64) -> Event {
+        let host = keys.public_key().to_hex();
+        EventBuilder::new(Kind::Custom(LIVE_ACTIVITY_KIND), "")
+            .tags([
+                make_tag(["d", d]),
+                make_tag(["title", "配信"]),
+                make_tag(["status", status]),
+                make_tag(["streaming", "https://stream.example/live.m3u8"]),
+                make_tag(["current_participants", "42"]),
+                make_tag(["p", host.as_str(), "", "Host"]),
+            ])
+            .custom_created_at(Timestamp::from(at))
+            .sign_with_keys(keys)
+            .unwrap()
+    }
+
+    #[test]
+    fn test_latest_activity_and_stale_status() {
+        let keys = Keys::generate();
+        let events = vec![
+            activity(&keys, "a", "planned", 1_000),
+            activity(&keys, "a", "live", 2_000),
+            activity(&keys, "b", "live", 100),
+        ];
+        let list = latest_activities(&events, 2_100);
+        assert_eq!(list.len(), 2);
+        assert_eq!(list[0].identifier, "a");
+        assert_eq!(list[0].status, LiveStatus::Live);
+        assert_eq!(list[0].current_participants, Some(42));
+        assert_eq!(list[0].host(), keys.public_key().to_hex());
+        assert_eq!(list[1].status, LiveStatus::Ended);
+    }
+
+    #[test]
+    fn test_chat_message_round_trip() {
+        let keys = Keys::generate();
+        let coordinate = format!("30311:{}:a", keys.public_key());
+        let chat = build_chat_message(&coordinate, "こんばんは", None)
+            .unwrap()
+            .sign_with_keys(&Keys::generate())
+            .unwrap();
+        let message = parse_chat_message(&chat).unwrap();
+        assert_eq!(message.activity, coordinate);
+        assert_eq!(message.content, "こんばんは");
+        assert!(build_chat_message(&format!("30023:{}:a", keys.public_key()), "x", None).is_err());
+    }
+}
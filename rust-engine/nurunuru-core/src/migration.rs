@@ -0,0 +1,73 @@
+//! Rebroadcasting the user's history to a newly added relay.
+//!
+//! Events the relay already has are skipped.  Every event it accepts is
+//! recorded per relay so an interrupted migration resumes where it
+//! stopped; the record is dropped once a migration finishes cleanly.
+
+use std::collections::{HashMap, HashSet};
+use std::time::Duration;
+
+use serde::{Deserialize, Serialize};
+
+/// IDs per `ids` query when asking the target which events it has.
+pub const ID_CHUNK: usize = 250;
+/// Pause between publishes.
+pub const PUBLISH_INTERVAL: Duration = Duration::from_millis(250);
+/// First back-off after a `rate-limited:` rejection; doubles per retry.
+pub const RATE_LIMIT_BACKOFF: Duration = Duration::from_secs(5);
+/// Retries of a rate-limited event before it counts as failed.
+pub const MAX_RETRIES: u32 = 3;
+/// Published events between saves of the resume record.
+pub const SAVE_EVERY: usize = 50;
+
+/// Event IDs already migrated, per relay URL.
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct MigrationLog {
+    #[serde(default)]
+    relays: HashMap<String, HashSet<String>>,
+}
+
+impl MigrationLog {
+    pub fn is_done(&self, relay: &str, event_id: &str) -> bool {
+        self.relays.get(relay).is_some_and(|ids| ids.contains(event_id))
+    }
+
+    pub fn mark(&mut self, relay: &str, event_id: &str) {
+        self.relays
+            .entry(relay.to_string())
+            .or_default()
+            .insert(event_id.to_string());
+    }
+
+    /// Forget `relay` once its migration has completed.
+    pub fn clear(&mut self, relay: &str) {
+        self.relays.remove(relay);
+    }
+}
+
+/// `true` for a NIP-01 `OK` message asking us to slow down.
+pub fn is_rate_limited(reason: &str) -> bool {
+    reason.starts_with("rate-limited")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_log_is_per_relay() {
+        let mut log = MigrationLog::default();
+        log.mark("wss://new.example", "aa");
+        assert!(log.is_done("wss://new.example", "aa"));
+        assert!(!log.is_done("wss://other.example", "aa"));
+
+        let log: MigrationLog = serde_json::from_str(&serde_json::to_string(&log).unwrap()).unwrap();
+        assert!(log.is_done("wss://new.example", "aa"));
+        let mut log = log;
+        log.clear("wss://new.example");
+        assert!(!log.is_done("wss://new.example", "aa"));
+
+        assert!(is_rate_limited("rate-limited: slow down"));
+        assert!(!is_rate_limited("blocked: spam"));
+    }
+}
@@ -41,7 +41,8 @@ type Mdk = mdk_core::MDK<MdkSqliteStorage>;
 /// `MlsManager` is not `Clone`; hold it behind `Option<MlsManager>` in the
 /// engine.  Read-only (anonymous) clients keep `None`.
 pub struct MlsManager {
-    mdk: Mdk,
+    /// Behind a lock so `wipe` can swap in a fresh database.
+    mdk: std::sync::RwLock<Mdk>,
     /// Hex-encoded Nostr public key of the local user.  Set on `login()`.
     /// Uses `RwLock` so `set_user_pubkey` can be called via `&self` from
     /// `NuruNuruEngine::login()` without requiring `&mut self` on the engine.
@@ -62,7 +63,7 @@ impl MlsManager {
         let mdk = Mdk::new(storage);
 
         Ok(Self {
-            mdk,
+            mdk: std::sync::RwLock::new(mdk),
             user_pubkey_hex: std::sync::RwLock::new(nostr_pubkey.to_string()),
             db_path: db_path.to_string(),
         })
@@ -76,6 +77,12 @@ impl MlsManager {
         }
     }
 
+    fn mdk(&self) -> Result<std::sync::RwLockReadGuard<'_, Mdk>> {
+        self.mdk
+            .read()
+            .map_err(|_| NuruNuruError::MlsError("mdk lock poisoned".to_string()))
+    }
+
     fn user_pubkey(&self) -> Result<PublicKey> {
         let hex = self.user_pubkey_hex.read()
             .map_err(|_| NuruNuruError::MlsError("pubkey lock poisoned".to_string()))?;
@@ -96,7 +103,7 @@ impl MlsManager {
             .map_err(|_| NuruNuruError::MlsError(
                 format!("nostr_group_id must be 32 bytes, got {nostr_group_id_hex}")
             ))?;
-        let groups = self.mdk.get_groups()
+        let groups = self.mdk()?.get_groups()
             .map_err(|e| NuruNuruError::MlsError(format!("get_groups: {e}")))?;
         groups.into_iter()
             .find(|g| g.nostr_group_id == nostr_id)
@@ -150,7 +157,7 @@ impl MlsManager {
         let pubkey = self.user_pubkey()?;
         // Publish on default relays; the caller can override the relay list tag later.
         let (content, tags, _hash_ref) = self
-            .mdk
+            .mdk()?
             .create_key_package_for_event(&pubkey, std::iter::empty::<RelayUrl>())
             .map_err(|e| NuruNuruError::MlsError(format!("create_key_package: {e}")))?;
 
@@ -194,7 +201,7 @@ impl MlsManager {
         };
 
         let result = self
-            .mdk
+            .mdk()?
             .create_group(&creator_pk, vec![], config)
             .map_err(|e| NuruNuruError::MlsError(format!("create_group: {e}")))?;
 
@@ -220,7 +227,7 @@ impl MlsManager {
             .map_err(|e| NuruNuruError::MlsError(format!("Invalid KeyPackage event JSON: {e}")))?;
 
         let result = self
-            .mdk
+            .mdk()?
             .add_members(&group_id, &[kp_event])
             .map_err(|e| NuruNuruError::MlsError(format!("add_members: {e}")))?;
 
@@ -272,7 +279,7 @@ impl MlsManager {
     /// on the same group will fail with "pending commit exists".
     pub fn merge_pending_commit(&self, group_id_hex: &str) -> Result<()> {
         let group_id = self.resolve_group_id(group_id_hex)?;
-        self.mdk
+        self.mdk()?
             .merge_pending_commit(&group_id)
             .map_err(|e| NuruNuruError::MlsError(format!("merge_pending_commit: {e}")))
     }
@@ -291,7 +298,7 @@ impl MlsManager {
             .map_err(|e| NuruNuruError::MlsError(format!("Invalid member pubkey: {e}")))?;
 
         let result = self
-            .mdk
+            .mdk()?
             .remove_members(&group_id, &[pk])
             .map_err(|e| NuruNuruError::MlsError(format!("remove_members: {e}")))?;
 
@@ -310,7 +317,7 @@ impl MlsManager {
         let group_id = self.resolve_group_id(group_id_hex)?;
 
         let result = self
-            .mdk
+            .mdk()?
             .leave_group(&group_id)
             .map_err(|e| NuruNuruError::MlsError(format!("leave_group: {e}")))?;
 
@@ -343,7 +350,7 @@ impl MlsManager {
             EventBuilder::new(nostr::Kind::from(14u16), content).build(pubkey);
 
         let event = self
-            .mdk
+            .mdk()?
             .create_message(&group_id, rumor)
             .map_err(|e| NuruNuruError::MlsError(format!("create_message: {e}")))?;
 
@@ -366,7 +373,7 @@ impl MlsManager {
             .map_err(|e| NuruNuruError::MlsError(format!("Invalid event JSON: {e}")))?;
 
         let result = self
-            .mdk
+            .mdk()?
             .process_message(&event)
             .map_err(|e| NuruNuruError::MlsError(format!("process_message: {e}")))?;
 
@@ -414,7 +421,7 @@ impl MlsManager {
         let wrapper_event_id: nostr::EventId = rumor.id();
 
         let welcome = self
-            .mdk
+            .mdk()?
             .process_welcome(&wrapper_event_id, &rumor)
             .map_err(|e| NuruNuruError::MlsError(format!("process_welcome: {e}")))?;
 
@@ -447,7 +454,7 @@ impl MlsManager {
     /// List all groups the local user is a member of.
     pub fn list_groups(&self) -> Result<Vec<MlsGroupInfo>> {
         let groups = self
-            .mdk
+            .mdk()?
             .get_groups()
             .map_err(|e| NuruNuruError::MlsError(format!("get_groups: {e}")))?;
 
@@ -457,11 +464,11 @@ impl MlsManager {
             let mut info = Self::group_to_info(group);
 
             // Enrich with relay and member info
-            match self.mdk.get_relays(&group_id) {
+            match self.mdk()?.get_relays(&group_id) {
                 Ok(relays) => info.relays = relays.iter().map(|r| r.to_string()).collect(),
                 Err(e) => tracing::warn!("[MLS] get_relays failed for {}: {e}", info.group_id_hex),
             }
-            match self.mdk.get_members(&group_id) {
+            match self.mdk()?.get_members(&group_id) {
                 Ok(members) => {
                     info.member_pubkeys = members.iter().map(|pk| pk.to_hex()).collect();
                     info.is_dm = info.member_pubkeys.len() <= 2;
@@ -480,18 +487,18 @@ impl MlsManager {
         let group_id = self.resolve_group_id(group_id_hex)?;
 
         let group = self
-            .mdk
+            .mdk()?
             .get_group(&group_id)
             .map_err(|e| NuruNuruError::MlsError(format!("get_group: {e}")))?
             .ok_or_else(|| NuruNuruError::MlsError(format!("Group not found: {group_id_hex}")))?;
 
         let mut info = Self::group_to_info(group);
 
-        match self.mdk.get_relays(&group_id) {
+        match self.mdk()?.get_relays(&group_id) {
             Ok(relays) => info.relays = relays.iter().map(|r| r.to_string()).collect(),
             Err(e) => tracing::warn!("[MLS] get_relays failed for {group_id_hex}: {e}"),
         }
-        match self.mdk.get_members(&group_id) {
+        match self.mdk()?.get_members(&group_id) {
             Ok(members) => {
                 info.member_pubkeys = members.iter().map(|pk| pk.to_hex()).collect();
                 info.is_dm = info.member_pubkeys.len() <= 2;
@@ -515,7 +522,7 @@ impl MlsManager {
 
         let pagination = mdk_storage_traits::groups::Pagination::new(Some(limit as usize), Some(0));
         let messages = self
-            .mdk
+            .mdk()?
             .get_messages(&mls_group_id, Some(pagination))
             .map_err(|e| NuruNuruError::MlsError(format!("get_messages: {e}")))?;
 
@@ -534,4 +541,32 @@ impl MlsManager {
     pub fn db_path(&self) -> &str {
         &self.db_path
     }
+
+    /// Delete every group, message and key package by recreating the
+    /// SQLite database from scratch (used after a NIP-62 vanish request).
+    pub fn wipe(&self) -> Result<()> {
+        let mut mdk = self
+            .mdk
+            .write()
+            .map_err(|_| NuruNuruError::MlsError("mdk lock poisoned".to_string()))?;
+
+        // Close the current database before deleting its files.
+        let placeholder = MdkSqliteStorage::new_unencrypted(":memory:")
+            .map_err(|e| NuruNuruError::MlsError(format!("SQLite open: {e}")))?;
+        drop(std::mem::replace(&mut *mdk, Mdk::new(placeholder)));
+
+        for suffix in ["", "-wal", "-shm"] {
+            let path = format!("{}{suffix}", self.db_path);
+            match std::fs::remove_file(&path) {
+                Ok(()) => {}
+                Err(e) if e.kind() == std::io::ErrorKind::NotFound => {}
+                Err(e) => return Err(NuruNuruError::MlsError(format!("remove {path}: {e}"))),
+            }
+        }
+
+        let storage = MdkSqliteStorage::new_unencrypted(&self.db_path)
+            .map_err(|e| NuruNuruError::MlsError(format!("SQLite open: {e}")))?;
+        *mdk = Mdk::new(storage);
+        Ok(())
+    }
 }
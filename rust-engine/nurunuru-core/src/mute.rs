@@ -0,0 +1,299 @@
+//! NIP-51 mute list (kind 10000).
+//!
+//! Public entries are tags on the event; private entries are the same kind
+//! of tag array, JSON-encoded and encrypted to self in `content` (NIP-44,
+//! or NIP-04 for lists written by older clients).  Encryption itself goes
+//! through the signer in the engine; this module only converts between
+//! tags and `MuteEntries` and compiles them into a `MuteFilter`.
+
+use std::collections::HashSet;
+
+use nostr::prelude::*;
+
+use crate::types::{MuteEntries, MuteKind, MuteList};
+
+impl MuteEntries {
+    /// Parse entries from raw tags (`["p", …]`, `["word", …]`, …).
+    pub fn from_tags<'a>(tags: impl IntoIterator<Item = &'a [String]>) -> Self {
+        let mut entries = Self::default();
+        for tag in tags {
+            entries.source_tags.push(tag.to_vec());
+            match entry_of(tag) {
+                Some((kind, value)) => {
+                    entries.insert(kind, &value);
+                }
+                None if !tag.is_empty() => entries.other.push(tag.to_vec()),
+                None => {}
+            }
+        }
+        entries
+    }
+
+    /// Raw tags for these entries.  Entries still present keep their
+    /// original tag and position; new entries follow in `p`, `t`, `word`,
+    /// `e` order, and unrecognised tags are kept verbatim.
+    pub fn to_tags(&self) -> Vec<Vec<String>> {
+        let mut emitted = HashSet::new();
+        let mut tags = Vec::new();
+        for tag in &self.source_tags {
+            match entry_of(tag) {
+                Some((kind, value)) => {
+                    if self.values(kind).contains(&value) && emitted.insert((kind, value)) {
+                        tags.push(tag.clone());
+                    }
+                }
+                None if self.other.contains(tag) => tags.push(tag.clone()),
+                None => {}
+            }
+        }
+        for (name, kind) in [
+            ("p", MuteKind::Pubkey),
+            ("t", MuteKind::Hashtag),
+            ("word", MuteKind::Word),
+            ("e", MuteKind::Thread),
+        ] {
+            for value in self.values(kind) {
+                if emitted.insert((kind, value.clone())) {
+                    tags.push(vec![name.to_string(), value.clone()]);
+                }
+            }
+        }
+        tags.extend(self.other.iter().filter(|t| !self.source_tags.contains(t)).cloned());
+        tags
+    }
+
+    /// Add an entry.  Returns `false` if it was already present or empty.
+    pub fn insert(&mut self, kind: MuteKind, value: &str) -> bool {
+        let value = normalize(kind, value);
+        let values = self.values_mut(kind);
+        if value.is_empty() || values.contains(&value) {
+            return false;
+        }
+        values.push(value);
+        true
+    }
+
+    /// Remove an entry.  Returns `false` if it was not present.
+    pub fn remove(&mut self, kind: MuteKind, value: &str) -> bool {
+        let value = normalize(kind, value);
+        let values = self.values_mut(kind);
+        let before = values.len();
+        values.retain(|v| *v != value);
+        values.len() != before
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.pubkeys.is_empty()
+            && self.words.is_empty()
+            && self.hashtags.is_empty()
+            && self.threads.is_empty()
+            && self.other.is_empty()
+    }
+
+    fn values(&self, kind: MuteKind) -> &Vec<String> {
+        match kind {
+            MuteKind::Pubkey => &self.pubkeys,
+            MuteKind::Word => &self.words,
+            MuteKind::Hashtag => &self.hashtags,
+            MuteKind::Thread => &self.threads,
+        }
+    }
+
+    fn values_mut(&mut self, kind: MuteKind) -> &mut Vec<String> {
+        match kind {
+            MuteKind::Pubkey => &mut self.pubkeys,
+            MuteKind::Word => &mut self.words,
+            MuteKind::Hashtag => &mut self.hashtags,
+            MuteKind::Thread => &mut self.threads,
+        }
+    }
+}
+
+/// The entry a tag stands for, normalised; `None` for unrecognised tags.
+fn entry_of(tag: &[String]) -> Option<(MuteKind, String)> {
+    let kind = match tag.first()?.as_str() {
+        "p" => MuteKind::Pubkey,
+        "word" => MuteKind::Word,
+        "t" => MuteKind::Hashtag,
+        "e" => MuteKind::Thread,
+        _ => return None,
+    };
+    Some((kind, normalize(kind, tag.get(1)?)))
+}
+
+/// Canonical form of an entry: hashtags lowercase without `#`, words
+/// lowercase, everything trimmed.
+fn normalize(kind: MuteKind, value: &str) -> String {
+    let value = value.trim();
+    match kind {
+        MuteKind::Hashtag => value.trim_start_matches('#').to_lowercase(),
+        MuteKind::Word => value.to_lowercase(),
+        MuteKind::Pubkey | MuteKind::Thread => value.to_string(),
+    }
+}
+
+/// `true` if `content` looks like a NIP-04 payload (`<base64>?iv=<base64>`)
+/// rather than NIP-44.
+pub fn is_nip04_payload(content: &str) -> bool {
+    content.contains("?iv=")
+}
+
+/// Compiled mute rules (public + private) applied to timelines and feed
+/// scoring.
+#[derive(Debug, Clone, Default)]
+pub struct MuteFilter {
+    pubkeys: HashSet<String>,
+    words: Vec<String>,
+    hashtags: HashSet<String>,
+    threads: HashSet<String>,
+}
+
+impl MuteFilter {
+    pub fn new(list: &MuteList) -> Self {
+        let mut filter = Self::default();
+        for entries in [&list.public, &list.private] {
+            filter.pubkeys.extend(entries.pubkeys.iter().cloned());
+            filter.words.extend(entries.words.iter().cloned());
+            filter.hashtags.extend(entries.hashtags.iter().cloned());
+            filter.threads.extend(entries.threads.iter().cloned());
+        }
+        filter.words.sort();
+        filter.words.dedup();
+        filter
+    }
+
+    /// Add pubkeys muted outside the list (e.g. restored platform state).
+    pub fn add_pubkeys(&mut self, pubkeys: impl IntoIterator<Item = String>) {
+        self.pubkeys.extend(pubkeys);
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.pubkeys.is_empty() && self.words.is_empty() && self.hashtags.is_empty() && self.threads.is_empty()
+    }
+
+    pub fn is_muted_pubkey(&self, pubkey: &str) -> bool {
+        self.pubkeys.contains(pubkey)
+    }
+
+    /// `is_muted`, limited to content kinds (notes, reposts, comments, live
+    /// chat).  Used where arbitrary kinds pass through, so profiles and
+    /// lists — including the mute list itself — are never dropped.
+    pub fn hides(&self, event: &Event) -> bool {
+        is_content_kind(event.kind) && self.is_muted(event)
+    }
+
+    /// `true` if `event` is from a muted user, belongs to a muted thread,
+    /// carries a muted hashtag or contains a muted word.  Reposts are also
+    /// checked against the reposted event embedded in their content.
+    pub fn is_muted(&self, event: &Event) -> bool {
+        if self.is_empty() {
+            return false;
+        }
+        if self.pubkeys.contains(&event.pubkey.to_hex()) || self.threads.contains(&event.id.to_hex()) {
+            return true;
+        }
+
+        for tag in event.tags.iter().map(|t| t.as_slice()) {
+            match (tag.first().map(String::as_str), tag.get(1)) {
+                (Some("e"), Some(id)) if self.threads.contains(id) => return true,
+                (Some("t"), Some(t)) if self.hashtags.contains(&t.to_lowercase()) => return true,
+                _ => {}
+            }
+        }
+
+        if matches!(event.kind, Kind::Repost | Kind::GenericRepost) {
+            if let Ok(inner) = serde_json::from_str::<Event>(&event.content) {
+                return self.is_muted(&inner);
+            }
+            return false;
+        }
+
+        if !self.words.is_empty() {
+            let content = event.content.to_lowercase();
+            if self.words.iter().any(|w| content.contains(w.as_str())) {
+                return true;
+            }
+        }
+        false
+    }
+}
+
+/// Kinds a mute hides when filtering mixed results: text notes (1),
+/// reposts (6, 16), comments (1111) and live chat messages (1311).
+fn is_content_kind(kind: Kind) -> bool {
+    matches!(kind.as_u16(), 1 | 6 | 16 | 1111 | 1311)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn note(keys: &Keys, content: &str, tags: Vec<Vec<String>>) -> Event {
+        EventBuilder::text_note(content)
+            .tags(tags.into_iter().map(|t| Tag::parse(t).unwrap()))
+            .sign_with_keys(keys)
+            .unwrap()
+    }
+
+    #[test]
+    fn test_entries_round_trip_and_normalize() {
+        let tags = vec![
+            vec!["p".to_string(), "abc".to_string()],
+            vec!["t".to_string(), "Spam".to_string()],
+            vec!["word".to_string(), "BadWord".to_string()],
+            vec!["emoji".to_string(), "x".to_string(), "https://e.example/x.png".to_string()],
+        ];
+        let mut entries = MuteEntries::from_tags(tags.iter().map(|t| t.as_slice()));
+        assert_eq!(entries.hashtags, vec!["spam"]);
+        assert_eq!(entries.words, vec!["badword"]);
+        assert!(!entries.insert(MuteKind::Hashtag, "#SPAM"));
+        assert!(entries.remove(MuteKind::Word, "badword"));
+        assert!(entries.insert(MuteKind::Word, "new"));
+        // Untouched entries keep their original tag and position.
+        assert_eq!(
+            entries.to_tags(),
+            vec![tags[0].clone(), tags[1].clone(), tags[3].clone(), vec!["word".to_string(), "new".to_string()]]
+        );
+    }
+
+    #[test]
+    fn test_filter_words_hashtags_threads() {
+        let keys = Keys::generate();
+        let root = note(&keys, "root", vec![]);
+        let mut list = MuteList::default();
+        list.public.insert(MuteKind::Hashtag, "spam");
+        list.private.insert(MuteKind::Word, "ネタバレ");
+        list.private.insert(MuteKind::Thread, &root.id.to_hex());
+        let filter = MuteFilter::new(&list);
+
+        assert!(filter.is_muted(&note(&keys, "hi", vec![vec!["t".into(), "SPAM".into()]])));
+        assert!(filter.is_muted(&note(&keys, "映画のネタバレです", vec![])));
+        assert!(filter.is_muted(&note(
+            &keys,
+            "reply",
+            vec![vec!["e".into(), root.id.to_hex(), String::new(), "root".into()]]
+        )));
+        assert!(!filter.is_muted(&note(&keys, "hello", vec![])));
+    }
+
+    #[test]
+    fn test_hides_only_content_kinds() {
+        let muted = Keys::generate();
+        let mut list = MuteList::default();
+        list.public.insert(MuteKind::Pubkey, &muted.public_key().to_hex());
+        list.public.insert(MuteKind::Word, "nostr");
+        let filter = MuteFilter::new(&list);
+
+        assert!(filter.hides(&note(&muted, "hello", vec![])));
+        let profile = EventBuilder::new(Kind::Metadata, r#"{"name":"nostr"}"#)
+            .sign_with_keys(&muted)
+            .unwrap();
+        assert!(filter.is_muted(&profile));
+        assert!(!filter.hides(&profile));
+        let mute_list = EventBuilder::new(Kind::MuteList, "")
+            .tags([Tag::public_key(muted.public_key())])
+            .sign_with_keys(&Keys::generate())
+            .unwrap();
+        assert!(!filter.hides(&mute_list));
+    }
+}
@@ -0,0 +1,355 @@
+//! Notification aggregation.
+//!
+//! Events tagging the logged-in user (`#p`) are classified into
+//! `Notification`s and merged into `NotificationGroup`s.  Nostr has no
+//! read / unread state, so `NotificationStore` keeps it locally, together
+//! with the time each follower was first seen (kind-3 lists are re-published
+//! on every edit, so their `created_at` says nothing about when the follow
+//! happened).
+
+use std::collections::HashMap;
+
+use nostr::prelude::*;
+use serde::{Deserialize, Serialize};
+
+use crate::error::Result;
+use crate::store;
+use crate::tags::{first_tag_value, tag_values};
+use crate::thread;
+use crate::types::{NotificationGroup, NotificationKind, NotificationPage};
+use crate::zap::ZapReceipt;
+
+/// Individually read event IDs kept per user; the oldest are dropped first.
+const MAX_READ_IDS: usize = 1000;
+
+/// How far before the newest notification already seen
+/// `fetch_new_notifications` queries again, for events that reach the
+/// relays late.
+pub const POLL_OVERLAP_SECS: u64 = 600;
+
+/// One classified notification event.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Notification {
+    pub event_id: String,
+    pub kind: NotificationKind,
+    /// Who acted: the event author, or the sender for zap receipts.
+    pub actor: String,
+    pub created_at: u64,
+    pub target_event_id: Option<String>,
+    pub content: Option<String>,
+    pub zap_msats: u64,
+}
+
+/// Classify `event` as a notification for `me`.  Returns `None` for events
+/// that do not tag `me`, the user's own actions and unsupported kinds.
+pub fn classify(event: &Event, me: &PublicKey) -> Option<Notification> {
+    let me_hex = me.to_hex();
+    if !tag_values(event, "p").any(|v| v.get(1) == Some(&me_hex)) {
+        return None;
+    }
+
+    let author = event.pubkey.to_hex();
+    // NIP-25 / NIP-18: the target is the last `e` tag.
+    let last_e = || {
+        tag_values(event, "e")
+            .filter_map(|v| v.get(1).cloned())
+            .last()
+    };
+
+    let (kind, actor, target, content, zap_msats) = match event.kind {
+        Kind::TextNote => {
+            let text = Some(event.content.clone());
+            if let Some(quoted) = first_tag_value(event, "q") {
+                (NotificationKind::Quote, author, Some(quoted.to_string()), text, 0)
+            } else if let Some(parent) = thread::thread_refs(event).parent_id() {
+                (NotificationKind::Reply, author, Some(parent.to_string()), text, 0)
+            } else {
+                (NotificationKind::Mention, author, None, text, 0)
+            }
+        }
+        Kind::Repost | Kind::GenericRepost => (NotificationKind::Repost, author, last_e(), None, 0),
+        Kind::Reaction => {
+            let emoji = if event.content.is_empty() { "+" } else { event.content.as_str() };
+            (NotificationKind::Reaction, author, last_e(), Some(emoji.to_string()), 0)
+        }
+        Kind::ZapReceipt => {
+            let zap = ZapReceipt::parse(event)?;
+            (NotificationKind::Zap, zap.sender, last_e(), zap.comment, zap.msats)
+        }
+        Kind::ContactList => (NotificationKind::Follow, author, None, None, 0),
+        _ => return None,
+    };
+
+    if actor == me_hex {
+        return None;
+    }
+    Some(Notification {
+        event_id: event.id.to_hex(),
+        kind,
+        actor,
+        created_at: event.created_at.as_secs(),
+        target_event_id: target,
+        content,
+        zap_msats,
+    })
+}
+
+/// Merge `notifications` into groups, most recent first.
+///
+/// Reactions, reposts and zaps are grouped per target event and follows
+/// into a single group; mentions, replies and quotes stay one per event.
+pub fn group(mut notifications: Vec<Notification>, state: &NotificationState) -> NotificationPage {
+    notifications.sort_by(|a, b| b.created_at.cmp(&a.created_at));
+
+    let mut groups: Vec<NotificationGroup> = Vec::new();
+    let mut index: HashMap<String, usize> = HashMap::new();
+    for n in notifications {
+        let key = match (n.kind, &n.target_event_id) {
+            (NotificationKind::Follow, _) => NotificationKind::Follow.as_str().to_string(),
+            (NotificationKind::Reaction | NotificationKind::Repost | NotificationKind::Zap, Some(target)) => {
+                format!("{}:{target}", n.kind.as_str())
+            }
+            _ => format!("{}:{}", n.kind.as_str(), n.event_id),
+        };
+        let unread = !state.is_read(&n.event_id, n.created_at);
+
+        match index.get(&key) {
+            Some(&i) => {
+                let g = &mut groups[i];
+                if g.event_ids.contains(&n.event_id) {
+                    continue;
+                }
+                if !g.actors.contains(&n.actor) {
+                    g.actors.push(n.actor);
+                }
+                g.event_ids.push(n.event_id);
+                g.zap_msats += n.zap_msats;
+                g.unread |= unread;
+            }
+            None => {
+                index.insert(key.clone(), groups.len());
+                groups.push(NotificationGroup {
+                    key,
+                    kind: n.kind,
+                    target_event_id: n.target_event_id,
+                    actors: vec![n.actor],
+                    event_ids: vec![n.event_id],
+                    preview: n.content,
+                    zap_msats: n.zap_msats,
+                    latest_at: n.created_at,
+                    unread,
+                });
+            }
+        }
+    }
+
+    NotificationPage {
+        unread_count: groups.iter().filter(|g| g.unread).count() as u32,
+        oldest_at: None,
+        groups,
+    }
+}
+
+/// Local notification state of one user.
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct NotificationState {
+    /// Everything created at or before this time is read.
+    #[serde(default)]
+    pub read_before: u64,
+    /// Event IDs marked read individually, oldest first.
+    #[serde(default)]
+    pub read_ids: Vec<String>,
+    /// Newest `created_at` returned by `fetch_new_notifications`.
+    #[serde(default)]
+    pub last_checked: Option<u64>,
+    /// Events already returned by `fetch_new_notifications` inside the
+    /// overlap window → their `created_at`.
+    #[serde(default)]
+    pub delivered: HashMap<String, u64>,
+    /// Follower pubkey → when their follow was first seen.
+    #[serde(default)]
+    pub followers: HashMap<String, u64>,
+}
+
+impl NotificationState {
+    pub fn is_read(&self, event_id: &str, created_at: u64) -> bool {
+        created_at <= self.read_before || self.read_ids.iter().any(|id| id == event_id)
+    }
+}
+
+/// Notification state for every user that logged in on this device.
+///
+/// Not thread-safe on its own; the engine holds it behind a `Mutex`.
+pub struct NotificationStore {
+    path: String,
+    /// Owner pubkey hex → state.
+    users: HashMap<String, NotificationState>,
+}
+
+impl NotificationStore {
+    /// Open (or create) the store at `path`.
+    pub fn open(path: &str) -> Self {
+        Self {
+            path: path.to_string(),
+            users: store::load_json(path),
+        }
+    }
+
+    /// State of `owner` (default if never seen).
+    pub fn state(&self, owner: &PublicKey) -> NotificationState {
+        self.users.get(&owner.to_hex()).cloned().unwrap_or_default()
+    }
+
+    /// Date follow notifications by when each follower was first seen,
+    /// recording new followers.
+    pub fn note_followers(&mut self, owner: &PublicKey, notifications: &mut [Notification]) -> Result<()> {
+        let state = self.users.entry(owner.to_hex()).or_default();
+        let mut changed = false;
+        for n in notifications.iter_mut().filter(|n| n.kind == NotificationKind::Follow) {
+            let first_seen = state.followers.entry(n.actor.clone()).or_insert_with(|| {
+                changed = true;
+                n.created_at
+            });
+            n.created_at = *first_seen;
+        }
+        if changed {
+            self.save()?;
+        }
+        Ok(())
+    }
+
+    /// Mark individual notification events as read.
+    pub fn mark_read(&mut self, owner: &PublicKey, event_ids: &[String]) -> Result<()> {
+        let state = self.users.entry(owner.to_hex()).or_default();
+        for id in event_ids {
+            if !state.read_ids.contains(id) {
+                state.read_ids.push(id.clone());
+            }
+        }
+        if state.read_ids.len() > MAX_READ_IDS {
+            let excess = state.read_ids.len() - MAX_READ_IDS;
+            state.read_ids.drain(..excess);
+        }
+        self.save()
+    }
+
+    /// Mark everything created at or before `until` as read.
+    pub fn mark_all_read(&mut self, owner: &PublicKey, until: u64) -> Result<()> {
+        let state = self.users.entry(owner.to_hex()).or_default();
+        state.read_before = state.read_before.max(until);
+        state.read_ids.clear();
+        self.save()
+    }
+
+    /// Drop notifications an earlier poll already returned, remember the
+    /// rest and move the poll cursor to the newest one seen (never past
+    /// `now`; left unchanged when nothing was seen).
+    pub fn take_new(&mut self, owner: &PublicKey, notifications: &mut Vec<Notification>, now: u64) -> Result<()> {
+        let state = self.users.entry(owner.to_hex()).or_default();
+        notifications.retain(|n| !state.delivered.contains_key(&n.event_id));
+
+        let newest = notifications.iter().map(|n| n.created_at.min(now)).max();
+        let cursor = newest.max(state.last_checked);
+        state.last_checked = cursor;
+        for n in notifications.iter() {
+            state.delivered.insert(n.event_id.clone(), n.created_at);
+        }
+        if let Some(cursor) = cursor {
+            let floor = cursor.saturating_sub(POLL_OVERLAP_SECS);
+            state.delivered.retain(|_, at| *at >= floor);
+        }
+        self.save()
+    }
+
+    fn save(&self) -> Result<()> {
+        store::save_json(&self.path, &self.users)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::tags::make_tag;
+
+    fn event(keys: &Keys, kind: Kind, content: &str, tags: Vec<Vec<String>>, at: u64) -> Event {
+        EventBuilder::new(kind, content)
+            .tags(tags.into_iter().map(make_tag))
+            .custom_created_at(Timestamp::from(at))
+            .sign_with_keys(keys)
+            .unwrap()
+    }
+
+    #[test]
+    fn test_classify_and_group() {
+        let me = Keys::generate();
+        let my_post = event(&me, Kind::TextNote, "hello", vec![], 50);
+        let target = vec![
+            vec!["e".to_string(), my_post.id.to_hex()],
+            vec!["p".to_string(), me.public_key().to_hex()],
+        ];
+        let alice = Keys::generate();
+        let bob = Keys::generate();
+        let events = [
+            event(&alice, Kind::Reaction, "+", target.clone(), 100),
+            event(&bob, Kind::Reaction, "🔥", target.clone(), 200),
+            event(&alice, Kind::TextNote, "nice", target.clone(), 150),
+            event(&bob, Kind::TextNote, "hi", vec![target[1].clone()], 120),
+            event(&me, Kind::Reaction, "+", target, 300),
+        ];
+        let items: Vec<Notification> = events
+            .iter()
+            .filter_map(|e| classify(e, &me.public_key()))
+            .collect();
+        assert_eq!(items.len(), 4);
+
+        let state = NotificationState {
+            read_before: 130,
+            ..Default::default()
+        };
+        let page = group(items, &state);
+        let kinds: Vec<NotificationKind> = page.groups.iter().map(|g| g.kind).collect();
+        assert_eq!(
+            kinds,
+            [NotificationKind::Reaction, NotificationKind::Reply, NotificationKind::Mention]
+        );
+        let likes = &page.groups[0];
+        assert_eq!(likes.actors, vec![bob.public_key().to_hex(), alice.public_key().to_hex()]);
+        assert_eq!(likes.preview.as_deref(), Some("🔥"));
+        assert_eq!(page.unread_count, 2);
+    }
+
+    #[test]
+    fn test_take_new_overlaps_without_repeats() {
+        let path = std::env::temp_dir().join(format!("nurunuru_notifications_{}.json", std::process::id()));
+        let _ = std::fs::remove_file(&path);
+        let mut store = NotificationStore::open(path.to_str().unwrap());
+        let owner = Keys::generate().public_key();
+        let item = |id: &str, at: u64| Notification {
+            event_id: id.to_string(),
+            kind: NotificationKind::Mention,
+            actor: "a".to_string(),
+            created_at: at,
+            target_event_id: None,
+            content: None,
+            zap_msats: 0,
+        };
+
+        let mut first = vec![item("a", 1_000), item("b", 2_000)];
+        store.take_new(&owner, &mut first, 5_000).unwrap();
+        assert_eq!(first.len(), 2);
+        // The cursor is the newest event, not the time of the poll.
+        assert_eq!(store.state(&owner).last_checked, Some(2_000));
+
+        // "c" reached the relays late but is inside the overlap window.
+        let mut second = vec![item("b", 2_000), item("c", 1_900)];
+        store.take_new(&owner, &mut second, 6_000).unwrap();
+        assert_eq!(second.iter().map(|n| n.event_id.as_str()).collect::<Vec<_>>(), ["c"]);
+        assert_eq!(store.state(&owner).last_checked, Some(2_000));
+        assert!(!store.state(&owner).delivered.contains_key("a"));
+
+        let mut empty = Vec::new();
+        store.take_new(&owner, &mut empty, 7_000).unwrap();
+        assert_eq!(store.state(&owner).last_checked, Some(2_000));
+        let _ = std::fs::remove_file(&path);
+    }
+}
@@ -0,0 +1,145 @@
+//! Per-emoji reaction (NIP-25) and zapper (NIP-57) breakdown of one event,
+//! for reaction chips and "who reacted" lists.
+
+use std::collections::HashMap;
+
+use nostr::prelude::*;
+
+use crate::compose::emoji_shortcode;
+use crate::tags::tag_values;
+use crate::types::{ReactionDetails, ReactionSummary, ZapperSummary};
+use crate::zap::ZapReceipt;
+
+/// `true` if the last `e` tag of `event` (the NIP-25 / NIP-57 target) is
+/// `event_id`.  Events merely mentioning the target do not count.
+fn targets(event: &Event, event_id: &str) -> bool {
+    tag_values(event, "e")
+        .filter_map(|v| v.get(1))
+        .last()
+        .is_some_and(|id| id == event_id)
+}
+
+/// Summarise `reactions` (kind 7) and `zaps` (kind 9735) of `event_id`.
+/// `me` is the logged-in user's pubkey hex.
+pub fn summarize(event_id: &str, reactions: &[Event], zaps: &[Event], me: Option<&str>) -> ReactionDetails {
+    let mut reactions: Vec<&Event> = reactions
+        .iter()
+        .filter(|e| e.kind == Kind::Reaction && targets(e, event_id))
+        .collect();
+    reactions.sort_by(|a, b| b.created_at.cmp(&a.created_at));
+
+    let mut details = ReactionDetails {
+        event_id: event_id.to_string(),
+        ..Default::default()
+    };
+    let mut index: HashMap<(String, Option<String>), usize> = HashMap::new();
+    for event in reactions {
+        let content = match event.content.trim() {
+            "" => "+".to_string(),
+            c => c.to_string(),
+        };
+        let emoji_url = emoji_shortcode(&content).and_then(|code| {
+            tag_values(event, "emoji")
+                .find(|v| v.get(1).is_some_and(|c| c == code))
+                .and_then(|v| v.get(2).cloned())
+        });
+        let reactor = event.pubkey.to_hex();
+        let mine = me == Some(reactor.as_str());
+        if mine && details.my_reaction.is_none() {
+            details.my_reaction = Some(content.clone());
+        }
+
+        let i = *index.entry((content.clone(), emoji_url.clone())).or_insert_with(|| {
+            details.reactions.push(ReactionSummary {
+                content,
+                emoji_url,
+                reactors: Vec::new(),
+                reacted_by_me: false,
+            });
+            details.reactions.len() - 1
+        });
+        let summary = &mut details.reactions[i];
+        if !summary.reactors.contains(&reactor) {
+            summary.reactors.push(reactor);
+        }
+        summary.reacted_by_me |= mine;
+    }
+    // Stable sort keeps most-recent-first among equal counts.
+    details.reactions.sort_by(|a, b| b.reactors.len().cmp(&a.reactors.len()));
+
+    let mut zaps: Vec<&Event> = zaps
+        .iter()
+        .filter(|e| e.kind == Kind::ZapReceipt && targets(e, event_id))
+        .collect();
+    zaps.sort_by(|a, b| b.created_at.cmp(&a.created_at));
+
+    let mut zappers: HashMap<String, ZapperSummary> = HashMap::new();
+    for event in zaps {
+        let Some(zap) = ZapReceipt::parse(event) else {
+            continue;
+        };
+        details.total_zap_msats += zap.msats;
+        let entry = zappers.entry(zap.sender.clone()).or_insert_with(|| ZapperSummary {
+            pubkey: zap.sender,
+            msats: 0,
+            zap_count: 0,
+            comment: None,
+        });
+        entry.msats += zap.msats;
+        entry.zap_count += 1;
+        if entry.comment.is_none() {
+            entry.comment = zap.comment;
+        }
+    }
+    details.zappers = zappers.into_values().collect();
+    details
+        .zappers
+        .sort_by(|a, b| b.msats.cmp(&a.msats).then_with(|| a.pubkey.cmp(&b.pubkey)));
+
+    details
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::tags::make_tag;
+
+    fn reaction(keys: &Keys, target: &str, content: &str, extra: Vec<Vec<&str>>, at: u64) -> Event {
+        let mut tags = vec![make_tag(["e", target])];
+        tags.extend(extra.into_iter().map(make_tag));
+        EventBuilder::new(Kind::Reaction, content)
+            .tags(tags)
+            .custom_created_at(Timestamp::from(at))
+            .sign_with_keys(keys)
+            .unwrap()
+    }
+
+    #[test]
+    fn test_counts_per_emoji_and_my_reaction() {
+        let target = EventBuilder::text_note("post").sign_with_keys(&Keys::generate()).unwrap();
+        let id = target.id.to_hex();
+        let other = EventBuilder::text_note("other").sign_with_keys(&Keys::generate()).unwrap();
+        let (me, alice, bob) = (Keys::generate(), Keys::generate(), Keys::generate());
+        let url = "https://e.example/nuru.png";
+        let events = vec![
+            reaction(&alice, &id, "+", vec![], 100),
+            reaction(&alice, &id, "", vec![], 110),
+            reaction(&bob, &id, "+", vec![], 120),
+            reaction(&me, &id, ":nuru:", vec![vec!["emoji", "nuru", url]], 130),
+            reaction(&bob, &other.id.to_hex(), "🔥", vec![], 140),
+        ];
+
+        let my_hex = me.public_key().to_hex();
+        let details = summarize(&id, &events, &[], Some(&my_hex));
+        assert_eq!(details.reactions.len(), 2);
+        assert_eq!(details.reactions[0].content, "+");
+        assert_eq!(
+            details.reactions[0].reactors,
+            vec![bob.public_key().to_hex(), alice.public_key().to_hex()]
+        );
+        assert_eq!(details.reactions[1].emoji_url.as_deref(), Some(url));
+        assert!(details.reactions[1].reacted_by_me);
+        assert_eq!(details.my_reaction.as_deref(), Some(":nuru:"));
+        assert!(details.zappers.is_empty());
+    }
+}
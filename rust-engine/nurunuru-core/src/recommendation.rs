@@ -3,7 +3,7 @@
 //! Ported from `lib/recommendation.js`. The core scoring formula is:
 //!
 //! ```text
-//! Score = Engagement × SocialBoost × AuthorQuality × GeohashBoost × AuthorModifier × TimeDecay
+//! Score = Engagement × SocialBoost × AuthorQuality × GeohashBoost × AuthorModifier × ReportModifier × TimeDecay
 //! ```
 //!
 //! Feed mix targets:
@@ -13,7 +13,13 @@
 
 use std::collections::{HashMap, HashSet};
 
+use nostr::Event;
+
 use crate::config::RecommendationConfig;
+use crate::content;
+use crate::mute::MuteFilter;
+use crate::reports::{self, ReportAction, TrustedReports};
+use crate::tags;
 use crate::types::{EngagementData, EngagementHistory, ScoredPost, UserProfile};
 
 /// Stateless recommendation engine. All mutable user state
@@ -85,7 +91,15 @@ impl RecommendationEngine {
             .replied_authors
             .get(author_pubkey)
             .unwrap_or(&0) as f64;
-        let total_engagements = liked + reposted * 2.0 + replied * 3.0;
+        let bookmarked = *engagement_history
+            .bookmarked_authors
+            .get(author_pubkey)
+            .unwrap_or(&0) as f64;
+        // Bookmarks count relative to likes by their configured weights.
+        let w = &self.config.engagement_weights;
+        let bookmark_factor = if w.like > 0.0 { w.bookmark / w.like } else { 1.0 };
+        let total_engagements =
+            liked + reposted * 2.0 + replied * 3.0 + bookmarked * bookmark_factor;
 
         let engagement_boost = if total_engagements >= 10.0 {
             sb.high_engagement_author
@@ -153,27 +167,41 @@ impl RecommendationEngine {
 
     /// Full recommendation score for a single post.
     /// Returns `None` if the post should be filtered out (muted, not-interested).
+    ///
+    /// `event` enables the content-based mutes (words, hashtags, threads);
+    /// without it only muted authors are filtered.  Posts reported by
+    /// enough trusted accounts (`reports`) are down-ranked or dropped.
     pub fn score_post(
         &self,
         event_id: &str,
         author_pubkey: &str,
         created_at: u64,
+        event: Option<&Event>,
         engagement: &EngagementData,
         follow_list: &HashSet<String>,
         second_degree_follows: &HashSet<String>,
         followers: &HashSet<String>,
         engagement_history: &EngagementHistory,
         profiles: &HashMap<String, UserProfile>,
-        muted_pubkeys: &HashSet<String>,
+        mutes: &MuteFilter,
+        reports: &TrustedReports,
         not_interested_posts: &HashSet<String>,
         author_scores: &HashMap<String, f64>,
         user_geohash: Option<&str>,
         follower_count: u64,
     ) -> Option<f64> {
         // Hard filters
-        if not_interested_posts.contains(event_id) || muted_pubkeys.contains(author_pubkey) {
+        if not_interested_posts.contains(event_id)
+            || mutes.is_muted_pubkey(author_pubkey)
+            || event.is_some_and(|e| mutes.is_muted(e))
+        {
             return None;
         }
+        let report_modifier = match reports.action(event_id, author_pubkey) {
+            ReportAction::Hide => return None,
+            ReportAction::Downrank => reports::DOWNRANK_FACTOR,
+            ReportAction::None => 1.0,
+        };
 
         let eng_score = self.engagement_score(engagement);
 
@@ -200,7 +228,7 @@ impl RecommendationEngine {
 
         let time = self.time_decay(created_at);
 
-        let final_score = eng_score * social * quality * geo * author_modifier * time;
+        let final_score = eng_score * social * quality * geo * author_modifier * report_modifier * time;
 
         if final_score > 0.0 {
             Some(final_score)
@@ -210,16 +238,20 @@ impl RecommendationEngine {
     }
 
     /// Sort and rank a batch of posts, applying category mixing.
+    ///
+    /// `events` (event_id → Event) supplies content for mute matching.
     pub fn rank_feed(
         &self,
         posts: &[(String, String, u64)], // (event_id, pubkey, created_at)
+        events: &HashMap<String, Event>,
         engagements: &HashMap<String, EngagementData>,
         follow_list: &HashSet<String>,
         second_degree_follows: &HashSet<String>,
         followers: &HashSet<String>,
         engagement_history: &EngagementHistory,
         profiles: &HashMap<String, UserProfile>,
-        muted_pubkeys: &HashSet<String>,
+        mutes: &MuteFilter,
+        reports: &TrustedReports,
         not_interested_posts: &HashSet<String>,
         author_scores: &HashMap<String, f64>,
         user_geohash: Option<&str>,
@@ -238,13 +270,15 @@ impl RecommendationEngine {
                     eid,
                     pk,
                     *ts,
+                    events.get(eid),
                     eng,
                     follow_list,
                     second_degree_follows,
                     followers,
                     engagement_history,
                     profiles,
-                    muted_pubkeys,
+                    mutes,
+                    reports,
                     not_interested_posts,
                     author_scores,
                     user_geohash,
@@ -255,6 +289,8 @@ impl RecommendationEngine {
                     pubkey: pk.clone(),
                     score,
                     created_at: *ts,
+                    content_warning: events.get(eid).and_then(content::content_warning),
+                    is_protected: events.get(eid).is_some_and(tags::is_protected),
                 })
             })
             .collect();
@@ -395,13 +431,14 @@ mod tests {
     #[test]
     fn test_muted_post_filtered() {
         let engine = make_engine();
-        let mut muted = HashSet::new();
-        muted.insert("bad_author".to_string());
+        let mut muted = MuteFilter::default();
+        muted.add_pubkeys(["bad_author".to_string()]);
 
         let result = engine.score_post(
             "event1",
             "bad_author",
             0,
+            None,
             &EngagementData::default(),
             &HashSet::new(),
             &HashSet::new(),
@@ -409,6 +446,7 @@ mod tests {
             &EngagementHistory::default(),
             &HashMap::new(),
             &muted,
+            &TrustedReports::default(),
             &HashSet::new(),
             &HashMap::new(),
             None,
@@ -417,6 +455,18 @@ mod tests {
         assert!(result.is_none());
     }
 
+    #[test]
+    fn test_bookmarked_author_boost() {
+        let engine = make_engine();
+        let mut history = EngagementHistory::default();
+        history.bookmarked_authors.insert("fav".to_string(), 2);
+
+        let empty = HashSet::new();
+        let boosted = engine.social_boost("fav", &empty, &empty, &empty, &history);
+        let plain = engine.social_boost("other", &empty, &empty, &empty, &history);
+        assert!(boosted > plain);
+    }
+
     #[test]
     fn test_2nd_degree_extraction() {
         let mut my_follows = HashSet::new();
@@ -5,6 +5,7 @@
 
 use crate::config::RelayConfig;
 use nostr::prelude::*;
+use serde::{Deserialize, Serialize};
 
 /// Validate a relay URL.
 /// Rules (from JS `isValidRelayUrl`):
@@ -66,6 +67,46 @@ pub fn parse_relay_url(url: &str) -> crate::Result<RelayUrl> {
     RelayUrl::parse(url).map_err(|e| crate::NuruNuruError::InvalidRelayUrl(e.to_string()))
 }
 
+/// The parts of a relay's NIP-11 information document used for relay
+/// selection.  The platform fetches the document (plain HTTPS with
+/// `Accept: application/nostr+json`) and hands it to the engine.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct RelayCapabilities {
+    pub supported_nips: Vec<u16>,
+    /// `limitation.auth_required`: NIP-42 auth before any request.
+    pub auth_required: bool,
+}
+
+impl RelayCapabilities {
+    /// Parse a NIP-11 document, ignoring fields that are malformed.
+    pub fn from_nip11(json: &str) -> crate::Result<Self> {
+        let doc: serde_json::Value = serde_json::from_str(json)?;
+        let supported_nips = doc["supported_nips"]
+            .as_array()
+            .map(|nips| {
+                nips.iter()
+                    .filter_map(|n| n.as_u64())
+                    .filter_map(|n| u16::try_from(n).ok())
+                    .collect()
+            })
+            .unwrap_or_default();
+        Ok(Self {
+            supported_nips,
+            auth_required: doc["limitation"]["auth_required"].as_bool().unwrap_or(false),
+        })
+    }
+
+    pub fn supports(&self, nip: u16) -> bool {
+        self.supported_nips.contains(&nip)
+    }
+
+    /// NIP-70 relays only accept a protected (`["-"]`) event from its
+    /// author after NIP-42 auth, so both are required.
+    pub fn accepts_protected(&self) -> bool {
+        self.supports(70) && self.supports(42)
+    }
+}
+
 /// Known Japanese relay regions with approximate geohash prefixes.
 /// Used for proximity-based relay selection.
 pub struct RegionalRelay {
@@ -156,6 +197,18 @@ mod tests {
         assert!(is_valid_relay_url("wss://localhost", true));
     }
 
+    #[test]
+    fn test_nip11_protected_support() {
+        let caps = RelayCapabilities::from_nip11(
+            r#"{"name":"r","supported_nips":[1,11,42,70],"limitation":{"auth_required":false}}"#,
+        )
+        .unwrap();
+        assert!(caps.accepts_protected());
+        let caps = RelayCapabilities::from_nip11(r#"{"supported_nips":[1,70,"x"]}"#).unwrap();
+        assert_eq!(caps.supported_nips, vec![1, 70]);
+        assert!(!caps.accepts_protected());
+    }
+
     #[test]
     fn test_proximity_japan() {
         let relays = select_relays_by_proximity("xn76u");
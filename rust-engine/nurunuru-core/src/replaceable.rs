@@ -0,0 +1,69 @@
+//! Picking the current version of replaceable (kinds 0, 3, 10000–19999)
+//! and addressable (30000–39999) events.
+//!
+//! Relays and nostrdb may both hold several versions; NIP-01 keeps the one
+//! with the highest `created_at` and, on a tie, the lowest event ID.
+
+use std::collections::HashMap;
+
+use nostr::prelude::*;
+
+use crate::tags::coordinate_of;
+
+/// Newest replaceable event per NIP-01: highest `created_at`, ties broken by
+/// the lowest event ID.
+pub fn newest<'a>(events: impl IntoIterator<Item = &'a Event>) -> Option<&'a Event> {
+    events
+        .into_iter()
+        .min_by(|a, b| b.created_at.cmp(&a.created_at).then_with(|| a.id.cmp(&b.id)))
+}
+
+/// `newest` version of each addressable event among `events`, keyed by
+/// coordinate (`kind:pubkey:d`).  Other events are ignored.
+pub fn newest_per_coordinate<'a>(events: impl IntoIterator<Item = &'a Event>) -> HashMap<String, &'a Event> {
+    let mut by_coordinate: HashMap<String, &Event> = HashMap::new();
+    for event in events {
+        let Some(coordinate) = coordinate_of(event) else { continue };
+        let current = by_coordinate.get(&coordinate).copied();
+        let pick = newest(current.into_iter().chain([event])).unwrap_or(event);
+        by_coordinate.insert(coordinate, pick);
+    }
+    by_coordinate
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::tags::make_tag;
+
+    fn set(keys: &Keys, d: &str, content: &str, at: u64) -> Event {
+        EventBuilder::new(Kind::Custom(30003), content)
+            .tags([make_tag(["d", d])])
+            .custom_created_at(Timestamp::from(at))
+            .sign_with_keys(keys)
+            .unwrap()
+    }
+
+    #[test]
+    fn test_newest_breaks_ties_by_lowest_id() {
+        let me = Keys::generate();
+        let a = set(&me, "x", "a", 100);
+        let b = set(&me, "x", "b", 100);
+        let lowest = if a.id < b.id { a.id } else { b.id };
+        assert_eq!(newest([&a, &b]).unwrap().id, lowest);
+        assert_eq!(newest([&b, &a]).unwrap().id, lowest);
+    }
+
+    #[test]
+    fn test_newest_per_coordinate() {
+        let me = Keys::generate();
+        let old = set(&me, "x", "old", 100);
+        let new = set(&me, "x", "new", 200);
+        let other = set(&me, "y", "other", 50);
+        let note = EventBuilder::text_note("hi").sign_with_keys(&me).unwrap();
+
+        let latest = newest_per_coordinate([&new, &other, &old, &note]);
+        assert_eq!(latest.len(), 2);
+        assert_eq!(latest[&format!("30003:{}:x", me.public_key())].id, new.id);
+    }
+}
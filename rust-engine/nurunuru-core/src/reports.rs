@@ -0,0 +1,150 @@
+//! NIP-56 reports (kind 1984) and the moderation signal they feed into
+//! ranking.
+//!
+//! Only reports by trusted accounts (people the user follows, and the user)
+//! count.  A report with `e` tags targets those events; one with only a `p`
+//! tag targets everything the user posts.
+
+use std::collections::{HashMap, HashSet};
+
+use nostr::prelude::*;
+
+use crate::tags::{make_tag, tag_values};
+use crate::types::{ReportPolicy, ReportType};
+
+/// Kind of a report.
+pub const REPORT_KIND: u16 = 1984;
+/// Score multiplier for down-ranked posts.
+pub const DOWNRANK_FACTOR: f64 = 0.2;
+
+/// Kind-1984 report of `pubkey`, or of `event` by `pubkey` when given.
+pub fn build_report(
+    pubkey: PublicKey,
+    event: Option<EventId>,
+    report_type: ReportType,
+    reason: &str,
+) -> EventBuilder {
+    let mut tags = vec![make_tag([
+        "p".to_string(),
+        pubkey.to_hex(),
+        report_type.as_str().to_string(),
+    ])];
+    if let Some(id) = event {
+        tags.push(make_tag([
+            "e".to_string(),
+            id.to_hex(),
+            report_type.as_str().to_string(),
+        ]));
+    }
+    EventBuilder::new(Kind::Custom(REPORT_KIND), reason.trim()).tags(tags)
+}
+
+/// What ranking should do with a post.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ReportAction {
+    None,
+    Downrank,
+    Hide,
+}
+
+/// Reports by trusted accounts, indexed by target, with the thresholds to
+/// apply.
+#[derive(Debug, Clone, Default)]
+pub struct TrustedReports {
+    policy: ReportPolicy,
+    /// Event ID hex → reporters.
+    by_event: HashMap<String, HashSet<String>>,
+    /// Pubkey hex → reporters of the account itself.
+    by_pubkey: HashMap<String, HashSet<String>>,
+}
+
+impl TrustedReports {
+    /// Index the reports in `events` whose author is in `trusted`.
+    pub fn new<'a>(
+        events: impl IntoIterator<Item = &'a Event>,
+        trusted: &HashSet<String>,
+        policy: ReportPolicy,
+    ) -> Self {
+        let mut reports = Self {
+            policy,
+            ..Default::default()
+        };
+        for event in events {
+            let reporter = event.pubkey.to_hex();
+            if event.kind.as_u16() != REPORT_KIND || !trusted.contains(&reporter) {
+                continue;
+            }
+            let targets: Vec<String> = tag_values(event, "e").filter_map(|v| v.get(1).cloned()).collect();
+            let (index, targets) = if targets.is_empty() {
+                let pubkeys = tag_values(event, "p").filter_map(|v| v.get(1).cloned()).collect();
+                (&mut reports.by_pubkey, pubkeys)
+            } else {
+                (&mut reports.by_event, targets)
+            };
+            for target in targets {
+                index.entry(target).or_default().insert(reporter.clone());
+            }
+        }
+        reports
+    }
+
+    /// Distinct trusted reporters of the post `event_id` by `author`,
+    /// counting reports of the post and of the account.
+    pub fn count(&self, event_id: &str, author: &str) -> usize {
+        let empty = HashSet::new();
+        let of_event = self.by_event.get(event_id).unwrap_or(&empty);
+        let of_author = self.by_pubkey.get(author).unwrap_or(&empty);
+        of_event.union(of_author).count()
+    }
+
+    /// `Hide` / `Downrank` once `count` reaches the policy's thresholds
+    /// (a threshold of 0 is off).
+    pub fn action(&self, event_id: &str, author: &str) -> ReportAction {
+        let count = self.count(event_id, author) as u32;
+        let reached = |threshold: u32| threshold > 0 && count >= threshold;
+        if reached(self.policy.hide_threshold) {
+            ReportAction::Hide
+        } else if reached(self.policy.downrank_threshold) {
+            ReportAction::Downrank
+        } else {
+            ReportAction::None
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_only_trusted_reports_count() {
+        let (friend, friend2, stranger, author) =
+            (Keys::generate(), Keys::generate(), Keys::generate(), Keys::generate());
+        let post = EventBuilder::text_note("buy now").sign_with_keys(&author).unwrap();
+        let report = |keys: &Keys, event: Option<EventId>| {
+            build_report(author.public_key(), event, ReportType::Spam, "")
+                .sign_with_keys(keys)
+                .unwrap()
+        };
+        let events = vec![
+            report(&friend, Some(post.id)),
+            report(&friend2, None),
+            report(&stranger, Some(post.id)),
+        ];
+        let trusted: HashSet<String> = [&friend, &friend2].iter().map(|k| k.public_key().to_hex()).collect();
+        let policy = ReportPolicy {
+            downrank_threshold: 1,
+            hide_threshold: 2,
+        };
+
+        let (id, pk) = (post.id.to_hex(), author.public_key().to_hex());
+        let reports = TrustedReports::new(&events, &trusted, policy);
+        assert_eq!(reports.count(&id, &pk), 2);
+        assert_eq!(reports.action(&id, &pk), ReportAction::Hide);
+        // The account report still applies to the author's other posts.
+        assert_eq!(reports.action("other", &pk), ReportAction::Downrank);
+
+        let reports = TrustedReports::new(&events[..1], &trusted, policy);
+        assert_eq!(reports.action(&id, &pk), ReportAction::Downrank);
+    }
+}
@@ -0,0 +1,276 @@
+//! Scheduled posts — events held locally until their target publish time.
+//!
+//! Two kinds of entries share one queue:
+//! - **Signed**: external signers (NIP-07 / Amber / NIP-46) sign when the
+//!   post is scheduled; the engine only broadcasts the stored JSON.
+//! - **Unsigned**: templates signed by the internal signer when they fall due,
+//!   so `created_at` matches the actual publish time.
+//!
+//! The queue is persisted as JSON next to the nostrdb directory and drained
+//! by the engine's background scheduler task while the engine is alive.
+
+use std::collections::HashSet;
+use std::sync::atomic::{AtomicU64, Ordering};
+
+use serde::{Deserialize, Serialize};
+
+use crate::error::{NuruNuruError, Result};
+use crate::store;
+use crate::types::{ScheduledPayload, ScheduledPost, ScheduledPostStatus};
+
+/// Published entries older than this are pruned from the queue.
+const PUBLISHED_RETENTION_SECS: u64 = 7 * 86_400;
+
+static ID_COUNTER: AtomicU64 = AtomicU64::new(0);
+
+#[derive(Debug, Default, Serialize, Deserialize)]
+struct StoreFile {
+    posts: Vec<ScheduledPost>,
+}
+
+impl ScheduledPayload {
+    /// `(kind, content, tags)` of the event this payload will publish.
+    /// Signed payloads whose JSON cannot be parsed yield kind 0 and no content.
+    pub fn template(&self) -> (u16, String, Vec<Vec<String>>) {
+        match self {
+            Self::Signed { event_json } => serde_json::from_str::<nostr::Event>(event_json)
+                .map(|e| {
+                    (
+                        e.kind.as_u16(),
+                        e.content.clone(),
+                        e.tags.iter().map(|t| t.as_slice().to_vec()).collect(),
+                    )
+                })
+                .unwrap_or_default(),
+            Self::Unsigned { kind, content, tags } => (*kind, content.clone(), tags.clone()),
+        }
+    }
+}
+
+/// Persistent queue of scheduled posts.
+///
+/// Not thread-safe on its own; the engine holds it behind a `Mutex`.
+pub struct ScheduledPostStore {
+    path: String,
+    posts: Vec<ScheduledPost>,
+    /// Posts handed out by `take_due` and not yet marked, so the queue can
+    /// be unlocked while they publish.
+    in_flight: HashSet<String>,
+}
+
+impl ScheduledPostStore {
+    /// Open (or create) the queue stored at `path`.
+    pub fn open(path: &str) -> Self {
+        let file: StoreFile = store::load_json(path);
+        Self {
+            path: path.to_string(),
+            posts: file.posts,
+            in_flight: HashSet::new(),
+        }
+    }
+
+    /// All entries, ordered by target publish time.
+    pub fn list(&self) -> Vec<ScheduledPost> {
+        let mut posts = self.posts.clone();
+        posts.sort_by_key(|p| p.publish_at);
+        posts
+    }
+
+    /// Queue a new post and persist the queue.
+    pub fn insert(&mut self, payload: ScheduledPayload, publish_at: u64, now: u64) -> Result<ScheduledPost> {
+        let post = ScheduledPost {
+            id: Self::next_id(),
+            publish_at,
+            payload,
+            status: ScheduledPostStatus::Pending,
+            published_event_id: None,
+            last_error: None,
+            attempts: 0,
+            created_at: now,
+        };
+        self.posts.push(post.clone());
+        self.persist()?;
+        Ok(post)
+    }
+
+    /// Remove a pending post. Returns an error if the ID is unknown or the
+    /// post has already been published.
+    pub fn cancel(&mut self, id: &str) -> Result<()> {
+        let idx = self.pending_index(id)?;
+        self.posts.remove(idx);
+        self.persist()
+    }
+
+    /// Move a pending (or failed) post to a new publish time and reset its
+    /// retry counter.
+    pub fn reschedule(&mut self, id: &str, publish_at: u64) -> Result<ScheduledPost> {
+        let idx = self.pending_index(id)?;
+        let post = &mut self.posts[idx];
+        post.publish_at = publish_at;
+        post.status = ScheduledPostStatus::Pending;
+        post.attempts = 0;
+        post.last_error = None;
+        let updated = post.clone();
+        self.persist()?;
+        Ok(updated)
+    }
+
+    /// Pending posts whose publish time has passed, oldest first.
+    pub fn due(&self, now: u64) -> Vec<ScheduledPost> {
+        let mut due: Vec<ScheduledPost> = self
+            .posts
+            .iter()
+            .filter(|p| {
+                p.status == ScheduledPostStatus::Pending
+                    && p.publish_at <= now
+                    && !self.in_flight.contains(&p.id)
+            })
+            .cloned()
+            .collect();
+        due.sort_by_key(|p| p.publish_at);
+        due
+    }
+
+    /// `due(now)`, marked in flight until `mark_published` /
+    /// `mark_attempt_failed`: they are not handed out again and cannot be
+    /// cancelled or rescheduled meanwhile.
+    pub fn take_due(&mut self, now: u64) -> Vec<ScheduledPost> {
+        let due = self.due(now);
+        self.in_flight.extend(due.iter().map(|p| p.id.clone()));
+        due
+    }
+
+    /// Drop every pending or failed post (posts being published finish).
+    /// Returns how many were removed.
+    pub fn cancel_all(&mut self) -> Result<usize> {
+        let before = self.posts.len();
+        let in_flight = &self.in_flight;
+        self.posts
+            .retain(|p| p.status == ScheduledPostStatus::Published || in_flight.contains(&p.id));
+        let removed = before - self.posts.len();
+        self.persist()?;
+        Ok(removed)
+    }
+
+    /// Record a successful publish.
+    pub fn mark_published(&mut self, id: &str, event_id_hex: String) -> Result<()> {
+        self.in_flight.remove(id);
+        if let Some(post) = self.posts.iter_mut().find(|p| p.id == id) {
+            post.status = ScheduledPostStatus::Published;
+            post.published_event_id = Some(event_id_hex);
+            post.last_error = None;
+        }
+        self.persist()
+    }
+
+    /// Record a failed publish attempt. The post stays pending until
+    /// `max_attempts` is reached, then moves to `Failed`.
+    pub fn mark_attempt_failed(&mut self, id: &str, error: String, max_attempts: u32) -> Result<()> {
+        self.in_flight.remove(id);
+        if let Some(post) = self.posts.iter_mut().find(|p| p.id == id) {
+            post.attempts += 1;
+            post.last_error = Some(error);
+            if post.attempts >= max_attempts {
+                post.status = ScheduledPostStatus::Failed;
+            }
+        }
+        self.persist()
+    }
+
+    fn pending_index(&self, id: &str) -> Result<usize> {
+        let idx = self
+            .posts
+            .iter()
+            .position(|p| p.id == id)
+            .ok_or_else(|| NuruNuruError::ValidationError(format!("予約投稿が見つかりません: {id}")))?;
+        if self.posts[idx].status == ScheduledPostStatus::Published {
+            return Err(NuruNuruError::ValidationError(format!("予約投稿は既に公開済みです: {id}")));
+        }
+        if self.in_flight.contains(id) {
+            return Err(NuruNuruError::ValidationError(format!("予約投稿は公開処理中です: {id}")));
+        }
+        Ok(idx)
+    }
+
+    fn persist(&mut self) -> Result<()> {
+        let now = std::time::SystemTime::now()
+            .duration_since(std::time::UNIX_EPOCH)
+            .unwrap_or_default()
+            .as_secs();
+        self.posts.retain(|p| {
+            p.status != ScheduledPostStatus::Published
+                || now.saturating_sub(p.publish_at) < PUBLISHED_RETENTION_SECS
+        });
+        store::save_json(
+            &self.path,
+            &StoreFile {
+                posts: self.posts.clone(),
+            },
+        )
+    }
+
+    fn next_id() -> String {
+        let nanos = std::time::SystemTime::now()
+            .duration_since(std::time::UNIX_EPOCH)
+            .unwrap_or_default()
+            .as_nanos();
+        let seq = ID_COUNTER.fetch_add(1, Ordering::Relaxed);
+        format!("{nanos:x}-{seq:x}")
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn temp_store(name: &str) -> ScheduledPostStore {
+        let path = std::env::temp_dir().join(format!("nurunuru_sched_{name}_{}.json", std::process::id()));
+        let _ = std::fs::remove_file(&path);
+        ScheduledPostStore::open(path.to_str().unwrap())
+    }
+
+    fn note(content: &str) -> ScheduledPayload {
+        ScheduledPayload::Unsigned {
+            kind: 1,
+            content: content.to_string(),
+            tags: vec![],
+        }
+    }
+
+    #[test]
+    fn test_due_and_reschedule() {
+        let mut store = temp_store("due");
+        let early = store.insert(note("early"), 100, 50).unwrap();
+        let late = store.insert(note("late"), 200, 50).unwrap();
+
+        let due: Vec<String> = store.due(150).into_iter().map(|p| p.id).collect();
+        assert_eq!(due, vec![early.id.clone()]);
+
+        store.reschedule(&late.id, 120).unwrap();
+        assert_eq!(store.due(150).len(), 2);
+
+        store.mark_published(&early.id, "abc".to_string()).unwrap();
+        assert!(store.cancel(&early.id).is_err());
+        assert_eq!(store.due(150).len(), 1);
+
+        // Taken posts are neither handed out again nor cancellable.
+        assert_eq!(store.take_due(150).len(), 1);
+        assert!(store.take_due(150).is_empty());
+        assert!(store.cancel(&late.id).is_err());
+        store.mark_attempt_failed(&late.id, "offline".into(), 3).unwrap();
+        assert_eq!(store.due(150).len(), 1);
+    }
+
+    #[test]
+    fn test_failed_after_max_attempts_and_persisted() {
+        let mut store = temp_store("fail");
+        let post = store.insert(note("x"), 0, 0).unwrap();
+        store.mark_attempt_failed(&post.id, "offline".into(), 2).unwrap();
+        assert_eq!(store.due(1).len(), 1);
+        store.mark_attempt_failed(&post.id, "offline".into(), 2).unwrap();
+        assert!(store.due(1).is_empty());
+
+        let reopened = ScheduledPostStore::open(&store.path);
+        assert_eq!(reopened.list()[0].status, ScheduledPostStatus::Failed);
+    }
+}
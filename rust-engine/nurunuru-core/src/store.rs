@@ -0,0 +1,34 @@
+//! Small JSON-file stores kept next to the nostrdb directory.
+//!
+//! nostrdb only holds signed Nostr events.  Engine-owned state that is not an
+//! event (scheduled posts, local bookkeeping) is persisted as a single JSON
+//! document per feature at `NuruNuruConfig::store_path(name)`.
+
+use serde::de::DeserializeOwned;
+use serde::Serialize;
+
+use crate::error::{NuruNuruError, Result};
+
+/// Load a JSON store, falling back to `T::default()` when the file is
+/// missing or unreadable (a corrupt store must never block engine start-up).
+pub fn load_json<T: DeserializeOwned + Default>(path: &str) -> T {
+    let Ok(raw) = std::fs::read_to_string(path) else {
+        return T::default();
+    };
+    match serde_json::from_str(&raw) {
+        Ok(value) => value,
+        Err(e) => {
+            tracing::warn!("[store] Ignoring unreadable store {path}: {e}");
+            T::default()
+        }
+    }
+}
+
+/// Write a JSON store atomically (temp file + rename).
+pub fn save_json<T: Serialize>(path: &str, value: &T) -> Result<()> {
+    let json = serde_json::to_string(value)?;
+    let tmp = format!("{path}.tmp");
+    std::fs::write(&tmp, json).map_err(|e| NuruNuruError::DatabaseError(e.to_string()))?;
+    std::fs::rename(&tmp, path).map_err(|e| NuruNuruError::DatabaseError(e.to_string()))?;
+    Ok(())
+}
@@ -0,0 +1,67 @@
+//! Tag construction and inspection helpers shared by the event builders.
+
+use nostr::prelude::*;
+
+/// Build a tag from its raw parts (`["e", "<id>", "<relay>", "reply"]`).
+///
+/// Standard tags go through `Tag::parse`; anything it rejects (e.g. an empty
+/// relay slot it does not accept) is kept verbatim as a custom tag so the
+/// wire format is exactly what the caller asked for.
+pub fn make_tag<S: Into<String>>(parts: impl IntoIterator<Item = S>) -> Tag {
+    let parts: Vec<String> = parts.into_iter().map(Into::into).collect();
+    match Tag::parse(parts.clone()) {
+        Ok(tag) => tag,
+        Err(_) => {
+            let (name, values) = parts.split_first().expect("tag must have a name");
+            Tag::custom(TagKind::from(name.as_str()), values.to_vec())
+        }
+    }
+}
+
+/// Raw values of every tag named `name` (each slice includes the name).
+pub fn tag_values<'a>(event: &'a Event, name: &'a str) -> impl Iterator<Item = &'a [String]> + 'a {
+    event
+        .tags
+        .iter()
+        .map(|t| t.as_slice())
+        .filter(move |v| v.first().is_some_and(|n| n == name))
+}
+
+/// First value (second element) of the first tag named `name`.
+pub fn first_tag_value<'a>(event: &'a Event, name: &'a str) -> Option<&'a str> {
+    tag_values(event, name)
+        .find_map(|v| v.get(1))
+        .map(String::as_str)
+}
+
+/// `true` if `event` carries the NIP-70 `["-"]` tag: only its author may
+/// publish it, to relays that authenticate them.
+pub fn is_protected(event: &Event) -> bool {
+    tag_values(event, "-").any(|v| v.len() == 1)
+}
+
+/// `tags` plus the NIP-70 `["-"]` tag, unless already present.
+pub fn with_protected_tag(mut tags: Vec<Tag>) -> Vec<Tag> {
+    if !tags.iter().any(|t| t.as_slice() == ["-"]) {
+        tags.push(make_tag(["-"]));
+    }
+    tags
+}
+
+/// `true` for characters allowed in a NIP-30 `:shortcode:` (Unicode
+/// letters and digits, `_` and `-`), wherever shortcodes are parsed.
+pub fn is_shortcode_char(c: char) -> bool {
+    c.is_alphanumeric() || c == '_' || c == '-'
+}
+
+/// `kind:pubkey:d` coordinate of an addressable event.
+pub fn coordinate_of(event: &Event) -> Option<String> {
+    event.kind.is_addressable().then(|| {
+        format!(
+            "{}:{}:{}",
+            event.kind.as_u16(),
+            event.pubkey,
+            first_tag_value(event, "d").unwrap_or("")
+        )
+    })
+}
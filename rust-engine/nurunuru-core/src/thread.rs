@@ -0,0 +1,295 @@
+//! NIP-10 thread reconstruction.
+//!
+//! Resolves `root` / `reply` references from marked `e` tags, falling back to
+//! the deprecated positional scheme (first = root, last = reply) for events
+//! without markers, and assembles fetched events into a `ThreadView`.
+
+use std::collections::{HashMap, HashSet};
+
+use nostr::prelude::*;
+
+use crate::content;
+use crate::tags;
+use crate::types::{ThreadNode, ThreadView};
+
+/// A referenced event from an `e` tag.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct EventRef {
+    pub id: String,
+    /// Relay hint (3rd tag element), if present.
+    pub relay: Option<String>,
+    /// Author pubkey hint (5th tag element), if present.
+    pub author: Option<String>,
+}
+
+/// NIP-10 references of a kind-1 event.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct ThreadRefs {
+    pub root: Option<EventRef>,
+    /// Direct parent. Equals `root` for top-level replies.
+    pub reply: Option<EventRef>,
+    pub mentions: Vec<EventRef>,
+}
+
+impl ThreadRefs {
+    /// Event ID of the direct parent, if this event is a reply.
+    pub fn parent_id(&self) -> Option<&str> {
+        self.reply
+            .as_ref()
+            .or(self.root.as_ref())
+            .map(|r| r.id.as_str())
+    }
+}
+
+fn non_empty(v: Option<&String>) -> Option<String> {
+    v.filter(|s| !s.is_empty()).cloned()
+}
+
+/// Parse the NIP-10 thread references of `event`.
+pub fn thread_refs(event: &Event) -> ThreadRefs {
+    let e_tags: Vec<(&[String], EventRef)> = event
+        .tags
+        .iter()
+        .map(|t| t.as_slice())
+        .filter(|v| v.len() >= 2 && v[0] == "e" && EventId::from_hex(&v[1]).is_ok())
+        .map(|v| {
+            let r = EventRef {
+                id: v[1].clone(),
+                relay: non_empty(v.get(2)),
+                author: non_empty(v.get(4)),
+            };
+            (v, r)
+        })
+        .collect();
+
+    let marked = e_tags
+        .iter()
+        .any(|(v, _)| matches!(v.get(3).map(String::as_str), Some("root" | "reply" | "mention")));
+
+    let mut refs = ThreadRefs::default();
+
+    if marked {
+        for (v, r) in e_tags {
+            match v.get(3).map(String::as_str) {
+                Some("root") => refs.root = Some(r),
+                Some("reply") => refs.reply = Some(r),
+                _ => refs.mentions.push(r),
+            }
+        }
+        // A reply marked only with `root` is a direct reply to the root.
+        if refs.reply.is_none() {
+            refs.reply = refs.root.clone();
+        }
+        // Some clients mark only `reply`; treat the parent as the root then.
+        if refs.root.is_none() {
+            refs.root = refs.reply.clone();
+        }
+    } else {
+        // Deprecated positional scheme: first = root, last = reply,
+        // everything in between is a mention.
+        let mut refs_iter: Vec<EventRef> = e_tags.into_iter().map(|(_, r)| r).collect();
+        match refs_iter.len() {
+            0 => {}
+            1 => {
+                let r = refs_iter.remove(0);
+                refs.root = Some(r.clone());
+                refs.reply = Some(r);
+            }
+            _ => {
+                let last = refs_iter.pop();
+                let first = refs_iter.remove(0);
+                refs.root = Some(first);
+                refs.reply = last;
+                refs.mentions = refs_iter;
+            }
+        }
+    }
+
+    refs
+}
+
+/// Resolve the thread root ID for `event` (the event itself if it is not a reply).
+pub fn root_id(event: &Event) -> String {
+    thread_refs(event)
+        .root
+        .map(|r| r.id)
+        .unwrap_or_else(|| event.id.to_hex())
+}
+
+/// Assemble `events` into a thread rooted at `root_id`.
+///
+/// Events whose parent was not fetched get a `missing` placeholder parent
+/// attached directly under the root, so every reply stays reachable.
+/// Events unrelated to the root are ignored.
+pub fn build_thread(root_id: &str, focus_id: &str, events: &[Event]) -> ThreadView {
+    let by_id: HashMap<String, &Event> = events.iter().map(|e| (e.id.to_hex(), e)).collect();
+
+    // parent_id → child ids
+    let mut children: HashMap<String, Vec<String>> = HashMap::new();
+    let mut placeholders: HashSet<String> = HashSet::new();
+
+    for (id, event) in &by_id {
+        if id == root_id {
+            continue;
+        }
+        let refs = thread_refs(event);
+        let in_thread = refs.root.as_ref().is_some_and(|r| r.id == root_id)
+            || refs.reply.as_ref().is_some_and(|r| by_id.contains_key(&r.id));
+        if !in_thread {
+            continue;
+        }
+        let Some(parent) = refs.parent_id().map(str::to_string) else {
+            continue;
+        };
+        if parent == *id {
+            continue;
+        }
+        if parent != root_id && !by_id.contains_key(&parent) && placeholders.insert(parent.clone()) {
+            children
+                .entry(root_id.to_string())
+                .or_default()
+                .push(parent.clone());
+        }
+        children.entry(parent).or_default().push(id.clone());
+    }
+
+    let created_at = |id: &String| by_id.get(id).map(|e| e.created_at.as_secs()).unwrap_or(0);
+    for kids in children.values_mut() {
+        kids.sort_by_key(|id| (created_at(id), id.clone()));
+        kids.dedup();
+    }
+
+    let mut nodes = Vec::new();
+    let mut visited = HashSet::new();
+    push_subtree(root_id, None, 0, &by_id, &children, &mut visited, &mut nodes);
+
+    ThreadView {
+        root_id: root_id.to_string(),
+        focus_id: focus_id.to_string(),
+        nodes,
+    }
+}
+
+/// Depth-first append of `id` and its descendants. Returns the number of
+/// descendants appended below `id`.
+fn push_subtree(
+    id: &str,
+    parent_id: Option<&str>,
+    depth: u32,
+    by_id: &HashMap<String, &Event>,
+    children: &HashMap<String, Vec<String>>,
+    visited: &mut HashSet<String>,
+    nodes: &mut Vec<ThreadNode>,
+) -> u32 {
+    if !visited.insert(id.to_string()) {
+        return 0;
+    }
+
+    let event = by_id.get(id);
+    let index = nodes.len();
+    nodes.push(ThreadNode {
+        event_id: id.to_string(),
+        parent_id: parent_id.map(str::to_string),
+        depth,
+        reply_count: 0,
+        descendant_count: 0,
+        missing: event.is_none(),
+        pubkey: event.map(|e| e.pubkey.to_hex()).unwrap_or_default(),
+        created_at: event.map(|e| e.created_at.as_secs()).unwrap_or(0),
+        event_json: event.and_then(|e| serde_json::to_string(e).ok()),
+        content_warning: event.and_then(content::content_warning),
+        is_protected: event.is_some_and(tags::is_protected),
+    });
+
+    let mut reply_count = 0;
+    let mut descendants = 0;
+    for child in children.get(id).into_iter().flatten() {
+        if visited.contains(child) {
+            continue;
+        }
+        reply_count += 1;
+        descendants += 1 + push_subtree(child, Some(id), depth + 1, by_id, children, visited, nodes);
+    }
+
+    nodes[index].reply_count = reply_count;
+    nodes[index].descendant_count = descendants;
+    descendants
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn note(keys: &Keys, content: &str, tags: Vec<Vec<String>>) -> Event {
+        let tags: Vec<Tag> = tags.into_iter().map(|t| Tag::parse(t).unwrap()).collect();
+        EventBuilder::text_note(content)
+            .tags(tags)
+            .sign_with_keys(keys)
+            .unwrap()
+    }
+
+    fn e(id: &EventId, marker: &str) -> Vec<String> {
+        vec!["e".into(), id.to_hex(), String::new(), marker.into()]
+    }
+
+    #[test]
+    fn test_marked_refs() {
+        let keys = Keys::generate();
+        let root = note(&keys, "root", vec![]);
+        let parent = note(&keys, "parent", vec![e(&root.id, "root")]);
+        let reply = note(&keys, "reply", vec![e(&root.id, "root"), e(&parent.id, "reply")]);
+
+        let refs = thread_refs(&parent);
+        assert_eq!(refs.parent_id(), Some(root.id.to_hex().as_str()));
+
+        let refs = thread_refs(&reply);
+        assert_eq!(refs.root.unwrap().id, root.id.to_hex());
+        assert_eq!(refs.reply.unwrap().id, parent.id.to_hex());
+    }
+
+    #[test]
+    fn test_positional_fallback() {
+        let keys = Keys::generate();
+        let root = note(&keys, "root", vec![]);
+        let mid = note(&keys, "mid", vec![]);
+        let parent = note(&keys, "parent", vec![]);
+        let reply = note(
+            &keys,
+            "reply",
+            vec![
+                vec!["e".into(), root.id.to_hex()],
+                vec!["e".into(), mid.id.to_hex()],
+                vec!["e".into(), parent.id.to_hex()],
+            ],
+        );
+
+        let refs = thread_refs(&reply);
+        assert_eq!(refs.root.unwrap().id, root.id.to_hex());
+        assert_eq!(refs.reply.unwrap().id, parent.id.to_hex());
+        assert_eq!(refs.mentions.len(), 1);
+    }
+
+    #[test]
+    fn test_build_thread_with_missing_parent() {
+        let keys = Keys::generate();
+        let root = note(&keys, "root", vec![]);
+        let a = note(&keys, "a", vec![e(&root.id, "root")]);
+        let lost = note(&keys, "lost", vec![e(&root.id, "root")]);
+        let b = note(&keys, "b", vec![e(&root.id, "root"), e(&lost.id, "reply")]);
+
+        let root_hex = root.id.to_hex();
+        let view = build_thread(&root_hex, &b.id.to_hex(), &[root.clone(), a, b.clone()]);
+
+        assert_eq!(view.nodes.len(), 4);
+        assert_eq!(view.nodes[0].event_id, root_hex);
+        assert_eq!(view.nodes[0].reply_count, 2);
+        assert_eq!(view.nodes[0].descendant_count, 3);
+
+        let placeholder = view.nodes.iter().find(|n| n.missing).unwrap();
+        assert_eq!(placeholder.event_id, lost.id.to_hex());
+        assert_eq!(placeholder.reply_count, 1);
+
+        let leaf = view.nodes.iter().find(|n| n.event_id == b.id.to_hex()).unwrap();
+        assert_eq!(leaf.depth, 2);
+    }
+}
@@ -72,6 +72,8 @@ pub struct EngagementHistory {
     pub liked_authors: HashMap<String, u64>,
     pub reposted_authors: HashMap<String, u64>,
     pub replied_authors: HashMap<String, u64>,
+    #[serde(default)]
+    pub bookmarked_authors: HashMap<String, u64>,
 }
 
 /// Scored post for feed ordering
@@ -81,6 +83,10 @@ pub struct ScoredPost {
     pub pubkey: String,
     pub score: f64,
     pub created_at: u64,
+    /// NIP-36 content warning reason (`""` if none given).
+    pub content_warning: Option<String>,
+    /// NIP-70 protected event (`["-"]` tag).
+    pub is_protected: bool,
 }
 
 /// Timeline fetch result
@@ -169,6 +175,968 @@ pub struct DecryptedMessage {
     pub group_id_hex: String,
 }
 
+// ─── Follow List ────────────────────────────────────────────────────────────
+
+/// Outcome of a follow / unfollow batch.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct FollowListUpdate {
+    /// Pubkeys newly followed.
+    pub added: Vec<String>,
+    /// Pubkeys no longer followed.
+    pub removed: Vec<String>,
+    /// Follow count after the update.
+    pub follow_count: u32,
+    /// ID of the published kind-3, or `None` if nothing changed.
+    pub event_id: Option<String>,
+}
+
+/// One stored version of the user's follow list (kind 3), diffed against
+/// the version before it.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct FollowListVersion {
+    pub event_id: String,
+    pub created_at: u64,
+    pub follow_count: u32,
+    pub added: Vec<String>,
+    pub removed: Vec<String>,
+    /// `true` for the newest known version.
+    pub is_current: bool,
+}
+
+// ─── Mute List (NIP-51) ─────────────────────────────────────────────────────
+
+/// Kind of a mute list entry.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum MuteKind {
+    /// `p` tag — a user.
+    Pubkey,
+    /// `word` tag — matched case-insensitively against content.
+    Word,
+    /// `t` tag — a hashtag (stored lowercase, without `#`).
+    Hashtag,
+    /// `e` tag — a thread (root or any event in it).
+    Thread,
+}
+
+impl MuteKind {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Self::Pubkey => "pubkey",
+            Self::Word => "word",
+            Self::Hashtag => "hashtag",
+            Self::Thread => "thread",
+        }
+    }
+
+    pub fn parse(s: &str) -> Option<Self> {
+        match s {
+            "pubkey" => Some(Self::Pubkey),
+            "word" => Some(Self::Word),
+            "hashtag" => Some(Self::Hashtag),
+            "thread" => Some(Self::Thread),
+            _ => None,
+        }
+    }
+}
+
+/// One section (public tags or encrypted private tags) of a mute list.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct MuteEntries {
+    pub pubkeys: Vec<String>,
+    pub words: Vec<String>,
+    pub hashtags: Vec<String>,
+    pub threads: Vec<String>,
+    /// Unrecognised tags, kept verbatim when the list is re-published.
+    #[serde(default)]
+    pub other: Vec<Vec<String>>,
+    /// The tags these entries were parsed from.  Entries left unchanged
+    /// are re-published with their original tag (case, relay hints, order).
+    #[serde(skip)]
+    pub source_tags: Vec<Vec<String>>,
+}
+
+/// A user's NIP-51 mute list (kind 10000).
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct MuteList {
+    pub public: MuteEntries,
+    /// Entries encrypted to self in `content`.  Only readable for the
+    /// logged-in user's own list.
+    pub private: MuteEntries,
+    /// `true` when `content` holds private entries that could not be
+    /// decrypted; the list is then not re-published to avoid losing them.
+    pub private_locked: bool,
+    pub event_id: Option<String>,
+    pub created_at: u64,
+}
+
+// ─── Bookmarks (NIP-51) ─────────────────────────────────────────────────────
+
+/// Kind of a bookmark entry.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum BookmarkKind {
+    /// `e` tag — a note.
+    Event,
+    /// `a` tag — an addressable event (`kind:pubkey:d`), e.g. an article.
+    Address,
+    /// `t` tag — a hashtag.
+    Hashtag,
+    /// `r` tag — a URL.
+    Url,
+}
+
+impl BookmarkKind {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Self::Event => "event",
+            Self::Address => "address",
+            Self::Hashtag => "hashtag",
+            Self::Url => "url",
+        }
+    }
+
+    pub fn parse(s: &str) -> Option<Self> {
+        match s {
+            "event" => Some(Self::Event),
+            "address" => Some(Self::Address),
+            "hashtag" => Some(Self::Hashtag),
+            "url" => Some(Self::Url),
+            _ => None,
+        }
+    }
+}
+
+/// One section (public tags or encrypted private tags) of a bookmark list.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct BookmarkEntries {
+    pub event_ids: Vec<String>,
+    pub addresses: Vec<String>,
+    pub hashtags: Vec<String>,
+    pub urls: Vec<String>,
+    /// Unrecognised tags, kept verbatim when the list is re-published.
+    #[serde(default)]
+    pub other: Vec<Vec<String>>,
+    /// The tags these entries were parsed from.  Entries left unchanged
+    /// are re-published with their original tag (relay hints, order).
+    #[serde(skip)]
+    pub source_tags: Vec<Vec<String>>,
+}
+
+/// The default bookmark list (kind 10003) or a named bookmark set
+/// (kind 30003).
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct BookmarkList {
+    /// `d` tag of a bookmark set; `None` for the default list.
+    pub identifier: Option<String>,
+    pub title: Option<String>,
+    pub description: Option<String>,
+    pub image: Option<String>,
+    pub public: BookmarkEntries,
+    /// Entries encrypted to self in `content` (own lists only).
+    pub private: BookmarkEntries,
+    /// `true` when `content` holds private entries that could not be
+    /// decrypted; the list is then not re-published.
+    pub private_locked: bool,
+    pub event_id: Option<String>,
+    pub created_at: u64,
+}
+
+// ─── Reaction Details ───────────────────────────────────────────────────────
+
+/// Reactions to an event that share the same emoji.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ReactionSummary {
+    /// Reaction content: `+`, `-`, a unicode emoji or `:shortcode:`.
+    pub content: String,
+    /// Image URL for NIP-30 custom emoji reactions.
+    pub emoji_url: Option<String>,
+    /// Distinct reactor pubkeys, most recent first.
+    pub reactors: Vec<String>,
+    pub reacted_by_me: bool,
+}
+
+/// Total zapped by one sender.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ZapperSummary {
+    pub pubkey: String,
+    pub msats: u64,
+    pub zap_count: u32,
+    /// Most recent non-empty zap comment.
+    pub comment: Option<String>,
+}
+
+/// Per-emoji reactions and zappers of an event.
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct ReactionDetails {
+    pub event_id: String,
+    /// Most reactors first.
+    pub reactions: Vec<ReactionSummary>,
+    /// Content of the logged-in user's most recent reaction, if any.
+    pub my_reaction: Option<String>,
+    /// Highest total first.
+    pub zappers: Vec<ZapperSummary>,
+    pub total_zap_msats: u64,
+}
+
+// ─── Notifications ──────────────────────────────────────────────────────────
+
+/// What an incoming event did to the logged-in user.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum NotificationKind {
+    /// A note that tags the user without replying to anything.
+    Mention,
+    Reply,
+    Reaction,
+    Repost,
+    /// A note quoting one of the user's events (`q` tag).
+    Quote,
+    Zap,
+    /// The user was added to someone's follow list.
+    Follow,
+}
+
+impl NotificationKind {
+    pub fn as_str(self) -> &'static str {
+        match self {
+            Self::Mention => "mention",
+            Self::Reply => "reply",
+            Self::Reaction => "reaction",
+            Self::Repost => "repost",
+            Self::Quote => "quote",
+            Self::Zap => "zap",
+            Self::Follow => "follow",
+        }
+    }
+}
+
+/// Notifications of one kind about one target, e.g. "5 people liked your
+/// post".  Mentions, replies and quotes are never merged (one per event).
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct NotificationGroup {
+    /// Stable key of the group (`kind:target` or `kind:event_id`).
+    pub key: String,
+    pub kind: NotificationKind,
+    /// The user's event that was reacted to / reposted / zapped / quoted /
+    /// replied to.  `None` for mentions and follows.
+    pub target_event_id: Option<String>,
+    /// Distinct actors, most recent first.
+    pub actors: Vec<String>,
+    /// Source events, most recent first.
+    pub event_ids: Vec<String>,
+    /// Content of the most recent event (note text, reaction emoji or zap
+    /// comment).
+    pub preview: Option<String>,
+    /// Sum of zap amounts in millisats (0 for other kinds).
+    pub zap_msats: u64,
+    /// Newest `created_at` in the group.
+    pub latest_at: u64,
+    /// `true` if any event in the group is unread.
+    pub unread: bool,
+}
+
+/// Result of a notifications fetch.
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct NotificationPage {
+    /// Groups, most recent first.
+    pub groups: Vec<NotificationGroup>,
+    /// Number of unread groups in `groups`.
+    pub unread_count: u32,
+    /// Oldest `created_at` seen, for paging further back with `until`.
+    pub oldest_at: Option<u64>,
+}
+
+// ─── Community Notes (NIP-32) ───────────────────────────────────────────────
+
+/// `l` label of a community note (`birdwatch` namespace).
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum BirdwatchContext {
+    Misleading,
+    #[default]
+    MissingContext,
+    FactualError,
+    Outdated,
+    Satire,
+}
+
+impl BirdwatchContext {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Self::Misleading => "misleading",
+            Self::MissingContext => "missing_context",
+            Self::FactualError => "factual_error",
+            Self::Outdated => "outdated",
+            Self::Satire => "satire",
+        }
+    }
+
+    pub fn parse(s: &str) -> Option<Self> {
+        match s {
+            "misleading" => Some(Self::Misleading),
+            "missing_context" => Some(Self::MissingContext),
+            "factual_error" => Some(Self::FactualError),
+            "outdated" => Some(Self::Outdated),
+            "satire" => Some(Self::Satire),
+            _ => None,
+        }
+    }
+}
+
+/// A rating of a community note.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum BirdwatchRating {
+    Helpful,
+    NotHelpful,
+}
+
+impl BirdwatchRating {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Self::Helpful => "helpful",
+            Self::NotHelpful => "not_helpful",
+        }
+    }
+
+    pub fn parse(s: &str) -> Option<Self> {
+        match s {
+            "helpful" => Some(Self::Helpful),
+            "not_helpful" => Some(Self::NotHelpful),
+            _ => None,
+        }
+    }
+}
+
+/// Consensus reached by a community note's ratings.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum BirdwatchStatus {
+    NeedsMoreRatings,
+    Helpful,
+    NotHelpful,
+}
+
+impl BirdwatchStatus {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Self::NeedsMoreRatings => "needs_more_ratings",
+            Self::Helpful => "helpful",
+            Self::NotHelpful => "not_helpful",
+        }
+    }
+}
+
+/// A kind-1985 community note with its ratings.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct BirdwatchNote {
+    pub event_id: String,
+    /// Event the note is attached to.
+    pub target_event_id: String,
+    pub author: String,
+    pub context: BirdwatchContext,
+    /// Note text, without the source line.
+    pub content: String,
+    pub source_url: Option<String>,
+    pub created_at: u64,
+    pub helpful_count: u32,
+    pub not_helpful_count: u32,
+    /// Bridging score (note intercept); `None` until rated.
+    pub score: Option<f64>,
+    pub status: BirdwatchStatus,
+    /// The logged-in user's rating, if any.
+    pub my_rating: Option<BirdwatchRating>,
+}
+
+/// Community notes of one event.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct BirdwatchSummary {
+    pub target_event_id: String,
+    /// Helpful notes first (highest score first), then newest first.
+    pub notes: Vec<BirdwatchNote>,
+    /// The note to display under the event, if any reached consensus.
+    pub shown_note_id: Option<String>,
+}
+
+// ─── Reports (NIP-56) ───────────────────────────────────────────────────────
+
+/// Report type of a kind-1984 report.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum ReportType {
+    Nudity,
+    Malware,
+    Profanity,
+    Illegal,
+    Spam,
+    Impersonation,
+    Other,
+}
+
+impl ReportType {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Self::Nudity => "nudity",
+            Self::Malware => "malware",
+            Self::Profanity => "profanity",
+            Self::Illegal => "illegal",
+            Self::Spam => "spam",
+            Self::Impersonation => "impersonation",
+            Self::Other => "other",
+        }
+    }
+
+    pub fn parse(s: &str) -> Option<Self> {
+        match s {
+            "nudity" => Some(Self::Nudity),
+            "malware" => Some(Self::Malware),
+            "profanity" => Some(Self::Profanity),
+            "illegal" => Some(Self::Illegal),
+            "spam" => Some(Self::Spam),
+            "impersonation" => Some(Self::Impersonation),
+            "other" => Some(Self::Other),
+            _ => None,
+        }
+    }
+}
+
+/// How many reports from followed accounts (or the user) it takes to
+/// down-rank or hide a post.  Reports of the account count for all its
+/// posts.  `0` turns a step off.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ReportPolicy {
+    /// Down-ranked in the recommended feed.
+    pub downrank_threshold: u32,
+    /// Dropped from the recommended feed and timelines.
+    pub hide_threshold: u32,
+}
+
+impl Default for ReportPolicy {
+    fn default() -> Self {
+        Self {
+            downrank_threshold: 1,
+            hide_threshold: 3,
+        }
+    }
+}
+
+// ─── Long-form Articles (NIP-23) ────────────────────────────────────────────
+
+/// A kind-30023 article or kind-30024 draft.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Article {
+    pub event_id: String,
+    pub pubkey: String,
+    /// `d` identifier; editing republishes the same one.
+    pub identifier: String,
+    pub title: String,
+    pub summary: String,
+    pub image: String,
+    /// First publication time (unix seconds), kept across edits.
+    pub published_at: Option<u64>,
+    /// Time of this version.
+    pub created_at: u64,
+    /// Markdown body.
+    pub content: String,
+    pub hashtags: Vec<String>,
+    pub draft: bool,
+    /// NIP-19 `naddr` of the article, with a relay hint when known.
+    pub naddr: String,
+    /// NIP-70 protected event (`["-"]` tag).
+    #[serde(default)]
+    pub is_protected: bool,
+}
+
+/// Fields for publishing or editing an article / draft.
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct ArticleInput {
+    /// `d` identifier of the article to replace; `None` for a new one.
+    #[serde(default)]
+    pub identifier: Option<String>,
+    #[serde(default)]
+    pub title: String,
+    #[serde(default)]
+    pub summary: String,
+    /// Header image URL.
+    #[serde(default)]
+    pub image: String,
+    #[serde(default)]
+    pub content: String,
+    #[serde(default)]
+    pub hashtags: Vec<String>,
+    /// Original publication time; when editing, `None` keeps the published
+    /// version's.
+    #[serde(default)]
+    pub published_at: Option<u64>,
+}
+
+// ─── Live Activities (NIP-53) ───────────────────────────────────────────────
+
+/// `status` of a live activity.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum LiveStatus {
+    Planned,
+    Live,
+    Ended,
+}
+
+impl LiveStatus {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Self::Planned => "planned",
+            Self::Live => "live",
+            Self::Ended => "ended",
+        }
+    }
+
+    pub fn parse(s: &str) -> Option<Self> {
+        match s {
+            "planned" => Some(Self::Planned),
+            "live" => Some(Self::Live),
+            "ended" => Some(Self::Ended),
+            _ => None,
+        }
+    }
+}
+
+/// A `p` tag of a live activity.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct LiveParticipant {
+    pub pubkey: String,
+    pub relay: Option<String>,
+    /// `Host`, `Speaker`, `Participant`, … (free-form).
+    pub role: String,
+    /// Signed proof of agreement to participate, if given.
+    pub proof: Option<String>,
+}
+
+/// A kind-30311 live activity (newest version).
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct LiveActivity {
+    pub event_id: String,
+    /// Author of the event (may be a streaming service, not the host).
+    pub pubkey: String,
+    pub identifier: String,
+    /// `30311:<pubkey>:<d>`, for chat and `a` tags.
+    pub coordinate: String,
+    pub title: String,
+    pub summary: String,
+    pub image: String,
+    pub streaming_url: Option<String>,
+    pub recording_url: Option<String>,
+    /// `Live` activities not updated for an hour are reported as `Ended`.
+    pub status: LiveStatus,
+    pub starts_at: Option<u64>,
+    pub ends_at: Option<u64>,
+    /// Viewer count reported by the host.
+    pub current_participants: Option<u64>,
+    pub total_participants: Option<u64>,
+    pub participants: Vec<LiveParticipant>,
+    pub hashtags: Vec<String>,
+    pub created_at: u64,
+}
+
+/// A kind-1311 live chat message.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct LiveChatMessage {
+    pub event_id: String,
+    /// Coordinate of the activity.
+    pub activity: String,
+    pub pubkey: String,
+    pub content: String,
+    pub created_at: u64,
+    /// `e` tag of the message replied to, if any.
+    pub reply_to: Option<String>,
+    /// NIP-70 protected event (`["-"]` tag).
+    #[serde(default)]
+    pub is_protected: bool,
+}
+
+// ─── Badges (NIP-58) ────────────────────────────────────────────────────────
+
+/// A `thumb` tag of a badge definition.
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct BadgeThumbnail {
+    pub url: String,
+    /// `WxH`, if given.
+    pub dimensions: Option<String>,
+}
+
+/// A kind-30009 badge definition (newest version).
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct BadgeDefinition {
+    pub event_id: String,
+    /// `30009:<issuer>:<d>`.
+    pub coordinate: String,
+    pub issuer: String,
+    pub identifier: String,
+    /// `name` tag, or the identifier if missing.
+    pub name: String,
+    pub description: String,
+    pub image: Option<String>,
+    pub image_dimensions: Option<String>,
+    pub thumbnails: Vec<BadgeThumbnail>,
+}
+
+/// A badge awarded to a user, with its award verified against the
+/// definition's author.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Badge {
+    pub definition: BadgeDefinition,
+    pub award_event_id: String,
+    pub awarded_at: u64,
+    /// `true` if the user shows it in their profile badges.
+    pub on_profile: bool,
+}
+
+/// Input for creating (or updating) a badge definition.
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct BadgeDefinitionInput {
+    pub identifier: String,
+    pub name: String,
+    pub description: String,
+    pub image: Option<String>,
+    pub image_dimensions: Option<String>,
+    pub thumbnails: Vec<BadgeThumbnail>,
+}
+
+// ─── Protected Events (NIP-70) ──────────────────────────────────────────────
+
+/// A relay that refused an event, with its `OK` message.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct RelayRejection {
+    pub url: String,
+    pub reason: String,
+}
+
+/// Outcome of `publish_protected_note`.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ProtectedPublishResult {
+    pub event_id: String,
+    /// Relays that stored the event.
+    pub accepted: Vec<String>,
+    /// Relays that refused it (e.g. `auth-required:` / `blocked:`).
+    pub rejected: Vec<RelayRejection>,
+    /// Requested relays not sent to: NIP-11 does not list NIP-70 and NIP-42,
+    /// or no NIP-11 document is known.
+    pub skipped: Vec<String>,
+}
+
+// ─── Request to Vanish (NIP-62) ─────────────────────────────────────────────
+
+/// What a pending request to vanish will do, shown for confirmation.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct VanishPreview {
+    /// ID the request will have once signed.
+    pub event_id: String,
+    /// `true` for an `ALL_RELAYS` request.
+    pub all_relays: bool,
+    /// Relays named in the request (empty when `all_relays`).
+    pub target_relays: Vec<String>,
+    /// Relays the request will be sent to.
+    pub publish_relays: Vec<String>,
+    pub reason: String,
+    /// The user's own events in nostrdb, purged after sending.
+    pub local_event_count: u64,
+    /// MLS groups whose local state is wiped after sending.
+    pub mls_group_count: u32,
+    /// The request for external signers (NIP-07 / NIP-46 / Amber).
+    pub unsigned_event_json: String,
+}
+
+/// Outcome of a sent request to vanish.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct VanishResult {
+    pub event_id: String,
+    /// Relays that accepted the request.
+    pub accepted: Vec<String>,
+    /// Relays that refused it or could not be reached.
+    pub rejected: Vec<RelayRejection>,
+    /// `true` if the user's events were removed from nostrdb.
+    pub local_events_purged: bool,
+    /// Queued scheduled posts dropped so none goes out after the request.
+    pub scheduled_posts_cancelled: u32,
+    /// `true` if the MLS database was reset.
+    pub mls_wiped: bool,
+}
+
+// ─── Event Backup ───────────────────────────────────────────────────────────
+
+/// Which of the user's events to export.
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct BackupOptions {
+    /// Empty = every kind.
+    #[serde(default)]
+    pub kinds: Vec<u16>,
+    #[serde(default)]
+    pub since: Option<u64>,
+    #[serde(default)]
+    pub until: Option<u64>,
+}
+
+/// Progress reported while exporting or importing.
+#[derive(Debug, Clone, Copy, Default, Serialize, Deserialize)]
+pub struct BackupProgress {
+    /// Distinct events fetched (export) or events handled (import).
+    pub processed: u64,
+    /// Events to import; 0 while exporting (unknown).
+    pub total: u64,
+    /// Pages fetched so far (export only).
+    pub batch: u32,
+}
+
+/// A backup entry that was not imported.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct BackupImportError {
+    /// 1-based line (JSONL) or array position.
+    pub line: u32,
+    pub event_id: Option<String>,
+    pub error: String,
+}
+
+/// Outcome of `import_events`.
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct BackupImportResult {
+    /// Verified events in the backup.
+    pub total: u64,
+    /// Stored locally, or accepted by at least one relay.
+    pub imported: u64,
+    pub failed: u64,
+    /// Not rebroadcast (NIP-70 protected events of other users).
+    pub skipped: u64,
+    /// Unparseable entries, bad signatures, failures and skips.
+    pub errors: Vec<BackupImportError>,
+}
+
+// ─── Relay Migration ────────────────────────────────────────────────────────
+
+/// Progress of `migrate_history_to_relay`, reported after every event.
+#[derive(Debug, Clone, Copy, Default, Serialize, Deserialize)]
+pub struct MigrationProgress {
+    /// Events handled so far, out of `total`.
+    pub processed: u64,
+    /// Events missing from the target relay.
+    pub total: u64,
+    pub published: u64,
+    pub failed: u64,
+}
+
+/// An event the target relay did not accept.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct MigrationFailure {
+    pub event_id: String,
+    pub reason: String,
+}
+
+/// Summary of `migrate_history_to_relay`.
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct MigrationReport {
+    pub relay_url: String,
+    /// The user's events found locally and on the other relays.
+    pub found: u64,
+    /// Events the target relay already had.
+    pub already_on_relay: u64,
+    /// Events migrated by an earlier, interrupted run.
+    pub resumed: u64,
+    pub published: u64,
+    pub failed: Vec<MigrationFailure>,
+}
+
+// ─── Content Warnings (NIP-36) ─────────────────────────────────────────────
+
+/// How the user wants content with a NIP-36 `content-warning` handled.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum ContentWarningPolicy {
+    /// Show warned content as is.
+    Show,
+    /// Show it behind a blur / tap-to-reveal (the default).
+    #[default]
+    Blur,
+    /// Exclude it from the recommended feed; blur it elsewhere.
+    Hide,
+}
+
+impl ContentWarningPolicy {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Self::Show => "show",
+            Self::Blur => "blur",
+            Self::Hide => "hide",
+        }
+    }
+
+    pub fn parse(s: &str) -> Option<Self> {
+        match s {
+            "show" => Some(Self::Show),
+            "blur" => Some(Self::Blur),
+            "hide" => Some(Self::Hide),
+            _ => None,
+        }
+    }
+}
+
+// ─── Scheduled Posts ────────────────────────────────────────────────────────
+
+/// What a scheduled post will publish when it falls due.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(tag = "type", rename_all = "snake_case")]
+pub enum ScheduledPayload {
+    /// Fully-signed event JSON (external signers sign at scheduling time).
+    Signed { event_json: String },
+    /// Event template signed by the internal signer at publish time.
+    Unsigned {
+        kind: u16,
+        content: String,
+        tags: Vec<Vec<String>>,
+    },
+}
+
+/// Lifecycle of a scheduled post.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum ScheduledPostStatus {
+    Pending,
+    Published,
+    Failed,
+}
+
+impl ScheduledPostStatus {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Self::Pending => "pending",
+            Self::Published => "published",
+            Self::Failed => "failed",
+        }
+    }
+}
+
+/// An event held in the local queue until `publish_at`.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ScheduledPost {
+    /// Local queue ID (not a Nostr event ID).
+    pub id: String,
+    /// Target publish time (unix seconds).
+    pub publish_at: u64,
+    pub payload: ScheduledPayload,
+    pub status: ScheduledPostStatus,
+    /// Event ID hex once published.
+    pub published_event_id: Option<String>,
+    pub last_error: Option<String>,
+    /// Number of failed publish attempts so far.
+    pub attempts: u32,
+    /// When the post was queued (unix seconds).
+    pub created_at: u64,
+}
+
+// ─── Threads (NIP-10) ───────────────────────────────────────────────────────
+
+/// One post in a reconstructed thread.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ThreadNode {
+    pub event_id: String,
+    /// `None` for the root.
+    pub parent_id: Option<String>,
+    /// 0 for the root, 1 for direct replies, …
+    pub depth: u32,
+    /// Number of direct replies.
+    pub reply_count: u32,
+    /// Number of replies at any depth below this node.
+    pub descendant_count: u32,
+    /// `true` for a placeholder whose event could not be found.
+    pub missing: bool,
+    pub pubkey: String,
+    pub created_at: u64,
+    /// Full event JSON (`None` for placeholders).
+    pub event_json: Option<String>,
+    /// NIP-36 content warning reason (`""` if none given).
+    pub content_warning: Option<String>,
+    /// NIP-70 protected event (`["-"]` tag).
+    pub is_protected: bool,
+}
+
+/// A thread flattened in depth-first order (children oldest-first), ready to
+/// render as an indented list.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ThreadView {
+    pub root_id: String,
+    /// The event the thread was requested for.
+    pub focus_id: String,
+    pub nodes: Vec<ThreadNode>,
+}
+
+// ─── Content Parsing ────────────────────────────────────────────────────────
+
+/// A typed piece of note content, in display order.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(tag = "type", rename_all = "snake_case")]
+pub enum ContentSegment {
+    Text {
+        text: String,
+    },
+    Url {
+        url: String,
+    },
+    Image {
+        url: String,
+    },
+    Video {
+        url: String,
+    },
+    /// `nostr:npub1…` / `nostr:nprofile1…`
+    Profile {
+        uri: String,
+        pubkey: String,
+        relays: Vec<String>,
+    },
+    /// `nostr:note1…` / `nostr:nevent1…`
+    Event {
+        uri: String,
+        event_id: String,
+        author: Option<String>,
+        kind: Option<u16>,
+        relays: Vec<String>,
+    },
+    /// `nostr:naddr1…`
+    Address {
+        uri: String,
+        kind: u16,
+        pubkey: String,
+        identifier: String,
+        relays: Vec<String>,
+    },
+    /// `#tag` (without the `#`).
+    Hashtag {
+        tag: String,
+    },
+    /// `:shortcode:` resolved against the event's NIP-30 `emoji` tags.
+    Emoji {
+        shortcode: String,
+        url: String,
+    },
+    /// BOLT-11 Lightning invoice (without any `lightning:` prefix).
+    Invoice {
+        invoice: String,
+    },
+}
+
+// ─── Publishing Options ─────────────────────────────────────────────────────
+
+/// Optional behaviour for `publish_note_with_options` / `build_note`.
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct NoteOptions {
+    /// Add the `t` / `p` / `q` / NIP-30 `emoji` tags implied by the content.
+    pub auto_tag: bool,
+    /// NIP-40: expire the note this many seconds after publishing.
+    #[serde(default)]
+    pub expires_in: Option<u64>,
+    /// NIP-36: mark the note as sensitive with this reason (`""` for none).
+    #[serde(default)]
+    pub content_warning: Option<String>,
+}
+
 /// Japanese-friendly timestamp display
 pub fn format_timestamp_ja(timestamp: u64) -> String {
     let now = std::time::SystemTime::now()
@@ -0,0 +1,84 @@
+//! NIP-62 requests to vanish (kind 62).
+//!
+//! A request names the relays that should delete everything by its author
+//! with `relay` tags, or asks every relay with `["relay", "ALL_RELAYS"]`.
+//! A targeted request is only sent to the relays it names; a global one
+//! goes to every relay we know of.
+
+use nostr::prelude::*;
+
+use crate::tags::{make_tag, tag_values};
+
+/// Kind of a request to vanish.
+pub const VANISH_KIND: u16 = 62;
+/// `relay` tag value of a request addressed to every relay.
+pub const ALL_RELAYS: &str = "ALL_RELAYS";
+
+/// Kind-62 request asking `relays` (every relay when empty) to delete all
+/// events by the signer.  `reason` becomes the content.
+pub fn build_request(relays: &[RelayUrl], reason: &str) -> EventBuilder {
+    let tags: Vec<Tag> = if relays.is_empty() {
+        vec![make_tag(["relay", ALL_RELAYS])]
+    } else {
+        relays
+            .iter()
+            .map(|url| make_tag(["relay".to_string(), url.to_string()]))
+            .collect()
+    };
+    EventBuilder::new(Kind::from(VANISH_KIND), reason.trim()).tags(tags)
+}
+
+/// `true` if `event` is a request addressed to every relay.
+pub fn is_global(event: &Event) -> bool {
+    tag_values(event, "relay").any(|v| v.get(1).is_some_and(|r| r == ALL_RELAYS))
+}
+
+/// Relays a targeted request names (empty for a global one).
+pub fn requested_relays(event: &Event) -> Vec<RelayUrl> {
+    if is_global(event) {
+        return Vec::new();
+    }
+    tag_values(event, "relay")
+        .filter_map(|v| RelayUrl::parse(v.get(1)?).ok())
+        .collect()
+}
+
+/// Where to send a request: the `requested` relays, or for a global
+/// request every relay in `pool` plus `fallback`, without repeats.
+pub fn publish_targets(requested: &[RelayUrl], pool: &[RelayUrl], fallback: &[RelayUrl]) -> Vec<RelayUrl> {
+    let candidates = if requested.is_empty() {
+        pool.iter().chain(fallback).collect::<Vec<_>>()
+    } else {
+        requested.iter().collect()
+    };
+    let mut targets: Vec<RelayUrl> = Vec::new();
+    for url in candidates {
+        if !targets.contains(url) {
+            targets.push(url.clone());
+        }
+    }
+    targets
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_targeted_and_global_requests() {
+        let keys = Keys::generate();
+        let a = RelayUrl::parse("wss://a.example").unwrap();
+        let b = RelayUrl::parse("wss://b.example").unwrap();
+
+        let targeted = build_request(&[a.clone()], " bye ").sign_with_keys(&keys).unwrap();
+        assert_eq!(targeted.content, "bye");
+        assert!(!is_global(&targeted));
+        assert_eq!(requested_relays(&targeted), vec![a.clone()]);
+        assert_eq!(publish_targets(&requested_relays(&targeted), &[b.clone()], &[]), vec![a.clone()]);
+
+        let global = build_request(&[], "").sign_with_keys(&keys).unwrap();
+        assert!(is_global(&global));
+        assert!(requested_relays(&global).is_empty());
+        assert_eq!(publish_targets(&[], &[a.clone(), b.clone()], &[b.clone()]), vec![a, b]);
+    }
+}
@@ -0,0 +1,155 @@
+//! NIP-57 zap receipt parsing.
+//!
+//! A receipt (kind 9735) is published by the recipient's LNURL server, so
+//! its author is not the zapper.  The sender and comment come from the
+//! embedded zap request (`description` tag), the amount from the `bolt11`
+//! invoice.  Receipts whose zap request is missing or does not verify, or
+//! whose invoice does not parse, are rejected.
+
+use nostr::prelude::*;
+
+use crate::tags::first_tag_value;
+
+/// Kind of a zap request.
+const ZAP_REQUEST_KIND: u16 = 9734;
+
+/// Characters of the bech32 data part.
+const BECH32_CHARSET: &str = "qpzry9x8gf2tvdw0s3jn54khce6mua7l";
+
+/// What a zap receipt says about the zap.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ZapReceipt {
+    /// Zap request author.
+    pub sender: String,
+    /// Amount in millisats (0 if unknown).
+    pub msats: u64,
+    /// Non-empty zap request content.
+    pub comment: Option<String>,
+}
+
+impl ZapReceipt {
+    /// `None` unless `receipt` embeds a signed kind-9734 request for the
+    /// same recipient and carries a parseable invoice whose amount agrees
+    /// with the request's `amount` tag.
+    pub fn parse(receipt: &Event) -> Option<Self> {
+        let request = Event::from_json(first_tag_value(receipt, "description")?).ok()?;
+        if request.kind.as_u16() != ZAP_REQUEST_KIND || request.verify().is_err() {
+            return None;
+        }
+        if first_tag_value(&request, "p") != first_tag_value(receipt, "p") {
+            return None;
+        }
+
+        let invoice_msats = bolt11_amount(first_tag_value(receipt, "bolt11")?)?;
+        let requested_msats = first_tag_value(&request, "amount").and_then(|a| a.parse::<u64>().ok());
+        if let (Some(invoice), Some(requested)) = (invoice_msats, requested_msats) {
+            if invoice != requested {
+                return None;
+            }
+        }
+
+        Some(Self {
+            sender: request.pubkey.to_hex(),
+            msats: invoice_msats.or(requested_msats).unwrap_or(0),
+            comment: Some(request.content).filter(|c| !c.is_empty()),
+        })
+    }
+}
+
+/// Amount of a BOLT-11 invoice in millisats, from its human-readable part.
+/// `None` for invoices without an amount or that do not parse.
+pub fn bolt11_msats(invoice: &str) -> Option<u64> {
+    bolt11_amount(invoice).flatten()
+}
+
+/// `Some(amount)` for a well-formed invoice (`Some(None)` if it has no
+/// amount), `None` if it does not parse.
+fn bolt11_amount(invoice: &str) -> Option<Option<u64>> {
+    let invoice = invoice.trim().to_lowercase();
+    // The bech32 data part never contains '1', so the last one is the separator.
+    let (hrp, data) = invoice.rsplit_once('1')?;
+    // Timestamp (7) + signature (104) + checksum (6) at the very least.
+    if data.len() < 117 || !data.chars().all(|c| BECH32_CHARSET.contains(c)) {
+        return None;
+    }
+    let amount = ["lnbcrt", "lntbs", "lnbc", "lntb"]
+        .iter()
+        .find_map(|prefix| hrp.strip_prefix(prefix))?;
+    if amount.is_empty() {
+        return Some(None);
+    }
+
+    let (digits, multiplier) = match amount.char_indices().last()? {
+        (_, c) if c.is_ascii_digit() => (amount, None),
+        (i, c) => (&amount[..i], Some(c)),
+    };
+    if digits.is_empty() || !digits.bytes().all(|b| b.is_ascii_digit()) || digits.starts_with('0') {
+        return None;
+    }
+    let value: u64 = digits.parse().ok()?;
+    // 1 BTC = 10^11 msat.
+    let msats = match multiplier {
+        None => value.checked_mul(100_000_000_000),
+        Some('m') => value.checked_mul(100_000_000),
+        Some('u') => value.checked_mul(100_000),
+        Some('n') => value.checked_mul(100),
+        // Pico-BTC amounts must be whole millisats.
+        Some('p') if value % 10 == 0 => Some(value / 10),
+        _ => None,
+    }?;
+    Some(Some(msats))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::tags::make_tag;
+
+    /// Well-formed data part: timestamp, tagged fields, signature and
+    /// checksum are not checked beyond the bech32 alphabet and length.
+    fn invoice(hrp: &str) -> String {
+        format!("{hrp}1{}", "q".repeat(120))
+    }
+
+    #[test]
+    fn test_bolt11_amount() {
+        assert_eq!(bolt11_msats(&invoice("lnbc2500u")), Some(250_000_000));
+        assert_eq!(bolt11_msats(&invoice("lnbc10n")), Some(1_000));
+        assert_eq!(bolt11_amount(&invoice("lnbc")), Some(None));
+        // Truncated, non-bech32 or non-ASCII invoices are rejected, not cut.
+        assert_eq!(bolt11_amount("lnbc2500u1pvjluezpp5qqqsyq"), None);
+        assert_eq!(bolt11_amount(&invoice("lnbc25é")), None);
+        assert_eq!(bolt11_amount(&format!("lnbc10n1{}b", "q".repeat(120))), None);
+        assert_eq!(bolt11_amount(&invoice("lnbc15p")), None);
+    }
+
+    #[test]
+    fn test_receipt_requires_verified_request() {
+        let (zapper, server, recipient) = (Keys::generate(), Keys::generate(), Keys::generate());
+        let p = recipient.public_key().to_hex();
+        let request = EventBuilder::new(Kind::from(ZAP_REQUEST_KIND), "nice")
+            .tags([make_tag(["p", p.as_str()]), make_tag(["amount", "1000"])])
+            .sign_with_keys(&zapper)
+            .unwrap();
+        let receipt = |description: String, bolt11: String| {
+            EventBuilder::new(Kind::ZapReceipt, "")
+                .tags([
+                    make_tag(["p".to_string(), p.clone()]),
+                    make_tag(["bolt11".to_string(), bolt11]),
+                    make_tag(["description".to_string(), description]),
+                ])
+                .sign_with_keys(&server)
+                .unwrap()
+        };
+
+        let zap = ZapReceipt::parse(&receipt(request.as_json(), invoice("lnbc10n"))).unwrap();
+        assert_eq!(zap.sender, zapper.public_key().to_hex());
+        assert_eq!(zap.msats, 1_000);
+        assert_eq!(zap.comment.as_deref(), Some("nice"));
+
+        let forged = request.as_json().replace("\"nice\"", "\"forged\"");
+        assert!(ZapReceipt::parse(&receipt(forged, invoice("lnbc10n"))).is_none());
+        // The invoice must match the requested amount.
+        assert!(ZapReceipt::parse(&receipt(request.as_json(), invoice("lnbc20n"))).is_none());
+    }
+}
@@ -167,6 +167,20 @@ impl NuruNuruClient {
             .map_err(|e| NuruNuruFfiError::EngineError(e.to_string()))
     }
 
+    /// Record a relay's NIP-11 information document (fetched by the app)
+    /// for capability-based relay selection, e.g. NIP-70 publishing.  The
+    /// engine never fetches NIP-11 itself, so call this for each relay
+    /// before publishing protected notes.
+    pub fn set_relay_information(
+        &self,
+        url: String,
+        nip11_json: String,
+    ) -> Result<(), NuruNuruFfiError> {
+        self.runtime
+            .block_on(self.engine.set_relay_information(&url, &nip11_json))
+            .map_err(|e| NuruNuruFfiError::EngineError(e.to_string()))
+    }
+
     // ─── Identity ──────────────────────────────────────────────────────────
 
     /// Set the current user's public key and load follow/mute lists.
@@ -224,7 +238,7 @@ impl NuruNuruClient {
             event_id,
             public_key: author,
             coordinate: None,
-            kind: Some(nostr::Kind::TextNote),
+            kind: None,
             relay_hint: None,
         };
         let unsigned = nostr::EventBuilder::reaction(target, &emoji).build(creator);
@@ -232,7 +246,28 @@ impl NuruNuruClient {
             .map_err(|e| NuruNuruFfiError::EngineError(e.to_string()))
     }
 
-    /// Create an **unsigned** repost (kind 6) JSON for external signing.
+    /// Create an **unsigned** reaction to `target_event_json` of any kind
+    /// for external signing, with its real kind, `a` tag and relay hint.
+    /// `:shortcode:` reactions take `emoji_url` or resolve it from the
+    /// user's NIP-30 emoji list.
+    pub fn create_unsigned_reaction_to_event(
+        &self,
+        target_event_json: String,
+        reaction: String,
+        emoji_url: Option<String>,
+    ) -> Result<String, NuruNuruFfiError> {
+        let target: nostr::Event = serde_json::from_str(&target_event_json)
+            .map_err(|e| NuruNuruFfiError::EngineError(format!("Invalid event JSON: {e}")))?;
+        let unsigned = self
+            .runtime
+            .block_on(self.engine.build_reaction(&target, &reaction, emoji_url.as_deref()))
+            .map_err(|e| NuruNuruFfiError::EngineError(e.to_string()))?;
+        serde_json::to_string(&unsigned)
+            .map_err(|e| NuruNuruFfiError::EngineError(e.to_string()))
+    }
+
+    /// Create an **unsigned** repost JSON for external signing: kind 6 for
+    /// text notes, NIP-18 generic repost (kind 16) for other kinds.
     pub fn create_unsigned_repost(
         &self,
         event_json: String,
@@ -242,7 +277,7 @@ impl NuruNuruClient {
             .map_err(|e| NuruNuruFfiError::EngineError(format!("Invalid event JSON: {e}")))?;
         let creator = nostr::PublicKey::from_hex(&creator_pubkey_hex)
             .map_err(|e| NuruNuruFfiError::KeyError(e.to_string()))?;
-        let unsigned = nostr::EventBuilder::repost(&event, None).build(creator);
+        let unsigned = nurunuru_core::compose::build_repost(&event, None).build(creator);
         serde_json::to_string(&unsigned)
             .map_err(|e| NuruNuruFfiError::EngineError(e.to_string()))
     }
@@ -266,6 +301,64 @@ impl NuruNuruClient {
             .map_err(|e| NuruNuruFfiError::EngineError(e.to_string()))
     }
 
+    /// Create an **unsigned** text note for external signing, with `options`
+    /// applied (e.g. `auto_tag` adds `t` / `p` / `q` / `emoji` tags implied
+    /// by `content`, resolving emoji against the creator's emoji list).
+    pub fn create_unsigned_note_with_options(
+        &self,
+        content: String,
+        tags: Vec<Vec<String>>,
+        creator_pubkey_hex: String,
+        options: FfiNoteOptions,
+    ) -> Result<String, NuruNuruFfiError> {
+        let creator = nostr::PublicKey::from_hex(&creator_pubkey_hex)
+            .map_err(|e| NuruNuruFfiError::KeyError(e.to_string()))?;
+        let parsed_tags = parse_ffi_tags(tags)?;
+        let unsigned = self.runtime.block_on(self.engine.build_note(
+            creator,
+            &content,
+            parsed_tags,
+            &options.into(),
+        ));
+        serde_json::to_string(&unsigned)
+            .map_err(|e| NuruNuruFfiError::EngineError(e.to_string()))
+    }
+
+    /// Create an **unsigned** NIP-10 reply to `parent_event_json` for external
+    /// signing.  Root/reply markers, thread participants' `p` tags and relay
+    /// hints are filled in by the engine for the logged-in user.
+    pub fn create_unsigned_reply(
+        &self,
+        parent_event_json: String,
+        content: String,
+    ) -> Result<String, NuruNuruFfiError> {
+        let parent: nostr::Event = serde_json::from_str(&parent_event_json)
+            .map_err(|e| NuruNuruFfiError::EngineError(format!("Invalid event JSON: {e}")))?;
+        let unsigned = self
+            .runtime
+            .block_on(self.engine.build_reply(&parent, &content))
+            .map_err(|e| NuruNuruFfiError::EngineError(e.to_string()))?;
+        serde_json::to_string(&unsigned)
+            .map_err(|e| NuruNuruFfiError::EngineError(e.to_string()))
+    }
+
+    /// Create an **unsigned** NIP-18 quote of `target_event_json` for external
+    /// signing (`q` tag + `nostr:nevent` reference appended to `content`).
+    pub fn create_unsigned_quote(
+        &self,
+        target_event_json: String,
+        content: String,
+    ) -> Result<String, NuruNuruFfiError> {
+        let target: nostr::Event = serde_json::from_str(&target_event_json)
+            .map_err(|e| NuruNuruFfiError::EngineError(format!("Invalid event JSON: {e}")))?;
+        let unsigned = self
+            .runtime
+            .block_on(self.engine.build_quote(&target, &content))
+            .map_err(|e| NuruNuruFfiError::EngineError(e.to_string()))?;
+        serde_json::to_string(&unsigned)
+            .map_err(|e| NuruNuruFfiError::EngineError(e.to_string()))
+    }
+
     /// Create an **unsigned** event of any kind for external signing.
     pub fn create_unsigned_event(
         &self,
@@ -407,7 +500,10 @@ impl NuruNuruClient {
 
         let events = self
             .runtime
-            .block_on(self.engine.query_local(filter))
+            .block_on(async {
+                let events = self.engine.query_local(filter).await?;
+                Ok::<_, nurunuru_core::NuruNuruError>(self.engine.filter_muted(events).await)
+            })
             .map_err(|e| NuruNuruFfiError::EngineError(e.to_string()))?;
 
         events
@@ -441,7 +537,10 @@ impl NuruNuruClient {
 
         let events = self
             .runtime
-            .block_on(self.engine.query_local(filter))
+            .block_on(async {
+                let events = self.engine.query_local(filter).await?;
+                Ok::<_, nurunuru_core::NuruNuruError>(self.engine.filter_muted(events).await)
+            })
             .map_err(|e| NuruNuruFfiError::EngineError(e.to_string()))?;
 
         events
@@ -530,6 +629,314 @@ impl NuruNuruClient {
             .map_err(|e| NuruNuruFfiError::EngineError(e.to_string()))
     }
 
+    /// Follow / unfollow several users in one kind-3 update.
+    ///
+    /// Existing petnames, relay hints and content are kept.  Fails (without
+    /// publishing) when the newest follow list cannot be confirmed.
+    pub fn update_follows(
+        &self,
+        follow_hex: Vec<String>,
+        unfollow_hex: Vec<String>,
+    ) -> Result<FfiFollowListUpdate, NuruNuruFfiError> {
+        let parse = |list: Vec<String>| -> Result<Vec<nostr::PublicKey>, NuruNuruFfiError> {
+            list.iter()
+                .map(|h| {
+                    nostr::PublicKey::from_hex(h)
+                        .map_err(|e| NuruNuruFfiError::KeyError(e.to_string()))
+                })
+                .collect()
+        };
+        let follow = parse(follow_hex)?;
+        let unfollow = parse(unfollow_hex)?;
+        let update = self
+            .runtime
+            .block_on(self.engine.update_follows(&follow, &unfollow))
+            .map_err(|e| NuruNuruFfiError::EngineError(e.to_string()))?;
+        Ok(core_follow_update_to_ffi(update))
+    }
+
+    /// Stored versions of the user's follow list, newest first, each with
+    /// the follows added / removed relative to the previous version.
+    pub fn list_follow_list_versions(
+        &self,
+    ) -> Result<Vec<FfiFollowListVersion>, NuruNuruFfiError> {
+        let versions = self
+            .runtime
+            .block_on(self.engine.list_follow_list_versions())
+            .map_err(|e| NuruNuruFfiError::EngineError(e.to_string()))?;
+        Ok(versions
+            .into_iter()
+            .map(|v| FfiFollowListVersion {
+                event_id: v.event_id,
+                created_at: v.created_at,
+                follow_count: v.follow_count,
+                added: v.added,
+                removed: v.removed,
+                is_current: v.is_current,
+            })
+            .collect())
+    }
+
+    /// Republish a stored follow-list version (e.g. "undo unfollow-all").
+    /// Internal signers only.
+    pub fn restore_follow_list(
+        &self,
+        version_event_id: String,
+    ) -> Result<FfiFollowListUpdate, NuruNuruFfiError> {
+        let update = self
+            .runtime
+            .block_on(self.engine.restore_follow_list(&version_event_id))
+            .map_err(|e| NuruNuruFfiError::EngineError(e.to_string()))?;
+        Ok(core_follow_update_to_ffi(update))
+    }
+
+    // ─── Mute List (NIP-51) ────────────────────────────────────────────────
+
+    /// Fetch a user's full mute list (pubkeys, words, hashtags, threads).
+    ///
+    /// Private entries are decrypted only for the logged-in user's own
+    /// list, which also becomes the active list applied to timelines.
+    pub fn fetch_full_mute_list(
+        &self,
+        pubkey_hex: String,
+    ) -> Result<FfiMuteList, NuruNuruFfiError> {
+        let pk = nostr::PublicKey::from_hex(&pubkey_hex)
+            .map_err(|e| NuruNuruFfiError::KeyError(e.to_string()))?;
+        let list = self
+            .runtime
+            .block_on(self.engine.fetch_full_mute_list(pk))
+            .map_err(|e| NuruNuruFfiError::EngineError(e.to_string()))?;
+        Ok(core_mute_list_to_ffi(list))
+    }
+
+    /// The active mute list as last fetched or edited (no network).
+    pub fn get_mute_list(&self) -> FfiMuteList {
+        core_mute_list_to_ffi(self.runtime.block_on(self.engine.get_mute_list()))
+    }
+
+    /// Add an entry to the user's mute list and re-publish it.
+    /// `private` entries are NIP-44 encrypted to self.  Internal signers only.
+    pub fn mute(
+        &self,
+        kind: FfiMuteKind,
+        value: String,
+        private: bool,
+    ) -> Result<FfiMuteList, NuruNuruFfiError> {
+        let list = self
+            .runtime
+            .block_on(self.engine.mute(kind.into(), &value, private))
+            .map_err(|e| NuruNuruFfiError::EngineError(e.to_string()))?;
+        Ok(core_mute_list_to_ffi(list))
+    }
+
+    /// Remove an entry (public or private) from the user's mute list and
+    /// re-publish it.  Internal signers only.
+    pub fn unmute(
+        &self,
+        kind: FfiMuteKind,
+        value: String,
+    ) -> Result<FfiMuteList, NuruNuruFfiError> {
+        let list = self
+            .runtime
+            .block_on(self.engine.unmute(kind.into(), &value))
+            .map_err(|e| NuruNuruFfiError::EngineError(e.to_string()))?;
+        Ok(core_mute_list_to_ffi(list))
+    }
+
+    // ─── Bookmarks (NIP-51) ────────────────────────────────────────────────
+
+    /// Fetch a user's default bookmark list (kind 10003).  Private entries
+    /// are decrypted only for the logged-in user's own list.
+    pub fn fetch_bookmarks(
+        &self,
+        pubkey_hex: String,
+    ) -> Result<FfiBookmarkList, NuruNuruFfiError> {
+        let pk = nostr::PublicKey::from_hex(&pubkey_hex)
+            .map_err(|e| NuruNuruFfiError::KeyError(e.to_string()))?;
+        let list = self
+            .runtime
+            .block_on(self.engine.fetch_bookmarks(pk))
+            .map_err(|e| NuruNuruFfiError::EngineError(e.to_string()))?;
+        Ok(core_bookmark_list_to_ffi(list))
+    }
+
+    /// Fetch a user's named bookmark sets (kind 30003).
+    pub fn fetch_bookmark_sets(
+        &self,
+        pubkey_hex: String,
+    ) -> Result<Vec<FfiBookmarkList>, NuruNuruFfiError> {
+        let pk = nostr::PublicKey::from_hex(&pubkey_hex)
+            .map_err(|e| NuruNuruFfiError::KeyError(e.to_string()))?;
+        let sets = self
+            .runtime
+            .block_on(self.engine.fetch_bookmark_sets(pk))
+            .map_err(|e| NuruNuruFfiError::EngineError(e.to_string()))?;
+        Ok(sets.into_iter().map(core_bookmark_list_to_ffi).collect())
+    }
+
+    /// Bookmark an entry in the default list, or in the set whose `d` tag is
+    /// `set_identifier` (created if missing).  Internal signers only.
+    pub fn add_bookmark(
+        &self,
+        kind: FfiBookmarkKind,
+        value: String,
+        private: bool,
+        set_identifier: Option<String>,
+    ) -> Result<FfiBookmarkList, NuruNuruFfiError> {
+        let list = self
+            .runtime
+            .block_on(self.engine.add_bookmark(
+                kind.into(),
+                &value,
+                private,
+                set_identifier.as_deref(),
+            ))
+            .map_err(|e| NuruNuruFfiError::EngineError(e.to_string()))?;
+        Ok(core_bookmark_list_to_ffi(list))
+    }
+
+    /// Remove an entry from the default list or the set `set_identifier`.
+    pub fn remove_bookmark(
+        &self,
+        kind: FfiBookmarkKind,
+        value: String,
+        set_identifier: Option<String>,
+    ) -> Result<FfiBookmarkList, NuruNuruFfiError> {
+        let list = self
+            .runtime
+            .block_on(self.engine.remove_bookmark(
+                kind.into(),
+                &value,
+                set_identifier.as_deref(),
+            ))
+            .map_err(|e| NuruNuruFfiError::EngineError(e.to_string()))?;
+        Ok(core_bookmark_list_to_ffi(list))
+    }
+
+    // ─── Notifications ─────────────────────────────────────────────────────
+
+    /// Grouped notifications for the logged-in user between `since` and
+    /// `until` (unix seconds), with muted users and content removed.
+    pub fn fetch_notifications(
+        &self,
+        since: Option<u64>,
+        until: Option<u64>,
+        limit: u32,
+    ) -> Result<FfiNotificationPage, NuruNuruFfiError> {
+        let page = self
+            .runtime
+            .block_on(self.engine.fetch_notifications(
+                since.map(nostr::Timestamp::from),
+                until.map(nostr::Timestamp::from),
+                limit as usize,
+            ))
+            .map_err(|e| NuruNuruFfiError::EngineError(e.to_string()))?;
+        Ok(core_notification_page_to_ffi(page))
+    }
+
+    /// Notifications since the previous call, for background polling.
+    pub fn fetch_new_notifications(
+        &self,
+        limit: u32,
+    ) -> Result<FfiNotificationPage, NuruNuruFfiError> {
+        let page = self
+            .runtime
+            .block_on(self.engine.fetch_new_notifications(limit as usize))
+            .map_err(|e| NuruNuruFfiError::EngineError(e.to_string()))?;
+        Ok(core_notification_page_to_ffi(page))
+    }
+
+    /// Mark notification events (a group's `event_ids`) as read.
+    pub fn mark_notifications_read(&self, event_ids: Vec<String>) -> Result<(), NuruNuruFfiError> {
+        self.runtime
+            .block_on(self.engine.mark_notifications_read(&event_ids))
+            .map_err(|e| NuruNuruFfiError::EngineError(e.to_string()))
+    }
+
+    /// Mark every notification up to now as read.
+    pub fn mark_all_notifications_read(&self) -> Result<(), NuruNuruFfiError> {
+        self.runtime
+            .block_on(self.engine.mark_all_notifications_read())
+            .map_err(|e| NuruNuruFfiError::EngineError(e.to_string()))
+    }
+
+    // ─── Long-form Articles (NIP-23) ───────────────────────────────────────
+
+    /// Publish a new article, or replace the one named `input.identifier`
+    /// (internal signer only).
+    pub fn publish_article(&self, input: FfiArticleInput) -> Result<FfiArticle, NuruNuruFfiError> {
+        let article = self
+            .runtime
+            .block_on(self.engine.publish_article(&input.into()))
+            .map_err(|e| NuruNuruFfiError::EngineError(e.to_string()))?;
+        Ok(core_article_to_ffi(article))
+    }
+
+    /// Save (or replace) a kind-30024 draft (internal signer only).
+    pub fn save_article_draft(&self, input: FfiArticleInput) -> Result<FfiArticle, NuruNuruFfiError> {
+        let article = self
+            .runtime
+            .block_on(self.engine.save_article_draft(&input.into()))
+            .map_err(|e| NuruNuruFfiError::EngineError(e.to_string()))?;
+        Ok(core_article_to_ffi(article))
+    }
+
+    /// Create an **unsigned** article (or draft) for external signing.
+    /// Returns the unsigned event JSON.
+    pub fn create_unsigned_article(
+        &self,
+        input: FfiArticleInput,
+        draft: bool,
+        creator_pubkey_hex: String,
+    ) -> Result<String, NuruNuruFfiError> {
+        let creator = nostr::PublicKey::from_hex(&creator_pubkey_hex)
+            .map_err(|e| NuruNuruFfiError::KeyError(e.to_string()))?;
+        let unsigned = self
+            .runtime
+            .block_on(self.engine.build_article(creator, &input.into(), draft))
+            .map_err(|e| NuruNuruFfiError::EngineError(e.to_string()))?;
+        serde_json::to_string(&unsigned)
+            .map_err(|e| NuruNuruFfiError::EngineError(e.to_string()))
+    }
+
+    /// Resolve an `naddr` to the newest version of its article.
+    pub fn fetch_article(
+        &self,
+        naddr: String,
+        cache_only: bool,
+    ) -> Result<Option<FfiArticle>, NuruNuruFfiError> {
+        let article = self
+            .runtime
+            .block_on(self.engine.fetch_article(&naddr, cache_only))
+            .map_err(|e| NuruNuruFfiError::EngineError(e.to_string()))?;
+        Ok(article.map(core_article_to_ffi))
+    }
+
+    /// A user's articles, newest first.
+    pub fn fetch_articles(
+        &self,
+        pubkey_hex: String,
+        limit: u32,
+        cache_only: bool,
+    ) -> Result<Vec<FfiArticle>, NuruNuruFfiError> {
+        let pk = nostr::PublicKey::from_hex(&pubkey_hex)
+            .map_err(|e| NuruNuruFfiError::KeyError(e.to_string()))?;
+        let articles = self
+            .runtime
+            .block_on(self.engine.fetch_articles(pk, limit as usize, cache_only))
+            .map_err(|e| NuruNuruFfiError::EngineError(e.to_string()))?;
+        Ok(articles.into_iter().map(core_article_to_ffi).collect())
+    }
+
+    /// The logged-in user's article drafts, newest first.
+    pub fn fetch_article_drafts(&self, limit: u32) -> Result<Vec<FfiArticle>, NuruNuruFfiError> {
+        let drafts = self
+            .runtime
+            .block_on(self.engine.fetch_article_drafts(limit as usize))
+            .map_err(|e| NuruNuruFfiError::EngineError(e.to_string()))?;
+        Ok(drafts.into_iter().map(core_article_to_ffi).collect())
+    }
+
     // ─── DMs (NIP-17, legacy) ──────────────────────────────────────────────
 
     /// Send an encrypted DM (NIP-17).
@@ -550,6 +957,20 @@ impl NuruNuruClient {
             .map_err(|e| NuruNuruFfiError::EngineError(e.to_string()))
     }
 
+    /// Send a NIP-17 DM that expires (NIP-40) `ttl_secs` from now.
+    pub fn send_expiring_dm(
+        &self,
+        recipient_hex: String,
+        content: String,
+        ttl_secs: u64,
+    ) -> Result<(), NuruNuruFfiError> {
+        let pk = nostr::PublicKey::from_hex(&recipient_hex)
+            .map_err(|e| NuruNuruFfiError::KeyError(e.to_string()))?;
+        self.runtime
+            .block_on(self.engine.send_expiring_dm(pk, &content, ttl_secs))
+            .map_err(|e| NuruNuruFfiError::EngineError(e.to_string()))
+    }
+
     // ─── MLS / NIP-EE ──────────────────────────────────────────────────────
 
     /// Generate a fresh MLS KeyPackage and return Kind-443 event data.
@@ -731,6 +1152,113 @@ impl NuruNuruClient {
             .map_err(|e| NuruNuruFfiError::EngineError(e.to_string()))
     }
 
+    // ─── Reactions (NIP-25) ────────────────────────────────────────────────
+
+    /// Per-emoji reaction counts with reactors, the user's own reaction and
+    /// zappers sorted by amount, for reaction chips.
+    pub fn fetch_reaction_details(
+        &self,
+        event_id_hex: String,
+    ) -> Result<FfiReactionDetails, NuruNuruFfiError> {
+        let event_id = nostr::EventId::from_hex(&event_id_hex)
+            .map_err(|e| NuruNuruFfiError::EngineError(format!("Invalid event id: {e}")))?;
+        let details = self
+            .runtime
+            .block_on(self.engine.fetch_reaction_details(event_id))
+            .map_err(|e| NuruNuruFfiError::EngineError(e.to_string()))?;
+        Ok(FfiReactionDetails {
+            event_id: details.event_id,
+            reactions: details
+                .reactions
+                .into_iter()
+                .map(|r| FfiReactionSummary {
+                    content: r.content,
+                    emoji_url: r.emoji_url,
+                    count: r.reactors.len() as u32,
+                    reactors: r.reactors,
+                    reacted_by_me: r.reacted_by_me,
+                })
+                .collect(),
+            my_reaction: details.my_reaction,
+            zappers: details
+                .zappers
+                .into_iter()
+                .map(|z| FfiZapperSummary {
+                    pubkey: z.pubkey,
+                    msats: z.msats,
+                    zap_count: z.zap_count,
+                    comment: z.comment,
+                })
+                .collect(),
+            total_zap_msats: details.total_zap_msats,
+        })
+    }
+
+    // ─── Threads (NIP-10) ──────────────────────────────────────────────────
+
+    /// Reconstruct the thread around an event for `PostDetailScreen`.
+    ///
+    /// Nodes are flattened depth-first (children oldest-first); indent by
+    /// `depth`.  Placeholders for unfetchable parents have `missing = true`.
+    /// With `cache_only = true` only nostrdb is read — call once with `true`
+    /// for an instant render, then with `false` to fill in from relays.
+    pub fn fetch_thread(
+        &self,
+        event_id_hex: String,
+        cache_only: bool,
+    ) -> Result<FfiThreadView, NuruNuruFfiError> {
+        let event_id = nostr::EventId::from_hex(&event_id_hex)
+            .map_err(|e| NuruNuruFfiError::EngineError(format!("Invalid event id: {e}")))?;
+        let view = self
+            .runtime
+            .block_on(self.engine.fetch_thread(event_id, cache_only))
+            .map_err(|e| NuruNuruFfiError::EngineError(e.to_string()))?;
+        Ok(FfiThreadView {
+            root_id: view.root_id,
+            focus_id: view.focus_id,
+            nodes: view
+                .nodes
+                .into_iter()
+                .map(|n| FfiThreadNode {
+                    event_id: n.event_id,
+                    parent_id: n.parent_id,
+                    depth: n.depth,
+                    reply_count: n.reply_count,
+                    descendant_count: n.descendant_count,
+                    missing: n.missing,
+                    pubkey: n.pubkey,
+                    created_at: n.created_at,
+                    event_json: n.event_json,
+                    content_warning: n.content_warning,
+                    is_protected: n.is_protected,
+                })
+                .collect(),
+        })
+    }
+
+    // ─── Content Parsing ───────────────────────────────────────────────────
+
+    /// Split an event's content into render segments (text, links, media,
+    /// `nostr:` references, hashtags, custom emoji, invoices).
+    pub fn parse_content(
+        &self,
+        event_json: String,
+    ) -> Result<Vec<FfiContentSegment>, NuruNuruFfiError> {
+        let event: nostr::Event = serde_json::from_str(&event_json)
+            .map_err(|e| NuruNuruFfiError::EngineError(format!("Invalid event JSON: {e}")))?;
+        Ok(nurunuru_core::content::tokenize_event(&event)
+            .into_iter()
+            .map(core_content_segment_to_ffi)
+            .collect())
+    }
+
+    /// `true` if the event carries the NIP-70 `["-"]` (protected) tag.
+    pub fn is_protected_event(&self, event_json: String) -> Result<bool, NuruNuruFfiError> {
+        let event: nostr::Event = serde_json::from_str(&event_json)
+            .map_err(|e| NuruNuruFfiError::EngineError(format!("Invalid event JSON: {e}")))?;
+        Ok(nurunuru_core::tags::is_protected(&event))
+    }
+
     // ─── Search / Feed ─────────────────────────────────────────────────────
 
     /// Full-text search (NIP-50). Returns matching event ID hex strings.
@@ -758,6 +1286,8 @@ impl NuruNuruClient {
                 pubkey: sp.pubkey,
                 score: sp.score,
                 created_at: sp.created_at,
+                content_warning: sp.content_warning,
+                is_protected: sp.is_protected,
             })
             .collect())
     }
@@ -807,6 +1337,39 @@ impl NuruNuruClient {
             .block_on(self.engine.record_engagement(&action, &author_pubkey));
     }
 
+    /// How NIP-36 warned content should be shown.
+    pub fn content_warning_policy(&self) -> FfiContentWarningPolicy {
+        self.runtime
+            .block_on(self.engine.content_warning_policy())
+            .into()
+    }
+
+    /// Set how NIP-36 warned content is shown; `Hide` also drops it from
+    /// the recommended feed.
+    pub fn set_content_warning_policy(
+        &self,
+        policy: FfiContentWarningPolicy,
+    ) -> Result<(), NuruNuruFfiError> {
+        self.runtime
+            .block_on(self.engine.set_content_warning_policy(policy.into()))
+            .map_err(|e| NuruNuruFfiError::EngineError(e.to_string()))
+    }
+
+    /// Report thresholds for the recommended feed and timelines.
+    pub fn report_policy(&self) -> FfiReportPolicy {
+        self.runtime
+            .block_on(self.engine.report_policy())
+            .into()
+    }
+
+    /// Set how many reports from followed accounts down-rank / hide a
+    /// post (`0` turns a step off).
+    pub fn set_report_policy(&self, policy: FfiReportPolicy) -> Result<(), NuruNuruFfiError> {
+        self.runtime
+            .block_on(self.engine.set_report_policy(policy.into()))
+            .map_err(|e| NuruNuruFfiError::EngineError(e.to_string()))
+    }
+
     // ─── Publishing (write operations) ────────────────────────────────────
 
     /// Publish a text note with tags (Kind 1).
@@ -826,20 +1389,73 @@ impl NuruNuruClient {
         Ok(eid.to_hex())
     }
 
-    /// React to an event (Kind 7, NIP-25).
-    ///
-    /// `emoji` is typically `"+"` (like), `"-"` (dislike), or a custom
-    /// emoji shortcode.  Returns the reaction event ID hex.
-    pub fn react(
+    /// Publish a text note with tags (Kind 1) and `options` applied.
+    pub fn publish_note_with_options(
         &self,
-        event_id_hex: String,
-        author_pubkey_hex: String,
-        emoji: String,
+        content: String,
+        tags: Vec<Vec<String>>,
+        options: FfiNoteOptions,
     ) -> Result<String, NuruNuruFfiError> {
-        let event_id = nostr::EventId::from_hex(&event_id_hex)
-            .map_err(|e| NuruNuruFfiError::EngineError(format!("Invalid event id: {e}")))?;
-        let author = nostr::PublicKey::from_hex(&author_pubkey_hex)
-            .map_err(|e| NuruNuruFfiError::KeyError(e.to_string()))?;
+        let parsed_tags = parse_ffi_tags(tags)?;
+        let eid = self
+            .runtime
+            .block_on(
+                self.engine
+                    .publish_note_with_options(&content, parsed_tags, &options.into()),
+            )
+            .map_err(|e| NuruNuruFfiError::EngineError(e.to_string()))?;
+        Ok(eid.to_hex())
+    }
+
+    /// Reply to an event (Kind 1, NIP-10). Internal signers only.
+    ///
+    /// `parent_event_json` is the full event being replied to.
+    /// Returns the reply event ID hex.
+    pub fn publish_reply(
+        &self,
+        parent_event_json: String,
+        content: String,
+    ) -> Result<String, NuruNuruFfiError> {
+        let parent: nostr::Event = serde_json::from_str(&parent_event_json)
+            .map_err(|e| NuruNuruFfiError::EngineError(format!("Invalid event JSON: {e}")))?;
+        let eid = self
+            .runtime
+            .block_on(self.engine.publish_reply(&parent, &content))
+            .map_err(|e| NuruNuruFfiError::EngineError(e.to_string()))?;
+        Ok(eid.to_hex())
+    }
+
+    /// Quote an event (Kind 1, NIP-18). Internal signers only.
+    ///
+    /// Returns the quote event ID hex.
+    pub fn publish_quote(
+        &self,
+        target_event_json: String,
+        content: String,
+    ) -> Result<String, NuruNuruFfiError> {
+        let target: nostr::Event = serde_json::from_str(&target_event_json)
+            .map_err(|e| NuruNuruFfiError::EngineError(format!("Invalid event JSON: {e}")))?;
+        let eid = self
+            .runtime
+            .block_on(self.engine.publish_quote(&target, &content))
+            .map_err(|e| NuruNuruFfiError::EngineError(e.to_string()))?;
+        Ok(eid.to_hex())
+    }
+
+    /// React to an event (Kind 7, NIP-25).
+    ///
+    /// `emoji` is typically `"+"` (like), `"-"` (dislike), or a custom
+    /// emoji shortcode.  Returns the reaction event ID hex.
+    pub fn react(
+        &self,
+        event_id_hex: String,
+        author_pubkey_hex: String,
+        emoji: String,
+    ) -> Result<String, NuruNuruFfiError> {
+        let event_id = nostr::EventId::from_hex(&event_id_hex)
+            .map_err(|e| NuruNuruFfiError::EngineError(format!("Invalid event id: {e}")))?;
+        let author = nostr::PublicKey::from_hex(&author_pubkey_hex)
+            .map_err(|e| NuruNuruFfiError::KeyError(e.to_string()))?;
         let eid = self
             .runtime
             .block_on(self.engine.react(event_id, author, &emoji))
@@ -847,7 +1463,25 @@ impl NuruNuruClient {
         Ok(eid.to_hex())
     }
 
-    /// Repost an event (Kind 6, NIP-18).
+    /// React to an event of any kind (Kind 7, NIP-25) with its real kind,
+    /// `a` tag and relay hint.  `:shortcode:` reactions take `emoji_url` or
+    /// resolve it from the user's emoji list.  Returns the reaction event ID hex.
+    pub fn react_to_event(
+        &self,
+        target_event_json: String,
+        reaction: String,
+        emoji_url: Option<String>,
+    ) -> Result<String, NuruNuruFfiError> {
+        let target: nostr::Event = serde_json::from_str(&target_event_json)
+            .map_err(|e| NuruNuruFfiError::EngineError(format!("Invalid event JSON: {e}")))?;
+        let eid = self
+            .runtime
+            .block_on(self.engine.react_to_event(&target, &reaction, emoji_url.as_deref()))
+            .map_err(|e| NuruNuruFfiError::EngineError(e.to_string()))?;
+        Ok(eid.to_hex())
+    }
+
+    /// Repost an event (Kind 6, or Kind 16 for non-text-note targets, NIP-18).
     ///
     /// `event_json` must be the full serialised Nostr event JSON received from
     /// a relay (including `id`, `pubkey`, `sig`).
@@ -865,6 +1499,30 @@ impl NuruNuruClient {
         Ok(eid.to_hex())
     }
 
+    /// Undo the user's reaction to an event (NIP-09 deletion of their
+    /// kind 7).  Returns the deletion event ID hex.
+    pub fn unreact(&self, event_id_hex: String) -> Result<String, NuruNuruFfiError> {
+        let event_id = nostr::EventId::from_hex(&event_id_hex)
+            .map_err(|e| NuruNuruFfiError::EngineError(format!("Invalid event id: {e}")))?;
+        let eid = self
+            .runtime
+            .block_on(self.engine.unreact(event_id))
+            .map_err(|e| NuruNuruFfiError::EngineError(e.to_string()))?;
+        Ok(eid.to_hex())
+    }
+
+    /// Undo the user's repost of an event (NIP-09 deletion of their
+    /// kind 6 / 16).  Returns the deletion event ID hex.
+    pub fn unrepost(&self, event_id_hex: String) -> Result<String, NuruNuruFfiError> {
+        let event_id = nostr::EventId::from_hex(&event_id_hex)
+            .map_err(|e| NuruNuruFfiError::EngineError(format!("Invalid event id: {e}")))?;
+        let eid = self
+            .runtime
+            .block_on(self.engine.unrepost(event_id))
+            .map_err(|e| NuruNuruFfiError::EngineError(e.to_string()))?;
+        Ok(eid.to_hex())
+    }
+
     /// Delete an event (Kind 5, NIP-09).
     ///
     /// Returns the deletion event ID hex.
@@ -882,6 +1540,32 @@ impl NuruNuruClient {
         Ok(eid.to_hex())
     }
 
+    /// Delete several events and / or addressable events (`kind:pubkey:d`
+    /// coordinates) in one Kind 5 request (NIP-09).  Deleted events are
+    /// hidden from local reads immediately.  Returns the deletion event ID hex.
+    pub fn delete_events(
+        &self,
+        event_id_hexes: Vec<String>,
+        coordinates: Vec<String>,
+        reason: Option<String>,
+    ) -> Result<String, NuruNuruFfiError> {
+        let ids = event_id_hexes
+            .iter()
+            .map(|hex| nostr::EventId::from_hex(hex.as_str()))
+            .collect::<Result<Vec<_>, _>>()
+            .map_err(|e| NuruNuruFfiError::EngineError(format!("Invalid event id: {e}")))?;
+        let coordinates = coordinates
+            .iter()
+            .map(|c| nostr::nips::nip01::Coordinate::parse(c.as_str()))
+            .collect::<Result<Vec<_>, _>>()
+            .map_err(|e| NuruNuruFfiError::EngineError(format!("Invalid coordinate: {e}")))?;
+        let eid = self
+            .runtime
+            .block_on(self.engine.delete_events(&ids, &coordinates, reason.as_deref()))
+            .map_err(|e| NuruNuruFfiError::EngineError(e.to_string()))?;
+        Ok(eid.to_hex())
+    }
+
     /// Generic event publisher for kinds without a dedicated method.
     ///
     /// Covers: Kind 0 (profile), Kind 3 (contacts), Kind 10000 (mute),
@@ -910,7 +1594,7 @@ impl NuruNuruClient {
         Ok(eid.to_hex())
     }
 
-    /// Publish a text note to specific relays only (NIP-70 relay selection).
+    /// Publish a text note to specific relays only.
     ///
     /// `relay_urls` is a list of `wss://...` relay URLs. Only those relays
     /// will receive the event. Returns the signed event ID hex.
@@ -931,6 +1615,88 @@ impl NuruNuruClient {
         Ok(eid.to_hex())
     }
 
+    /// Publish a NIP-70 protected note (`["-"]` tag, internal signer only)
+    /// to the relays among `relay_urls` (empty = all) whose NIP-11 document
+    /// lists NIP-70 and NIP-42.  Reports which relays accepted, rejected or
+    /// were skipped.
+    ///
+    /// The engine does not fetch NIP-11 itself: call `set_relay_information`
+    /// for each relay first, or every relay is skipped.
+    pub fn publish_protected_note(
+        &self,
+        content: String,
+        tags: Vec<Vec<String>>,
+        relay_urls: Vec<String>,
+    ) -> Result<FfiProtectedPublishResult, NuruNuruFfiError> {
+        let parsed_tags = parse_ffi_tags(tags)?;
+        let result = self
+            .runtime
+            .block_on(
+                self.engine
+                    .publish_protected_note(&content, parsed_tags, relay_urls),
+            )
+            .map_err(|e| NuruNuruFfiError::EngineError(e.to_string()))?;
+        Ok(FfiProtectedPublishResult {
+            event_id: result.event_id,
+            accepted: result.accepted,
+            rejected: result
+                .rejected
+                .into_iter()
+                .map(|r| FfiRelayRejection {
+                    url: r.url,
+                    reason: r.reason,
+                })
+                .collect(),
+            skipped: result.skipped,
+        })
+    }
+
+    /// Create an **unsigned** protected note for external signing; publish
+    /// the signed event with `publish_signed_protected_event`.
+    pub fn create_unsigned_protected_note(
+        &self,
+        content: String,
+        tags: Vec<Vec<String>>,
+        creator_pubkey_hex: String,
+    ) -> Result<String, NuruNuruFfiError> {
+        let creator = nostr::PublicKey::from_hex(&creator_pubkey_hex)
+            .map_err(|e| NuruNuruFfiError::KeyError(e.to_string()))?;
+        let parsed_tags = parse_ffi_tags(tags)?;
+        let unsigned = self
+            .engine
+            .build_protected_note(creator, &content, parsed_tags);
+        serde_json::to_string(&unsigned)
+            .map_err(|e| NuruNuruFfiError::EngineError(e.to_string()))
+    }
+
+    /// Publish an externally signed protected note to the same relays as
+    /// `publish_protected_note` (same `set_relay_information` requirement).
+    pub fn publish_signed_protected_event(
+        &self,
+        event_json: String,
+        relay_urls: Vec<String>,
+    ) -> Result<FfiProtectedPublishResult, NuruNuruFfiError> {
+        let event: nostr::Event = serde_json::from_str(&event_json)
+            .map_err(|e| NuruNuruFfiError::EngineError(format!("Invalid event JSON: {e}")))?;
+        let result = self
+            .runtime
+            .block_on(self.engine.publish_protected_event(event, relay_urls))
+            .map_err(|e| NuruNuruFfiError::EngineError(e.to_string()))?;
+        Ok(FfiProtectedPublishResult {
+            event_id: result.event_id,
+            accepted: result.accepted,
+            rejected: result
+                .rejected
+                .into_iter()
+                .map(|r| FfiRelayRejection {
+                    url: r.url,
+                    reason: r.reason,
+                })
+                .collect(),
+            skipped: result.skipped,
+        })
+    }
+
     /// Update user profile (Kind 0, NIP-01).
     ///
     /// `metadata_json` must be a JSON object with profile fields:
@@ -941,6 +1707,96 @@ impl NuruNuruClient {
         self.publish_event(0, metadata_json, vec![])
     }
 
+    // ─── Scheduled posts ───────────────────────────────────────────────────
+
+    /// Schedule a kind-1 note to be signed and published at `publish_at`
+    /// (unix seconds).
+    ///
+    /// Internal signers only — the engine signs when the post falls due.
+    /// External signers sign first and use `schedule_signed_event`.
+    pub fn schedule_note(
+        &self,
+        content: String,
+        tags: Vec<Vec<String>>,
+        publish_at: u64,
+    ) -> Result<FfiScheduledPost, NuruNuruFfiError> {
+        let parsed_tags = parse_ffi_tags(tags)?;
+        let post = self
+            .runtime
+            .block_on(self.engine.schedule_post(
+                nostr::Kind::TextNote,
+                &content,
+                parsed_tags,
+                nostr::Timestamp::from(publish_at),
+            ))
+            .map_err(|e| NuruNuruFfiError::EngineError(e.to_string()))?;
+        Ok(core_scheduled_post_to_ffi(post))
+    }
+
+    /// Schedule an already-signed event JSON (NIP-07 / Amber / NIP-46) to be
+    /// broadcast at `publish_at` (unix seconds).
+    pub fn schedule_signed_event(
+        &self,
+        event_json: String,
+        publish_at: u64,
+    ) -> Result<FfiScheduledPost, NuruNuruFfiError> {
+        let event: nostr::Event = serde_json::from_str(&event_json)
+            .map_err(|e| NuruNuruFfiError::EngineError(format!("Invalid event JSON: {e}")))?;
+        let post = self
+            .runtime
+            .block_on(
+                self.engine
+                    .schedule_signed_event(event, nostr::Timestamp::from(publish_at)),
+            )
+            .map_err(|e| NuruNuruFfiError::EngineError(e.to_string()))?;
+        Ok(core_scheduled_post_to_ffi(post))
+    }
+
+    /// List scheduled posts (pending, failed and recently published),
+    /// ordered by publish time.
+    pub fn list_scheduled_posts(&self) -> Vec<FfiScheduledPost> {
+        self.runtime
+            .block_on(self.engine.list_scheduled_posts())
+            .into_iter()
+            .map(core_scheduled_post_to_ffi)
+            .collect()
+    }
+
+    /// Cancel a pending scheduled post.
+    pub fn cancel_scheduled_post(&self, id: String) -> Result<(), NuruNuruFfiError> {
+        self.runtime
+            .block_on(self.engine.cancel_scheduled_post(&id))
+            .map_err(|e| NuruNuruFfiError::EngineError(e.to_string()))
+    }
+
+    /// Move a pending or failed scheduled post to a new publish time.
+    pub fn reschedule_post(
+        &self,
+        id: String,
+        publish_at: u64,
+    ) -> Result<FfiScheduledPost, NuruNuruFfiError> {
+        let post = self
+            .runtime
+            .block_on(
+                self.engine
+                    .reschedule_post(&id, nostr::Timestamp::from(publish_at)),
+            )
+            .map_err(|e| NuruNuruFfiError::EngineError(e.to_string()))?;
+        Ok(core_scheduled_post_to_ffi(post))
+    }
+
+    /// Publish all due scheduled posts now. Returns how many were published.
+    ///
+    /// The engine already does this in the background while it runs; call
+    /// this from a WorkManager / BGTask job to catch up after suspension.
+    pub fn publish_due_scheduled_posts(&self) -> Result<u32, NuruNuruFfiError> {
+        let count = self
+            .runtime
+            .block_on(self.engine.publish_due_scheduled_posts())
+            .map_err(|e| NuruNuruFfiError::EngineError(e.to_string()))?;
+        Ok(count as u32)
+    }
+
     // ─── NIP-04/44 Encryption (internal signer only) ──────────────────────
 
     /// NIP-04 encrypt a message for a recipient (legacy DM, Kind 4).
@@ -1012,80 +1868,641 @@ impl NuruNuruClient {
             .map_err(|e| NuruNuruFfiError::EngineError(format!("NIP-44 decrypt: {e}")))
     }
 
-    // ─── Live Streaming ────────────────────────────────────────────────────
+    // ─── Reports (NIP-56) ──────────────────────────────────────────────────
 
-    /// Start a persistent relay subscription for live events.
-    ///
-    /// Pass an empty `authors` vec for the global feed, or a list of pubkey
-    /// hex strings for the follow timeline.
-    ///
-    /// Returns a subscription ID to pass to `poll_live_events` and
-    /// `stop_live_subscription`.  The subscription emits Kind-1 text notes
-    /// with `since = now` so only new events (posted after this call) arrive.
-    pub fn start_live_subscription(
+    /// Report an event by `author_pubkey_hex` (internal signer only).
+    /// Returns the report event ID hex.
+    pub fn report_event(
         &self,
-        authors: Vec<String>,
+        event_id_hex: String,
+        author_pubkey_hex: String,
+        report_type: FfiReportType,
+        reason: String,
     ) -> Result<String, NuruNuruFfiError> {
-        let author_pks: Vec<nostr::PublicKey> = authors
-            .iter()
-            .filter_map(|h| nostr::PublicKey::from_hex(h).ok())
-            .collect();
-
-        // since = now so we only receive events posted after subscribing.
-        let since = nostr::Timestamp::now();
-        let mut filter = nostr::Filter::new()
-            .kind(nostr::Kind::TextNote)
-            .since(since);
-        if !author_pks.is_empty() {
-            filter = filter.authors(author_pks);
-        }
-
-        self.runtime
-            .block_on(self.engine.subscribe_stream(filter))
-            .map_err(|e| NuruNuruFfiError::EngineError(e.to_string()))
+        let event = nostr::EventId::from_hex(&event_id_hex)
+            .map_err(|e| NuruNuruFfiError::EngineError(format!("Invalid event id: {e}")))?;
+        let author = nostr::PublicKey::from_hex(&author_pubkey_hex)
+            .map_err(|e| NuruNuruFfiError::KeyError(e.to_string()))?;
+        let eid = self
+            .runtime
+            .block_on(self.engine.report_event(event, author, report_type.into(), &reason))
+            .map_err(|e| NuruNuruFfiError::EngineError(e.to_string()))?;
+        Ok(eid.to_hex())
     }
 
-    /// Drain up to `max_count` buffered live events. Returns serialised JSON
-    /// strings. Returns an empty vec when no new events have arrived.
-    ///
-    /// Safe to call on a background thread; will return immediately.
-    pub fn poll_live_events(
+    /// Report an account (internal signer only).  Returns the report event
+    /// ID hex.
+    pub fn report_user(
         &self,
-        sub_id: String,
-        max_count: u32,
-    ) -> Vec<String> {
-        self.runtime
-            .block_on(self.engine.poll_subscription(&sub_id, max_count as usize))
+        pubkey_hex: String,
+        report_type: FfiReportType,
+        reason: String,
+    ) -> Result<String, NuruNuruFfiError> {
+        let pk = nostr::PublicKey::from_hex(&pubkey_hex)
+            .map_err(|e| NuruNuruFfiError::KeyError(e.to_string()))?;
+        let eid = self
+            .runtime
+            .block_on(self.engine.report_user(pk, report_type.into(), &reason))
+            .map_err(|e| NuruNuruFfiError::EngineError(e.to_string()))?;
+        Ok(eid.to_hex())
     }
 
-    /// Cancel a live subscription and release all associated resources.
-    pub fn stop_live_subscription(
+    /// Create an **unsigned** report of an account (or of one of its
+    /// events) for external signing.
+    pub fn create_unsigned_report(
         &self,
-        sub_id: String,
-    ) -> Result<(), NuruNuruFfiError> {
-        self.runtime
-            .block_on(self.engine.unsubscribe_stream(&sub_id))
+        pubkey_hex: String,
+        event_id_hex: Option<String>,
+        report_type: FfiReportType,
+        reason: String,
+        creator_pubkey_hex: String,
+    ) -> Result<String, NuruNuruFfiError> {
+        let creator = nostr::PublicKey::from_hex(&creator_pubkey_hex)
+            .map_err(|e| NuruNuruFfiError::KeyError(e.to_string()))?;
+        let pk = nostr::PublicKey::from_hex(&pubkey_hex)
+            .map_err(|e| NuruNuruFfiError::KeyError(e.to_string()))?;
+        let event = event_id_hex
+            .map(|hex| nostr::EventId::from_hex(&hex))
+            .transpose()
+            .map_err(|e| NuruNuruFfiError::EngineError(format!("Invalid event id: {e}")))?;
+        let unsigned = self
+            .engine
+            .build_report(creator, pk, event, report_type.into(), &reason);
+        serde_json::to_string(&unsigned)
             .map_err(|e| NuruNuruFfiError::EngineError(e.to_string()))
     }
 
-    // ─── Diagnostics ───────────────────────────────────────────────────────
-
-    /// Return current relay connection statistics.
-    pub fn connection_stats(&self) -> FfiConnectionStats {
-        let stats = self.runtime.block_on(self.engine.connection_stats());
-        FfiConnectionStats {
-            connected_relays: stats.connected_relays as u32,
-            total_relays: stats.total_relays as u32,
-        }
-    }
+    // ─── Community Notes (NIP-32) ──────────────────────────────────────────
 
-    /// Format a Unix timestamp as a Japanese relative string (e.g. "3分").
-    pub fn format_timestamp(&self, timestamp: u64) -> String {
-        format_timestamp_ja(timestamp)
+    /// Community notes on events, one summary per event that has any.
+    /// `shown_note_id` is the note to display under the event.
+    pub fn fetch_birdwatch_notes(
+        &self,
+        event_id_hexes: Vec<String>,
+        cache_only: bool,
+    ) -> Result<Vec<FfiBirdwatchSummary>, NuruNuruFfiError> {
+        let ids = event_id_hexes
+            .iter()
+            .map(|hex| nostr::EventId::from_hex(hex.as_str()))
+            .collect::<Result<Vec<_>, _>>()
+            .map_err(|e| NuruNuruFfiError::EngineError(format!("Invalid event id: {e}")))?;
+        let summaries = self
+            .runtime
+            .block_on(self.engine.fetch_birdwatch_notes(&ids, cache_only))
+            .map_err(|e| NuruNuruFfiError::EngineError(e.to_string()))?;
+        Ok(summaries.into_iter().map(core_birdwatch_summary_to_ffi).collect())
     }
-}
-
-// ─── Non-exported impl helpers ─────────────────────────────────────────────
+
+    /// Write a community note on an event (internal signer only).
+    pub fn create_birdwatch_note(
+        &self,
+        target_event_id_hex: String,
+        context: FfiBirdwatchContext,
+        content: String,
+        source_url: Option<String>,
+    ) -> Result<FfiBirdwatchNote, NuruNuruFfiError> {
+        let target = nostr::EventId::from_hex(&target_event_id_hex)
+            .map_err(|e| NuruNuruFfiError::EngineError(format!("Invalid event id: {e}")))?;
+        let note = self
+            .runtime
+            .block_on(self.engine.create_birdwatch_note(
+                target,
+                context.into(),
+                &content,
+                source_url.as_deref(),
+            ))
+            .map_err(|e| NuruNuruFfiError::EngineError(e.to_string()))?;
+        Ok(core_birdwatch_note_to_ffi(note))
+    }
+
+    /// Create an **unsigned** community note for external signing.
+    pub fn create_unsigned_birdwatch_note(
+        &self,
+        target_event_id_hex: String,
+        context: FfiBirdwatchContext,
+        content: String,
+        source_url: Option<String>,
+        creator_pubkey_hex: String,
+    ) -> Result<String, NuruNuruFfiError> {
+        let creator = nostr::PublicKey::from_hex(&creator_pubkey_hex)
+            .map_err(|e| NuruNuruFfiError::KeyError(e.to_string()))?;
+        let target = nostr::EventId::from_hex(&target_event_id_hex)
+            .map_err(|e| NuruNuruFfiError::EngineError(format!("Invalid event id: {e}")))?;
+        let unsigned = self
+            .runtime
+            .block_on(self.engine.build_birdwatch_note(
+                creator,
+                target,
+                context.into(),
+                &content,
+                source_url.as_deref(),
+            ))
+            .map_err(|e| NuruNuruFfiError::EngineError(e.to_string()))?;
+        serde_json::to_string(&unsigned)
+            .map_err(|e| NuruNuruFfiError::EngineError(e.to_string()))
+    }
+
+    /// Rate a community note (internal signer only).  Returns the rating
+    /// event ID hex.
+    pub fn rate_birdwatch_note(
+        &self,
+        note_event_id_hex: String,
+        rating: FfiBirdwatchRating,
+    ) -> Result<String, NuruNuruFfiError> {
+        let note = nostr::EventId::from_hex(&note_event_id_hex)
+            .map_err(|e| NuruNuruFfiError::EngineError(format!("Invalid event id: {e}")))?;
+        let eid = self
+            .runtime
+            .block_on(self.engine.rate_birdwatch_note(note, rating.into()))
+            .map_err(|e| NuruNuruFfiError::EngineError(e.to_string()))?;
+        Ok(eid.to_hex())
+    }
+
+    /// Create an **unsigned** rating for external signing.
+    pub fn create_unsigned_birdwatch_rating(
+        &self,
+        note_event_id_hex: String,
+        rating: FfiBirdwatchRating,
+        creator_pubkey_hex: String,
+    ) -> Result<String, NuruNuruFfiError> {
+        let creator = nostr::PublicKey::from_hex(&creator_pubkey_hex)
+            .map_err(|e| NuruNuruFfiError::KeyError(e.to_string()))?;
+        let note = nostr::EventId::from_hex(&note_event_id_hex)
+            .map_err(|e| NuruNuruFfiError::EngineError(format!("Invalid event id: {e}")))?;
+        let unsigned = self
+            .runtime
+            .block_on(self.engine.build_birdwatch_rating(creator, note, rating.into()))
+            .map_err(|e| NuruNuruFfiError::EngineError(e.to_string()))?;
+        serde_json::to_string(&unsigned)
+            .map_err(|e| NuruNuruFfiError::EngineError(e.to_string()))
+    }
+
+    // ─── Live Activities (NIP-53) ──────────────────────────────────────────
+
+    /// Live activities with a status in `statuses` (all if empty): live
+    /// first, then planned, then ended.
+    pub fn fetch_live_activities(
+        &self,
+        statuses: Vec<FfiLiveStatus>,
+        limit: u32,
+        cache_only: bool,
+    ) -> Result<Vec<FfiLiveActivity>, NuruNuruFfiError> {
+        let statuses: Vec<LiveStatus> = statuses.into_iter().map(Into::into).collect();
+        let activities = self
+            .runtime
+            .block_on(self.engine.fetch_live_activities(&statuses, limit as usize, cache_only))
+            .map_err(|e| NuruNuruFfiError::EngineError(e.to_string()))?;
+        Ok(activities.into_iter().map(core_live_activity_to_ffi).collect())
+    }
+
+    /// The activity at `coordinate` (`30311:<pubkey>:<d>`).
+    pub fn fetch_live_activity(
+        &self,
+        coordinate: String,
+        cache_only: bool,
+    ) -> Result<Option<FfiLiveActivity>, NuruNuruFfiError> {
+        let activity = self
+            .runtime
+            .block_on(self.engine.fetch_live_activity(&coordinate, cache_only))
+            .map_err(|e| NuruNuruFfiError::EngineError(e.to_string()))?;
+        Ok(activity.map(core_live_activity_to_ffi))
+    }
+
+    /// Recent chat of an activity, oldest first.
+    pub fn fetch_live_chat(
+        &self,
+        coordinate: String,
+        limit: u32,
+        cache_only: bool,
+    ) -> Result<Vec<FfiLiveChatMessage>, NuruNuruFfiError> {
+        let messages = self
+            .runtime
+            .block_on(self.engine.fetch_live_chat(&coordinate, limit as usize, cache_only))
+            .map_err(|e| NuruNuruFfiError::EngineError(e.to_string()))?;
+        Ok(messages.into_iter().map(core_live_chat_to_ffi).collect())
+    }
+
+    /// Subscribe to new chat messages of an activity.  Returns a
+    /// subscription ID for `poll_live_chat` / `stop_live_subscription`.
+    pub fn subscribe_live_chat(&self, coordinate: String) -> Result<String, NuruNuruFfiError> {
+        self.runtime
+            .block_on(self.engine.subscribe_live_chat(&coordinate))
+            .map_err(|e| NuruNuruFfiError::EngineError(e.to_string()))
+    }
+
+    /// Drain up to `max_count` buffered chat messages.
+    pub fn poll_live_chat(&self, sub_id: String, max_count: u32) -> Vec<FfiLiveChatMessage> {
+        self.runtime
+            .block_on(self.engine.poll_live_chat(&sub_id, max_count as usize))
+            .into_iter()
+            .map(core_live_chat_to_ffi)
+            .collect()
+    }
+
+    /// Post a chat message to an activity (internal signer only).
+    /// Returns the event ID hex.
+    pub fn send_live_chat(
+        &self,
+        coordinate: String,
+        content: String,
+    ) -> Result<String, NuruNuruFfiError> {
+        let eid = self
+            .runtime
+            .block_on(self.engine.send_live_chat(&coordinate, &content))
+            .map_err(|e| NuruNuruFfiError::EngineError(e.to_string()))?;
+        Ok(eid.to_hex())
+    }
+
+    /// Create an **unsigned** chat message for external signing.
+    pub fn create_unsigned_live_chat(
+        &self,
+        coordinate: String,
+        content: String,
+        creator_pubkey_hex: String,
+    ) -> Result<String, NuruNuruFfiError> {
+        let creator = nostr::PublicKey::from_hex(&creator_pubkey_hex)
+            .map_err(|e| NuruNuruFfiError::KeyError(e.to_string()))?;
+        let unsigned = self
+            .runtime
+            .block_on(self.engine.build_live_chat(creator, &coordinate, &content))
+            .map_err(|e| NuruNuruFfiError::EngineError(e.to_string()))?;
+        serde_json::to_string(&unsigned)
+            .map_err(|e| NuruNuruFfiError::EngineError(e.to_string()))
+    }
+
+    // ─── Badges (NIP-58) ───────────────────────────────────────────────────
+
+    /// Badges a user shows on their profile, in their order.  Entries whose
+    /// award is missing or forged are dropped.
+    pub fn fetch_profile_badges(
+        &self,
+        pubkey_hex: String,
+        cache_only: bool,
+    ) -> Result<Vec<FfiBadge>, NuruNuruFfiError> {
+        let pk = nostr::PublicKey::from_hex(&pubkey_hex)
+            .map_err(|e| NuruNuruFfiError::KeyError(e.to_string()))?;
+        let list = self
+            .runtime
+            .block_on(self.engine.fetch_profile_badges(pk, cache_only))
+            .map_err(|e| NuruNuruFfiError::EngineError(e.to_string()))?;
+        Ok(list.into_iter().map(core_badge_to_ffi).collect())
+    }
+
+    /// Every verified badge awarded to a user, newest first.  `on_profile`
+    /// marks the ones already shown.
+    pub fn fetch_received_badges(
+        &self,
+        pubkey_hex: String,
+        limit: u32,
+        cache_only: bool,
+    ) -> Result<Vec<FfiBadge>, NuruNuruFfiError> {
+        let pk = nostr::PublicKey::from_hex(&pubkey_hex)
+            .map_err(|e| NuruNuruFfiError::KeyError(e.to_string()))?;
+        let list = self
+            .runtime
+            .block_on(self.engine.fetch_received_badges(pk, limit as usize, cache_only))
+            .map_err(|e| NuruNuruFfiError::EngineError(e.to_string()))?;
+        Ok(list.into_iter().map(core_badge_to_ffi).collect())
+    }
+
+    /// Replace the profile badges with these awards, in order (internal
+    /// signer only).  Used to accept, reorder and remove badges.
+    pub fn set_profile_badges(
+        &self,
+        award_event_ids: Vec<String>,
+    ) -> Result<Vec<FfiBadge>, NuruNuruFfiError> {
+        let list = self
+            .runtime
+            .block_on(self.engine.set_profile_badges(&award_event_ids))
+            .map_err(|e| NuruNuruFfiError::EngineError(e.to_string()))?;
+        Ok(list.into_iter().map(core_badge_to_ffi).collect())
+    }
+
+    /// Add an award to the end of the profile badges (internal signer only).
+    pub fn accept_badge(&self, award_event_id: String) -> Result<Vec<FfiBadge>, NuruNuruFfiError> {
+        let list = self
+            .runtime
+            .block_on(self.engine.accept_badge(&award_event_id))
+            .map_err(|e| NuruNuruFfiError::EngineError(e.to_string()))?;
+        Ok(list.into_iter().map(core_badge_to_ffi).collect())
+    }
+
+    /// Create an **unsigned** profile badges list for external signing.
+    pub fn create_unsigned_profile_badges(
+        &self,
+        award_event_ids: Vec<String>,
+        creator_pubkey_hex: String,
+    ) -> Result<String, NuruNuruFfiError> {
+        let creator = nostr::PublicKey::from_hex(&creator_pubkey_hex)
+            .map_err(|e| NuruNuruFfiError::KeyError(e.to_string()))?;
+        let unsigned = self
+            .runtime
+            .block_on(self.engine.build_profile_badges(creator, &award_event_ids))
+            .map_err(|e| NuruNuruFfiError::EngineError(e.to_string()))?;
+        serde_json::to_string(&unsigned)
+            .map_err(|e| NuruNuruFfiError::EngineError(e.to_string()))
+    }
+
+    /// Create (or replace) a badge definition (internal signer only).
+    pub fn create_badge_definition(
+        &self,
+        input: FfiBadgeDefinitionInput,
+    ) -> Result<FfiBadgeDefinition, NuruNuruFfiError> {
+        let definition = self
+            .runtime
+            .block_on(self.engine.create_badge_definition(&input.into()))
+            .map_err(|e| NuruNuruFfiError::EngineError(e.to_string()))?;
+        Ok(core_badge_definition_to_ffi(definition))
+    }
+
+    /// Create an **unsigned** badge definition for external signing.
+    pub fn create_unsigned_badge_definition(
+        &self,
+        input: FfiBadgeDefinitionInput,
+        creator_pubkey_hex: String,
+    ) -> Result<String, NuruNuruFfiError> {
+        let creator = nostr::PublicKey::from_hex(&creator_pubkey_hex)
+            .map_err(|e| NuruNuruFfiError::KeyError(e.to_string()))?;
+        let unsigned = self
+            .runtime
+            .block_on(self.engine.build_badge_definition(creator, &input.into()))
+            .map_err(|e| NuruNuruFfiError::EngineError(e.to_string()))?;
+        serde_json::to_string(&unsigned)
+            .map_err(|e| NuruNuruFfiError::EngineError(e.to_string()))
+    }
+
+    /// Award your badge at `coordinate` (`30009:<pubkey>:<d>`) to
+    /// `recipient_hexes` (internal signer only).  Returns the event ID hex.
+    pub fn award_badge(
+        &self,
+        coordinate: String,
+        recipient_hexes: Vec<String>,
+    ) -> Result<String, NuruNuruFfiError> {
+        let recipients = recipient_hexes
+            .iter()
+            .map(|hex| nostr::PublicKey::from_hex(hex))
+            .collect::<Result<Vec<_>, _>>()
+            .map_err(|e| NuruNuruFfiError::KeyError(e.to_string()))?;
+        let eid = self
+            .runtime
+            .block_on(self.engine.award_badge(&coordinate, &recipients))
+            .map_err(|e| NuruNuruFfiError::EngineError(e.to_string()))?;
+        Ok(eid.to_hex())
+    }
+
+    /// Create an **unsigned** badge award for external signing.
+    pub fn create_unsigned_badge_award(
+        &self,
+        coordinate: String,
+        recipient_hexes: Vec<String>,
+        creator_pubkey_hex: String,
+    ) -> Result<String, NuruNuruFfiError> {
+        let creator = nostr::PublicKey::from_hex(&creator_pubkey_hex)
+            .map_err(|e| NuruNuruFfiError::KeyError(e.to_string()))?;
+        let recipients = recipient_hexes
+            .iter()
+            .map(|hex| nostr::PublicKey::from_hex(hex))
+            .collect::<Result<Vec<_>, _>>()
+            .map_err(|e| NuruNuruFfiError::KeyError(e.to_string()))?;
+        let unsigned = self
+            .runtime
+            .block_on(self.engine.build_badge_award(creator, &coordinate, &recipients))
+            .map_err(|e| NuruNuruFfiError::EngineError(e.to_string()))?;
+        serde_json::to_string(&unsigned)
+            .map_err(|e| NuruNuruFfiError::EngineError(e.to_string()))
+    }
+
+    // ─── Request to Vanish (NIP-62) ────────────────────────────────────────
+
+    /// Prepare a request asking `relay_urls` (empty = every relay) to delete
+    /// all of the user's events and return the confirmation summary.
+    /// Nothing is sent yet.
+    pub fn prepare_vanish(
+        &self,
+        relay_urls: Vec<String>,
+        reason: String,
+    ) -> Result<FfiVanishPreview, NuruNuruFfiError> {
+        let preview = self
+            .runtime
+            .block_on(self.engine.prepare_vanish(&relay_urls, &reason))
+            .map_err(|e| NuruNuruFfiError::EngineError(e.to_string()))?;
+        Ok(FfiVanishPreview {
+            event_id: preview.event_id,
+            all_relays: preview.all_relays,
+            target_relays: preview.target_relays,
+            publish_relays: preview.publish_relays,
+            reason: preview.reason,
+            local_event_count: preview.local_event_count,
+            mls_group_count: preview.mls_group_count,
+            unsigned_event_json: preview.unsigned_event_json,
+        })
+    }
+
+    /// Sign (internal signer) and send the prepared request `event_id_hex`,
+    /// then purge the user's events and MLS state locally.
+    pub fn confirm_vanish(&self, event_id_hex: String) -> Result<FfiVanishResult, NuruNuruFfiError> {
+        let event_id = nostr::EventId::from_hex(&event_id_hex)
+            .map_err(|e| NuruNuruFfiError::EngineError(format!("Invalid event id: {e}")))?;
+        let result = self
+            .runtime
+            .block_on(self.engine.confirm_vanish(&event_id))
+            .map_err(|e| NuruNuruFfiError::EngineError(e.to_string()))?;
+        Ok(core_vanish_result_to_ffi(result))
+    }
+
+    /// Send the prepared request after signing `unsigned_event_json`
+    /// externally (NIP-07 / Amber), then purge locally.
+    pub fn publish_vanish_request(&self, event_json: String) -> Result<FfiVanishResult, NuruNuruFfiError> {
+        let event: nostr::Event = serde_json::from_str(&event_json)
+            .map_err(|e| NuruNuruFfiError::EngineError(format!("Invalid event JSON: {e}")))?;
+        let result = self
+            .runtime
+            .block_on(self.engine.publish_vanish_request(event))
+            .map_err(|e| NuruNuruFfiError::EngineError(e.to_string()))?;
+        Ok(core_vanish_result_to_ffi(result))
+    }
+
+    // ─── Event Backup ──────────────────────────────────────────────────────
+
+    /// Write every event by the user to `path` as JSONL (oldest first),
+    /// paging through the local cache and each connected relay.  Kinds
+    /// empty = all; `since` / `until` 0 = unbounded.  Returns how many
+    /// events were written.
+    pub fn export_events(
+        &self,
+        path: String,
+        kinds: Vec<u16>,
+        since: u64,
+        until: u64,
+        listener: Option<Arc<dyn FfiBackupProgressListener>>,
+    ) -> Result<u64, NuruNuruFfiError> {
+        let options = BackupOptions {
+            kinds,
+            since: (since > 0).then_some(since),
+            until: (until > 0).then_some(until),
+        };
+        self.runtime
+            .block_on(self.engine.export_events(&options, &path, |p| {
+                if let Some(l) = &listener {
+                    l.on_progress(p.into());
+                }
+            }))
+            .map_err(|e| NuruNuruFfiError::EngineError(e.to_string()))
+    }
+
+    /// Import the backup at `path` (JSONL or a JSON array).  Events with a
+    /// bad signature are rejected.  `relay_urls` empty stores them in the
+    /// local cache; otherwise they are rebroadcast to those relays.
+    pub fn import_events(
+        &self,
+        path: String,
+        relay_urls: Vec<String>,
+        listener: Option<Arc<dyn FfiBackupProgressListener>>,
+    ) -> Result<FfiBackupImportResult, NuruNuruFfiError> {
+        let data = std::fs::read_to_string(&path)
+            .map_err(|e| NuruNuruFfiError::EngineError(format!("Cannot read {path}: {e}")))?;
+        let result = self
+            .runtime
+            .block_on(self.engine.import_events(&data, &relay_urls, |p| {
+                if let Some(l) = &listener {
+                    l.on_progress(p.into());
+                }
+            }))
+            .map_err(|e| NuruNuruFfiError::EngineError(e.to_string()))?;
+        Ok(FfiBackupImportResult {
+            total: result.total,
+            imported: result.imported,
+            failed: result.failed,
+            skipped: result.skipped,
+            errors: result
+                .errors
+                .into_iter()
+                .map(|e| FfiBackupImportError {
+                    line: e.line,
+                    event_id: e.event_id,
+                    error: e.error,
+                })
+                .collect(),
+        })
+    }
+
+    // ─── Relay Migration ───────────────────────────────────────────────────
+
+    /// Rebroadcast the user's history (`kinds` empty = all, `since` 0 =
+    /// everything) to `url`, skipping events it already has.  Rate limited
+    /// and resumable: call again after an interruption.
+    pub fn migrate_history_to_relay(
+        &self,
+        url: String,
+        kinds: Vec<u16>,
+        since: u64,
+        listener: Option<Arc<dyn FfiMigrationProgressListener>>,
+    ) -> Result<FfiMigrationReport, NuruNuruFfiError> {
+        let since = (since > 0).then_some(since);
+        let report = self
+            .runtime
+            .block_on(self.engine.migrate_history_to_relay(&url, &kinds, since, |p| {
+                if let Some(l) = &listener {
+                    l.on_progress(FfiMigrationProgress {
+                        processed: p.processed,
+                        total: p.total,
+                        published: p.published,
+                        failed: p.failed,
+                    });
+                }
+            }))
+            .map_err(|e| NuruNuruFfiError::EngineError(e.to_string()))?;
+        Ok(FfiMigrationReport {
+            relay_url: report.relay_url,
+            found: report.found,
+            already_on_relay: report.already_on_relay,
+            resumed: report.resumed,
+            published: report.published,
+            failed: report
+                .failed
+                .into_iter()
+                .map(|f| FfiMigrationFailure {
+                    event_id: f.event_id,
+                    reason: f.reason,
+                })
+                .collect(),
+        })
+    }
+
+    // ─── Live Streaming ────────────────────────────────────────────────────
+
+    /// Start a persistent relay subscription for live events.
+    ///
+    /// Pass an empty `authors` vec for the global feed, or a list of pubkey
+    /// hex strings for the follow timeline.
+    ///
+    /// Returns a subscription ID to pass to `poll_live_events` and
+    /// `stop_live_subscription`.  The subscription emits Kind-1 text notes
+    /// with `since = now` so only new events (posted after this call) arrive.
+    pub fn start_live_subscription(
+        &self,
+        authors: Vec<String>,
+    ) -> Result<String, NuruNuruFfiError> {
+        let author_pks: Vec<nostr::PublicKey> = authors
+            .iter()
+            .filter_map(|h| nostr::PublicKey::from_hex(h).ok())
+            .collect();
+
+        // since = now so we only receive events posted after subscribing.
+        let since = nostr::Timestamp::now();
+        let mut filter = nostr::Filter::new()
+            .kind(nostr::Kind::TextNote)
+            .since(since);
+        if !author_pks.is_empty() {
+            filter = filter.authors(author_pks);
+        }
+
+        self.runtime
+            .block_on(self.engine.subscribe_stream(filter))
+            .map_err(|e| NuruNuruFfiError::EngineError(e.to_string()))
+    }
+
+    /// Drain up to `max_count` buffered live events. Returns serialised JSON
+    /// strings. Returns an empty vec when no new events have arrived.
+    ///
+    /// Safe to call on a background thread; will return immediately.
+    pub fn poll_live_events(
+        &self,
+        sub_id: String,
+        max_count: u32,
+    ) -> Vec<String> {
+        self.runtime
+            .block_on(self.engine.poll_subscription(&sub_id, max_count as usize))
+    }
+
+    /// Cancel a live subscription and release all associated resources.
+    pub fn stop_live_subscription(
+        &self,
+        sub_id: String,
+    ) -> Result<(), NuruNuruFfiError> {
+        self.runtime
+            .block_on(self.engine.unsubscribe_stream(&sub_id))
+            .map_err(|e| NuruNuruFfiError::EngineError(e.to_string()))
+    }
+
+    // ─── Diagnostics ───────────────────────────────────────────────────────
+
+    /// Return current relay connection statistics.
+    pub fn connection_stats(&self) -> FfiConnectionStats {
+        let stats = self.runtime.block_on(self.engine.connection_stats());
+        FfiConnectionStats {
+            connected_relays: stats.connected_relays as u32,
+            total_relays: stats.total_relays as u32,
+        }
+    }
+
+    /// Format a Unix timestamp as a Japanese relative string (e.g. "3分").
+    pub fn format_timestamp(&self, timestamp: u64) -> String {
+        format_timestamp_ja(timestamp)
+    }
+}
+
+// ─── Non-exported impl helpers ─────────────────────────────────────────────
 
 /// Convert `Vec<Vec<String>>` tag lists from the FFI boundary into
 /// `Vec<nostr::Tag>`.  Invalid tag arrays are silently skipped.
@@ -1097,91 +2514,1072 @@ fn parse_ffi_tags(
         if parts.is_empty() {
             continue;
         }
-        match nostr::Tag::parse(parts) {
-            Ok(t) => out.push(t),
-            Err(e) => {
-                // Warn but don't fail the whole publish — a bad tag shouldn't
-                // prevent the event from being sent.
-                eprintln!("[nurunuru-ffi] Skipping unparseable tag: {e}");
-            }
+        match nostr::Tag::parse(parts) {
+            Ok(t) => out.push(t),
+            Err(e) => {
+                // Warn but don't fail the whole publish — a bad tag shouldn't
+                // prevent the event from being sent.
+                eprintln!("[nurunuru-ffi] Skipping unparseable tag: {e}");
+            }
+        }
+    }
+    Ok(out)
+}
+
+impl NuruNuruClient {
+    /// Shared implementation for global and follow timelines.
+    /// Not exported to UniFFI — called only from the exported pub methods above.
+    fn fetch_timeline_inner(
+        &self,
+        author_hexes: Option<Vec<String>>,
+        limit: u32,
+    ) -> Result<Vec<String>, NuruNuruFfiError> {
+        let author_pks: Option<Vec<nostr::PublicKey>> = author_hexes.map(|hexes| {
+            hexes
+                .iter()
+                .filter_map(|h| nostr::PublicKey::from_hex(h).ok())
+                .collect()
+        });
+
+        let events = self
+            .runtime
+            .block_on(self.engine.fetch_timeline(
+                author_pks.as_deref(),
+                None,
+                limit as usize,
+            ))
+            .map_err(|e| NuruNuruFfiError::EngineError(e.to_string()))?;
+
+        events
+            .iter()
+            .map(|e| {
+                serde_json::to_string(e)
+                    .map_err(|err| NuruNuruFfiError::EngineError(err.to_string()))
+            })
+            .collect()
+    }
+}
+
+// ─── Internal helpers ──────────────────────────────────────────────────────
+
+fn core_profile_to_ffi(p: nurunuru_core::types::UserProfile) -> FfiUserProfile {
+    FfiUserProfile {
+        pubkey: p.pubkey,
+        name: p.name,
+        display_name: p.display_name,
+        about: p.about,
+        picture: p.picture,
+        nip05: p.nip05,
+        lud16: p.lud16,
+    }
+}
+
+// ─── FFI-safe types ────────────────────────────────────────────────────────
+
+#[derive(uniffi::Record)]
+pub struct FfiUserProfile {
+    pub name: String,
+    pub display_name: String,
+    pub about: String,
+    pub picture: String,
+    pub nip05: String,
+    pub lud16: String,
+    pub pubkey: String,
+}
+
+#[derive(uniffi::Record)]
+pub struct FfiScoredPost {
+    pub event_id: String,
+    pub pubkey: String,
+    pub score: f64,
+    pub created_at: u64,
+    /// NIP-36 content warning reason (`""` if none given).
+    pub content_warning: Option<String>,
+    /// NIP-70 protected event (`["-"]` tag).
+    pub is_protected: bool,
+}
+
+#[derive(uniffi::Record)]
+pub struct FfiConnectionStats {
+    pub connected_relays: u32,
+    pub total_relays: u32,
+}
+
+#[derive(uniffi::Record)]
+pub struct FfiScheduledPost {
+    pub id: String,
+    /// Target publish time (unix seconds).
+    pub publish_at: u64,
+    pub kind: u32,
+    pub content: String,
+    pub tags: Vec<Vec<String>>,
+    /// `true` when the event was signed at scheduling time (external signer).
+    pub pre_signed: bool,
+    /// `"pending"`, `"published"` or `"failed"`.
+    pub status: String,
+    pub published_event_id: Option<String>,
+    pub last_error: Option<String>,
+    pub attempts: u32,
+    pub created_at: u64,
+}
+
+fn core_scheduled_post_to_ffi(post: nurunuru_core::types::ScheduledPost) -> FfiScheduledPost {
+    let (kind, content, tags) = post.payload.template();
+    FfiScheduledPost {
+        id: post.id,
+        publish_at: post.publish_at,
+        kind: kind as u32,
+        content,
+        tags,
+        pre_signed: matches!(post.payload, ScheduledPayload::Signed { .. }),
+        status: post.status.as_str().to_string(),
+        published_event_id: post.published_event_id,
+        last_error: post.last_error,
+        attempts: post.attempts,
+        created_at: post.created_at,
+    }
+}
+
+#[derive(uniffi::Record)]
+pub struct FfiThreadNode {
+    pub event_id: String,
+    pub parent_id: Option<String>,
+    pub depth: u32,
+    pub reply_count: u32,
+    pub descendant_count: u32,
+    pub missing: bool,
+    pub pubkey: String,
+    pub created_at: u64,
+    pub event_json: Option<String>,
+    pub content_warning: Option<String>,
+    pub is_protected: bool,
+}
+
+#[derive(uniffi::Record)]
+pub struct FfiThreadView {
+    pub root_id: String,
+    pub focus_id: String,
+    pub nodes: Vec<FfiThreadNode>,
+}
+
+#[derive(uniffi::Record)]
+pub struct FfiFollowListUpdate {
+    pub added: Vec<String>,
+    pub removed: Vec<String>,
+    pub follow_count: u32,
+    /// Published kind-3 ID, or `None` if the list was unchanged.
+    pub event_id: Option<String>,
+}
+
+fn core_follow_update_to_ffi(update: FollowListUpdate) -> FfiFollowListUpdate {
+    FfiFollowListUpdate {
+        added: update.added,
+        removed: update.removed,
+        follow_count: update.follow_count,
+        event_id: update.event_id,
+    }
+}
+
+#[derive(uniffi::Record)]
+pub struct FfiFollowListVersion {
+    pub event_id: String,
+    pub created_at: u64,
+    pub follow_count: u32,
+    pub added: Vec<String>,
+    pub removed: Vec<String>,
+    pub is_current: bool,
+}
+
+#[derive(uniffi::Enum)]
+pub enum FfiMuteKind {
+    Pubkey,
+    Word,
+    Hashtag,
+    Thread,
+}
+
+impl From<FfiMuteKind> for MuteKind {
+    fn from(k: FfiMuteKind) -> Self {
+        match k {
+            FfiMuteKind::Pubkey => MuteKind::Pubkey,
+            FfiMuteKind::Word => MuteKind::Word,
+            FfiMuteKind::Hashtag => MuteKind::Hashtag,
+            FfiMuteKind::Thread => MuteKind::Thread,
+        }
+    }
+}
+
+#[derive(uniffi::Record)]
+pub struct FfiMuteEntries {
+    pub pubkeys: Vec<String>,
+    pub words: Vec<String>,
+    pub hashtags: Vec<String>,
+    pub threads: Vec<String>,
+}
+
+#[derive(uniffi::Record)]
+pub struct FfiMuteList {
+    pub public_entries: FfiMuteEntries,
+    pub private_entries: FfiMuteEntries,
+    /// Private entries exist but could not be decrypted.
+    pub private_locked: bool,
+    pub event_id: Option<String>,
+    pub created_at: u64,
+}
+
+fn core_mute_list_to_ffi(list: MuteList) -> FfiMuteList {
+    let entries = |e: MuteEntries| FfiMuteEntries {
+        pubkeys: e.pubkeys,
+        words: e.words,
+        hashtags: e.hashtags,
+        threads: e.threads,
+    };
+    FfiMuteList {
+        public_entries: entries(list.public),
+        private_entries: entries(list.private),
+        private_locked: list.private_locked,
+        event_id: list.event_id,
+        created_at: list.created_at,
+    }
+}
+
+#[derive(uniffi::Enum)]
+pub enum FfiBookmarkKind {
+    Event,
+    Address,
+    Hashtag,
+    Url,
+}
+
+impl From<FfiBookmarkKind> for BookmarkKind {
+    fn from(k: FfiBookmarkKind) -> Self {
+        match k {
+            FfiBookmarkKind::Event => BookmarkKind::Event,
+            FfiBookmarkKind::Address => BookmarkKind::Address,
+            FfiBookmarkKind::Hashtag => BookmarkKind::Hashtag,
+            FfiBookmarkKind::Url => BookmarkKind::Url,
+        }
+    }
+}
+
+#[derive(uniffi::Record)]
+pub struct FfiBookmarkEntries {
+    pub event_ids: Vec<String>,
+    pub addresses: Vec<String>,
+    pub hashtags: Vec<String>,
+    pub urls: Vec<String>,
+}
+
+#[derive(uniffi::Record)]
+pub struct FfiBookmarkList {
+    /// `d` tag of a bookmark set; `None` for the default list.
+    pub identifier: Option<String>,
+    pub title: Option<String>,
+    pub description: Option<String>,
+    pub image: Option<String>,
+    pub public_entries: FfiBookmarkEntries,
+    pub private_entries: FfiBookmarkEntries,
+    pub private_locked: bool,
+    pub event_id: Option<String>,
+    pub created_at: u64,
+}
+
+fn core_bookmark_list_to_ffi(list: BookmarkList) -> FfiBookmarkList {
+    let entries = |e: BookmarkEntries| FfiBookmarkEntries {
+        event_ids: e.event_ids,
+        addresses: e.addresses,
+        hashtags: e.hashtags,
+        urls: e.urls,
+    };
+    FfiBookmarkList {
+        identifier: list.identifier,
+        title: list.title,
+        description: list.description,
+        image: list.image,
+        public_entries: entries(list.public),
+        private_entries: entries(list.private),
+        private_locked: list.private_locked,
+        event_id: list.event_id,
+        created_at: list.created_at,
+    }
+}
+
+#[derive(uniffi::Enum)]
+pub enum FfiNotificationKind {
+    Mention,
+    Reply,
+    Reaction,
+    Repost,
+    Quote,
+    Zap,
+    Follow,
+}
+
+impl From<NotificationKind> for FfiNotificationKind {
+    fn from(k: NotificationKind) -> Self {
+        match k {
+            NotificationKind::Mention => Self::Mention,
+            NotificationKind::Reply => Self::Reply,
+            NotificationKind::Reaction => Self::Reaction,
+            NotificationKind::Repost => Self::Repost,
+            NotificationKind::Quote => Self::Quote,
+            NotificationKind::Zap => Self::Zap,
+            NotificationKind::Follow => Self::Follow,
+        }
+    }
+}
+
+#[derive(uniffi::Record)]
+pub struct FfiNotificationGroup {
+    pub key: String,
+    pub kind: FfiNotificationKind,
+    pub target_event_id: Option<String>,
+    /// Distinct actors, most recent first.
+    pub actors: Vec<String>,
+    pub event_ids: Vec<String>,
+    pub preview: Option<String>,
+    pub zap_msats: u64,
+    pub latest_at: u64,
+    pub unread: bool,
+}
+
+#[derive(uniffi::Record)]
+pub struct FfiNotificationPage {
+    pub groups: Vec<FfiNotificationGroup>,
+    pub unread_count: u32,
+    /// Pass as `until` to page further back.
+    pub oldest_at: Option<u64>,
+}
+
+fn core_notification_page_to_ffi(page: NotificationPage) -> FfiNotificationPage {
+    FfiNotificationPage {
+        groups: page
+            .groups
+            .into_iter()
+            .map(|g| FfiNotificationGroup {
+                key: g.key,
+                kind: g.kind.into(),
+                target_event_id: g.target_event_id,
+                actors: g.actors,
+                event_ids: g.event_ids,
+                preview: g.preview,
+                zap_msats: g.zap_msats,
+                latest_at: g.latest_at,
+                unread: g.unread,
+            })
+            .collect(),
+        unread_count: page.unread_count,
+        oldest_at: page.oldest_at,
+    }
+}
+
+#[derive(uniffi::Record)]
+pub struct FfiReactionSummary {
+    /// `+`, `-`, a unicode emoji or `:shortcode:`.
+    pub content: String,
+    pub emoji_url: Option<String>,
+    pub count: u32,
+    /// Most recent first.
+    pub reactors: Vec<String>,
+    pub reacted_by_me: bool,
+}
+
+#[derive(uniffi::Record)]
+pub struct FfiZapperSummary {
+    pub pubkey: String,
+    pub msats: u64,
+    pub zap_count: u32,
+    pub comment: Option<String>,
+}
+
+#[derive(uniffi::Record)]
+pub struct FfiReactionDetails {
+    pub event_id: String,
+    /// Most reactors first.
+    pub reactions: Vec<FfiReactionSummary>,
+    pub my_reaction: Option<String>,
+    /// Highest total first.
+    pub zappers: Vec<FfiZapperSummary>,
+    pub total_zap_msats: u64,
+}
+
+#[derive(uniffi::Enum)]
+pub enum FfiReportType {
+    Nudity,
+    Malware,
+    Profanity,
+    Illegal,
+    Spam,
+    Impersonation,
+    Other,
+}
+
+impl From<FfiReportType> for ReportType {
+    fn from(t: FfiReportType) -> Self {
+        match t {
+            FfiReportType::Nudity => ReportType::Nudity,
+            FfiReportType::Malware => ReportType::Malware,
+            FfiReportType::Profanity => ReportType::Profanity,
+            FfiReportType::Illegal => ReportType::Illegal,
+            FfiReportType::Spam => ReportType::Spam,
+            FfiReportType::Impersonation => ReportType::Impersonation,
+            FfiReportType::Other => ReportType::Other,
+        }
+    }
+}
+
+#[derive(uniffi::Record)]
+pub struct FfiReportPolicy {
+    pub downrank_threshold: u32,
+    pub hide_threshold: u32,
+}
+
+impl From<FfiReportPolicy> for ReportPolicy {
+    fn from(p: FfiReportPolicy) -> Self {
+        ReportPolicy {
+            downrank_threshold: p.downrank_threshold,
+            hide_threshold: p.hide_threshold,
         }
     }
-    Ok(out)
 }
 
-impl NuruNuruClient {
-    /// Shared implementation for global and follow timelines.
-    /// Not exported to UniFFI — called only from the exported pub methods above.
-    fn fetch_timeline_inner(
-        &self,
-        author_hexes: Option<Vec<String>>,
-        limit: u32,
-    ) -> Result<Vec<String>, NuruNuruFfiError> {
-        let author_pks: Option<Vec<nostr::PublicKey>> = author_hexes.map(|hexes| {
-            hexes
-                .iter()
-                .filter_map(|h| nostr::PublicKey::from_hex(h).ok())
-                .collect()
-        });
+impl From<ReportPolicy> for FfiReportPolicy {
+    fn from(p: ReportPolicy) -> Self {
+        FfiReportPolicy {
+            downrank_threshold: p.downrank_threshold,
+            hide_threshold: p.hide_threshold,
+        }
+    }
+}
 
-        let events = self
-            .runtime
-            .block_on(self.engine.fetch_timeline(
-                author_pks.as_deref(),
-                None,
-                limit as usize,
-            ))
-            .map_err(|e| NuruNuruFfiError::EngineError(e.to_string()))?;
+#[derive(uniffi::Enum)]
+pub enum FfiBirdwatchContext {
+    Misleading,
+    MissingContext,
+    FactualError,
+    Outdated,
+    Satire,
+}
 
-        events
-            .iter()
-            .map(|e| {
-                serde_json::to_string(e)
-                    .map_err(|err| NuruNuruFfiError::EngineError(err.to_string()))
-            })
-            .collect()
+impl From<FfiBirdwatchContext> for BirdwatchContext {
+    fn from(c: FfiBirdwatchContext) -> Self {
+        match c {
+            FfiBirdwatchContext::Misleading => BirdwatchContext::Misleading,
+            FfiBirdwatchContext::MissingContext => BirdwatchContext::MissingContext,
+            FfiBirdwatchContext::FactualError => BirdwatchContext::FactualError,
+            FfiBirdwatchContext::Outdated => BirdwatchContext::Outdated,
+            FfiBirdwatchContext::Satire => BirdwatchContext::Satire,
+        }
     }
 }
 
-// ─── Internal helpers ──────────────────────────────────────────────────────
+impl From<BirdwatchContext> for FfiBirdwatchContext {
+    fn from(c: BirdwatchContext) -> Self {
+        match c {
+            BirdwatchContext::Misleading => FfiBirdwatchContext::Misleading,
+            BirdwatchContext::MissingContext => FfiBirdwatchContext::MissingContext,
+            BirdwatchContext::FactualError => FfiBirdwatchContext::FactualError,
+            BirdwatchContext::Outdated => FfiBirdwatchContext::Outdated,
+            BirdwatchContext::Satire => FfiBirdwatchContext::Satire,
+        }
+    }
+}
 
-fn core_profile_to_ffi(p: nurunuru_core::types::UserProfile) -> FfiUserProfile {
-    FfiUserProfile {
-        pubkey: p.pubkey,
-        name: p.name,
-        display_name: p.display_name,
-        about: p.about,
-        picture: p.picture,
-        nip05: p.nip05,
-        lud16: p.lud16,
+#[derive(uniffi::Enum)]
+pub enum FfiBirdwatchRating {
+    Helpful,
+    NotHelpful,
+}
+
+impl From<FfiBirdwatchRating> for BirdwatchRating {
+    fn from(r: FfiBirdwatchRating) -> Self {
+        match r {
+            FfiBirdwatchRating::Helpful => BirdwatchRating::Helpful,
+            FfiBirdwatchRating::NotHelpful => BirdwatchRating::NotHelpful,
+        }
     }
 }
 
-// ─── FFI-safe types ────────────────────────────────────────────────────────
+impl From<BirdwatchRating> for FfiBirdwatchRating {
+    fn from(r: BirdwatchRating) -> Self {
+        match r {
+            BirdwatchRating::Helpful => FfiBirdwatchRating::Helpful,
+            BirdwatchRating::NotHelpful => FfiBirdwatchRating::NotHelpful,
+        }
+    }
+}
+
+#[derive(uniffi::Enum)]
+pub enum FfiBirdwatchStatus {
+    NeedsMoreRatings,
+    Helpful,
+    NotHelpful,
+}
+
+impl From<BirdwatchStatus> for FfiBirdwatchStatus {
+    fn from(s: BirdwatchStatus) -> Self {
+        match s {
+            BirdwatchStatus::NeedsMoreRatings => FfiBirdwatchStatus::NeedsMoreRatings,
+            BirdwatchStatus::Helpful => FfiBirdwatchStatus::Helpful,
+            BirdwatchStatus::NotHelpful => FfiBirdwatchStatus::NotHelpful,
+        }
+    }
+}
 
 #[derive(uniffi::Record)]
-pub struct FfiUserProfile {
-    pub name: String,
-    pub display_name: String,
-    pub about: String,
-    pub picture: String,
-    pub nip05: String,
-    pub lud16: String,
+pub struct FfiBirdwatchNote {
+    pub event_id: String,
+    pub target_event_id: String,
+    pub author: String,
+    pub context: FfiBirdwatchContext,
+    pub content: String,
+    pub source_url: Option<String>,
+    pub created_at: u64,
+    pub helpful_count: u32,
+    pub not_helpful_count: u32,
+    pub score: Option<f64>,
+    pub status: FfiBirdwatchStatus,
+    pub my_rating: Option<FfiBirdwatchRating>,
+}
+
+fn core_birdwatch_note_to_ffi(n: BirdwatchNote) -> FfiBirdwatchNote {
+    FfiBirdwatchNote {
+        event_id: n.event_id,
+        target_event_id: n.target_event_id,
+        author: n.author,
+        context: n.context.into(),
+        content: n.content,
+        source_url: n.source_url,
+        created_at: n.created_at,
+        helpful_count: n.helpful_count,
+        not_helpful_count: n.not_helpful_count,
+        score: n.score,
+        status: n.status.into(),
+        my_rating: n.my_rating.map(Into::into),
+    }
+}
+
+#[derive(uniffi::Record)]
+pub struct FfiBirdwatchSummary {
+    pub target_event_id: String,
+    /// Helpful notes first, then newest first.
+    pub notes: Vec<FfiBirdwatchNote>,
+    pub shown_note_id: Option<String>,
+}
+
+fn core_birdwatch_summary_to_ffi(s: BirdwatchSummary) -> FfiBirdwatchSummary {
+    FfiBirdwatchSummary {
+        target_event_id: s.target_event_id,
+        notes: s.notes.into_iter().map(core_birdwatch_note_to_ffi).collect(),
+        shown_note_id: s.shown_note_id,
+    }
+}
+
+#[derive(uniffi::Record)]
+pub struct FfiArticle {
+    pub event_id: String,
     pub pubkey: String,
+    pub identifier: String,
+    pub title: String,
+    pub summary: String,
+    pub image: String,
+    pub published_at: Option<u64>,
+    pub created_at: u64,
+    pub content: String,
+    pub hashtags: Vec<String>,
+    pub draft: bool,
+    pub naddr: String,
+    pub is_protected: bool,
+}
+
+fn core_article_to_ffi(a: Article) -> FfiArticle {
+    FfiArticle {
+        event_id: a.event_id,
+        pubkey: a.pubkey,
+        identifier: a.identifier,
+        title: a.title,
+        summary: a.summary,
+        image: a.image,
+        published_at: a.published_at,
+        created_at: a.created_at,
+        content: a.content,
+        hashtags: a.hashtags,
+        draft: a.draft,
+        naddr: a.naddr,
+        is_protected: a.is_protected,
+    }
 }
 
+/// Fields for `publish_article` / `save_article_draft`.
 #[derive(uniffi::Record)]
-pub struct FfiScoredPost {
+pub struct FfiArticleInput {
+    /// `d` identifier of the article to replace; `None` for a new one.
+    pub identifier: Option<String>,
+    pub title: String,
+    pub summary: String,
+    pub image: String,
+    pub content: String,
+    pub hashtags: Vec<String>,
+    /// `None` keeps the published version's when editing.
+    pub published_at: Option<u64>,
+}
+
+impl From<FfiArticleInput> for ArticleInput {
+    fn from(i: FfiArticleInput) -> Self {
+        ArticleInput {
+            identifier: i.identifier,
+            title: i.title,
+            summary: i.summary,
+            image: i.image,
+            content: i.content,
+            hashtags: i.hashtags,
+            published_at: i.published_at,
+        }
+    }
+}
+
+#[derive(uniffi::Enum)]
+pub enum FfiLiveStatus {
+    Planned,
+    Live,
+    Ended,
+}
+
+impl From<FfiLiveStatus> for LiveStatus {
+    fn from(s: FfiLiveStatus) -> Self {
+        match s {
+            FfiLiveStatus::Planned => LiveStatus::Planned,
+            FfiLiveStatus::Live => LiveStatus::Live,
+            FfiLiveStatus::Ended => LiveStatus::Ended,
+        }
+    }
+}
+
+impl From<LiveStatus> for FfiLiveStatus {
+    fn from(s: LiveStatus) -> Self {
+        match s {
+            LiveStatus::Planned => FfiLiveStatus::Planned,
+            LiveStatus::Live => FfiLiveStatus::Live,
+            LiveStatus::Ended => FfiLiveStatus::Ended,
+        }
+    }
+}
+
+#[derive(uniffi::Record)]
+pub struct FfiLiveParticipant {
+    pub pubkey: String,
+    pub relay: Option<String>,
+    pub role: String,
+    pub proof: Option<String>,
+}
+
+#[derive(uniffi::Record)]
+pub struct FfiLiveActivity {
     pub event_id: String,
     pub pubkey: String,
-    pub score: f64,
+    /// Pubkey of the `Host` participant (or the event author).
+    pub host: String,
+    pub identifier: String,
+    pub coordinate: String,
+    pub title: String,
+    pub summary: String,
+    pub image: String,
+    pub streaming_url: Option<String>,
+    pub recording_url: Option<String>,
+    pub status: FfiLiveStatus,
+    pub starts_at: Option<u64>,
+    pub ends_at: Option<u64>,
+    pub current_participants: Option<u64>,
+    pub total_participants: Option<u64>,
+    pub participants: Vec<FfiLiveParticipant>,
+    pub hashtags: Vec<String>,
     pub created_at: u64,
 }
 
+fn core_live_activity_to_ffi(a: LiveActivity) -> FfiLiveActivity {
+    FfiLiveActivity {
+        host: a.host().to_string(),
+        event_id: a.event_id,
+        pubkey: a.pubkey,
+        identifier: a.identifier,
+        coordinate: a.coordinate,
+        title: a.title,
+        summary: a.summary,
+        image: a.image,
+        streaming_url: a.streaming_url,
+        recording_url: a.recording_url,
+        status: a.status.into(),
+        starts_at: a.starts_at,
+        ends_at: a.ends_at,
+        current_participants: a.current_participants,
+        total_participants: a.total_participants,
+        participants: a
+            .participants
+            .into_iter()
+            .map(|p| FfiLiveParticipant {
+                pubkey: p.pubkey,
+                relay: p.relay,
+                role: p.role,
+                proof: p.proof,
+            })
+            .collect(),
+        hashtags: a.hashtags,
+        created_at: a.created_at,
+    }
+}
+
 #[derive(uniffi::Record)]
-pub struct FfiConnectionStats {
-    pub connected_relays: u32,
-    pub total_relays: u32,
+pub struct FfiLiveChatMessage {
+    pub event_id: String,
+    pub activity: String,
+    pub pubkey: String,
+    pub content: String,
+    pub created_at: u64,
+    pub reply_to: Option<String>,
+    pub is_protected: bool,
+}
+
+fn core_live_chat_to_ffi(m: LiveChatMessage) -> FfiLiveChatMessage {
+    FfiLiveChatMessage {
+        event_id: m.event_id,
+        activity: m.activity,
+        pubkey: m.pubkey,
+        content: m.content,
+        created_at: m.created_at,
+        reply_to: m.reply_to,
+        is_protected: m.is_protected,
+    }
+}
+
+#[derive(uniffi::Record)]
+pub struct FfiBadgeThumbnail {
+    pub url: String,
+    pub dimensions: Option<String>,
+}
+
+#[derive(uniffi::Record)]
+pub struct FfiBadgeDefinition {
+    pub event_id: String,
+    pub coordinate: String,
+    pub issuer: String,
+    pub identifier: String,
+    pub name: String,
+    pub description: String,
+    pub image: Option<String>,
+    pub image_dimensions: Option<String>,
+    pub thumbnails: Vec<FfiBadgeThumbnail>,
+    /// Smallest thumbnail at least 64px wide, else the image.
+    pub thumb: Option<String>,
+}
+
+fn core_badge_definition_to_ffi(d: BadgeDefinition) -> FfiBadgeDefinition {
+    FfiBadgeDefinition {
+        thumb: d.thumbnail(64).map(str::to_string),
+        event_id: d.event_id,
+        coordinate: d.coordinate,
+        issuer: d.issuer,
+        identifier: d.identifier,
+        name: d.name,
+        description: d.description,
+        image: d.image,
+        image_dimensions: d.image_dimensions,
+        thumbnails: d
+            .thumbnails
+            .into_iter()
+            .map(|t| FfiBadgeThumbnail {
+                url: t.url,
+                dimensions: t.dimensions,
+            })
+            .collect(),
+    }
+}
+
+#[derive(uniffi::Record)]
+pub struct FfiBadge {
+    pub definition: FfiBadgeDefinition,
+    pub award_event_id: String,
+    pub awarded_at: u64,
+    pub on_profile: bool,
+}
+
+fn core_badge_to_ffi(b: Badge) -> FfiBadge {
+    FfiBadge {
+        definition: core_badge_definition_to_ffi(b.definition),
+        award_event_id: b.award_event_id,
+        awarded_at: b.awarded_at,
+        on_profile: b.on_profile,
+    }
+}
+
+/// Fields for `create_badge_definition`.
+#[derive(uniffi::Record)]
+pub struct FfiBadgeDefinitionInput {
+    /// `d` identifier; reusing one replaces that definition.
+    pub identifier: String,
+    pub name: String,
+    pub description: String,
+    pub image: Option<String>,
+    pub image_dimensions: Option<String>,
+    pub thumbnails: Vec<FfiBadgeThumbnail>,
+}
+
+impl From<FfiBadgeDefinitionInput> for BadgeDefinitionInput {
+    fn from(i: FfiBadgeDefinitionInput) -> Self {
+        BadgeDefinitionInput {
+            identifier: i.identifier,
+            name: i.name,
+            description: i.description,
+            image: i.image,
+            image_dimensions: i.image_dimensions,
+            thumbnails: i
+                .thumbnails
+                .into_iter()
+                .map(|t| BadgeThumbnail {
+                    url: t.url,
+                    dimensions: t.dimensions,
+                })
+                .collect(),
+        }
+    }
+}
+
+#[derive(uniffi::Record)]
+pub struct FfiRelayRejection {
+    pub url: String,
+    pub reason: String,
+}
+
+#[derive(uniffi::Record)]
+pub struct FfiProtectedPublishResult {
+    pub event_id: String,
+    pub accepted: Vec<String>,
+    pub rejected: Vec<FfiRelayRejection>,
+    /// Relays without NIP-70 + NIP-42 support (or no known NIP-11 document).
+    pub skipped: Vec<String>,
+}
+
+/// Confirmation summary of a pending NIP-62 request to vanish.
+#[derive(uniffi::Record)]
+pub struct FfiVanishPreview {
+    pub event_id: String,
+    pub all_relays: bool,
+    pub target_relays: Vec<String>,
+    pub publish_relays: Vec<String>,
+    pub reason: String,
+    /// The user's own events in the local cache, purged after sending.
+    pub local_event_count: u64,
+    pub mls_group_count: u32,
+    /// The request for external signers.
+    pub unsigned_event_json: String,
+}
+
+#[derive(uniffi::Record)]
+pub struct FfiVanishResult {
+    pub event_id: String,
+    pub accepted: Vec<String>,
+    pub rejected: Vec<FfiRelayRejection>,
+    pub local_events_purged: bool,
+    pub scheduled_posts_cancelled: u32,
+    pub mls_wiped: bool,
+}
+
+fn core_vanish_result_to_ffi(r: nurunuru_core::types::VanishResult) -> FfiVanishResult {
+    FfiVanishResult {
+        event_id: r.event_id,
+        accepted: r.accepted,
+        rejected: r
+            .rejected
+            .into_iter()
+            .map(|r| FfiRelayRejection {
+                url: r.url,
+                reason: r.reason,
+            })
+            .collect(),
+        local_events_purged: r.local_events_purged,
+        scheduled_posts_cancelled: r.scheduled_posts_cancelled,
+        mls_wiped: r.mls_wiped,
+    }
+}
+
+/// Progress of `export_events` / `import_events`, delivered on the calling
+/// thread.
+#[uniffi::export(with_foreign)]
+pub trait FfiBackupProgressListener: Send + Sync {
+    fn on_progress(&self, progress: FfiBackupProgress);
+}
+
+#[derive(uniffi::Record)]
+pub struct FfiBackupProgress {
+    /// Distinct events fetched (export) or events handled (import).
+    pub processed: u64,
+    /// Events to import; 0 while exporting.
+    pub total: u64,
+    pub batch: u32,
+}
+
+impl From<BackupProgress> for FfiBackupProgress {
+    fn from(p: BackupProgress) -> Self {
+        Self {
+            processed: p.processed,
+            total: p.total,
+            batch: p.batch,
+        }
+    }
+}
+
+#[derive(uniffi::Record)]
+pub struct FfiBackupImportError {
+    /// 1-based line in the backup file.
+    pub line: u32,
+    pub event_id: Option<String>,
+    pub error: String,
+}
+
+#[derive(uniffi::Record)]
+pub struct FfiBackupImportResult {
+    pub total: u64,
+    pub imported: u64,
+    pub failed: u64,
+    /// Protected events of other users, not rebroadcast.
+    pub skipped: u64,
+    pub errors: Vec<FfiBackupImportError>,
+}
+
+/// Progress of `migrate_history_to_relay`, delivered on the calling thread.
+#[uniffi::export(with_foreign)]
+pub trait FfiMigrationProgressListener: Send + Sync {
+    fn on_progress(&self, progress: FfiMigrationProgress);
+}
+
+#[derive(uniffi::Record)]
+pub struct FfiMigrationProgress {
+    pub processed: u64,
+    /// Events missing from the target relay.
+    pub total: u64,
+    pub published: u64,
+    pub failed: u64,
+}
+
+#[derive(uniffi::Record)]
+pub struct FfiMigrationFailure {
+    pub event_id: String,
+    pub reason: String,
+}
+
+#[derive(uniffi::Record)]
+pub struct FfiMigrationReport {
+    pub relay_url: String,
+    pub found: u64,
+    pub already_on_relay: u64,
+    /// Migrated by an earlier, interrupted run.
+    pub resumed: u64,
+    pub published: u64,
+    pub failed: Vec<FfiMigrationFailure>,
+}
+
+/// Options for `publish_note_with_options` / `create_unsigned_note_with_options`.
+#[derive(uniffi::Record)]
+pub struct FfiNoteOptions {
+    /// Add `t` / `p` / `q` / NIP-30 `emoji` tags implied by the content.
+    pub auto_tag: bool,
+    /// NIP-40: seconds after publishing at which the note expires.
+    pub expires_in: Option<u64>,
+    /// NIP-36: mark the note as sensitive with this reason (`""` for none).
+    pub content_warning: Option<String>,
+}
+
+impl From<FfiNoteOptions> for NoteOptions {
+    fn from(o: FfiNoteOptions) -> Self {
+        NoteOptions {
+            auto_tag: o.auto_tag,
+            expires_in: o.expires_in,
+            content_warning: o.content_warning,
+        }
+    }
+}
+
+#[derive(uniffi::Enum)]
+pub enum FfiContentWarningPolicy {
+    Show,
+    Blur,
+    Hide,
+}
+
+impl From<FfiContentWarningPolicy> for ContentWarningPolicy {
+    fn from(p: FfiContentWarningPolicy) -> Self {
+        match p {
+            FfiContentWarningPolicy::Show => ContentWarningPolicy::Show,
+            FfiContentWarningPolicy::Blur => ContentWarningPolicy::Blur,
+            FfiContentWarningPolicy::Hide => ContentWarningPolicy::Hide,
+        }
+    }
+}
+
+impl From<ContentWarningPolicy> for FfiContentWarningPolicy {
+    fn from(p: ContentWarningPolicy) -> Self {
+        match p {
+            ContentWarningPolicy::Show => FfiContentWarningPolicy::Show,
+            ContentWarningPolicy::Blur => FfiContentWarningPolicy::Blur,
+            ContentWarningPolicy::Hide => FfiContentWarningPolicy::Hide,
+        }
+    }
+}
+
+#[derive(uniffi::Enum)]
+pub enum FfiContentSegment {
+    Text { text: String },
+    Url { url: String },
+    Image { url: String },
+    Video { url: String },
+    Profile { uri: String, pubkey: String, relays: Vec<String> },
+    Event {
+        uri: String,
+        event_id: String,
+        author: Option<String>,
+        kind: Option<u32>,
+        relays: Vec<String>,
+    },
+    Address {
+        uri: String,
+        kind: u32,
+        pubkey: String,
+        identifier: String,
+        relays: Vec<String>,
+    },
+    Hashtag { tag: String },
+    Emoji { shortcode: String, url: String },
+    Invoice { invoice: String },
+}
+
+fn core_content_segment_to_ffi(segment: ContentSegment) -> FfiContentSegment {
+    match segment {
+        ContentSegment::Text { text } => FfiContentSegment::Text { text },
+        ContentSegment::Url { url } => FfiContentSegment::Url { url },
+        ContentSegment::Image { url } => FfiContentSegment::Image { url },
+        ContentSegment::Video { url } => FfiContentSegment::Video { url },
+        ContentSegment::Profile { uri, pubkey, relays } => {
+            FfiContentSegment::Profile { uri, pubkey, relays }
+        }
+        ContentSegment::Event { uri, event_id, author, kind, relays } => FfiContentSegment::Event {
+            uri,
+            event_id,
+            author,
+            kind: kind.map(u32::from),
+            relays,
+        },
+        ContentSegment::Address { uri, kind, pubkey, identifier, relays } => {
+            FfiContentSegment::Address {
+                uri,
+                kind: kind as u32,
+                pubkey,
+                identifier,
+                relays,
+            }
+        }
+        ContentSegment::Hashtag { tag } => FfiContentSegment::Hashtag { tag },
+        ContentSegment::Emoji { shortcode, url } => FfiContentSegment::Emoji { shortcode, url },
+        ContentSegment::Invoice { invoice } => FfiContentSegment::Invoice { invoice },
+    }
 }
 
 // ─── MLS FFI Record types ───────────────────────────────────────────────────
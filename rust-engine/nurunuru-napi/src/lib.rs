@@ -16,6 +16,7 @@
 
 use std::sync::Arc;
 
+use napi::threadsafe_function::{ErrorStrategy, ThreadsafeFunction, ThreadsafeFunctionCallMode};
 use napi::Result;
 use napi_derive::napi;
 use nostr::prelude::*;
@@ -62,6 +63,10 @@ pub struct NapiScoredPost {
     pub score: f64,
     /// Unix timestamp (seconds) as f64 for JS Number compatibility.
     pub created_at: f64,
+    /// NIP-36 content warning reason (`""` if none given).
+    pub content_warning: Option<String>,
+    /// NIP-70 protected event (`["-"]` tag).
+    pub is_protected: bool,
 }
 
 impl From<ScoredPost> for NapiScoredPost {
@@ -71,6 +76,8 @@ impl From<ScoredPost> for NapiScoredPost {
             pubkey: sp.pubkey,
             score: sp.score,
             created_at: sp.created_at as f64,
+            content_warning: sp.content_warning,
+            is_protected: sp.is_protected,
         }
     }
 }
@@ -119,291 +126,2463 @@ impl From<EngagementData> for NapiEngagementData {
     }
 }
 
-// ─── Helper ─────────────────────────────────────────────────────
+#[napi(object)]
+pub struct NapiScheduledPost {
+    pub id: String,
+    /// Target publish time (unix seconds).
+    pub publish_at: f64,
+    pub kind: u32,
+    pub content: String,
+    pub tags: Vec<Vec<String>>,
+    /// `true` when the event was signed at scheduling time (external signer).
+    pub pre_signed: bool,
+    /// `"pending"`, `"published"` or `"failed"`.
+    pub status: String,
+    pub published_event_id: Option<String>,
+    pub last_error: Option<String>,
+    pub attempts: u32,
+    pub created_at: f64,
+}
 
-fn to_napi_err(e: impl std::fmt::Display) -> napi::Error {
-    napi::Error::from_reason(e.to_string())
+impl From<ScheduledPost> for NapiScheduledPost {
+    fn from(p: ScheduledPost) -> Self {
+        let (kind, content, tags) = p.payload.template();
+        Self {
+            id: p.id,
+            publish_at: p.publish_at as f64,
+            kind: kind as u32,
+            content,
+            tags,
+            pre_signed: matches!(p.payload, ScheduledPayload::Signed { .. }),
+            status: p.status.as_str().to_string(),
+            published_event_id: p.published_event_id,
+            last_error: p.last_error,
+            attempts: p.attempts,
+            created_at: p.created_at as f64,
+        }
+    }
 }
 
-// ─── Main engine wrapper ────────────────────────────────────────
+#[napi(object)]
+pub struct NapiThreadNode {
+    pub event_id: String,
+    pub parent_id: Option<String>,
+    pub depth: u32,
+    pub reply_count: u32,
+    pub descendant_count: u32,
+    pub missing: bool,
+    pub pubkey: String,
+    pub created_at: f64,
+    pub event_json: Option<String>,
+    pub content_warning: Option<String>,
+    pub is_protected: bool,
+}
 
-/// NuruNuru native engine for Node.js.
-///
-/// Create with `NuruNuruNapi.create(secretKeyHex, dbPath)`.
-/// All methods that hit the network return Promises.
-#[napi]
-pub struct NuruNuruNapi {
-    engine: Arc<NuruNuruEngine>,
+#[napi(object)]
+pub struct NapiThreadView {
+    pub root_id: String,
+    pub focus_id: String,
+    pub nodes: Vec<NapiThreadNode>,
 }
 
-#[napi]
-impl NuruNuruNapi {
-    // ─── Lifecycle ────────────────────────────────────────────
+impl From<ThreadView> for NapiThreadView {
+    fn from(v: ThreadView) -> Self {
+        Self {
+            root_id: v.root_id,
+            focus_id: v.focus_id,
+            nodes: v
+                .nodes
+                .into_iter()
+                .map(|n| NapiThreadNode {
+                    event_id: n.event_id,
+                    parent_id: n.parent_id,
+                    depth: n.depth,
+                    reply_count: n.reply_count,
+                    descendant_count: n.descendant_count,
+                    missing: n.missing,
+                    pubkey: n.pubkey,
+                    created_at: n.created_at as f64,
+                    event_json: n.event_json,
+                    content_warning: n.content_warning,
+                    is_protected: n.is_protected,
+                })
+                .collect(),
+        }
+    }
+}
 
-    /// Create a new engine instance (async factory).
-    ///
-    /// `secret_key_hex` — hex or nsec private key.
-    /// `db_path` — directory for nostrdb (e.g. `"./nurunuru-db"`).
-    #[napi(factory)]
-    pub async fn create(secret_key_hex: String, db_path: String) -> Result<Self> {
-        let keys = Keys::parse(&secret_key_hex).map_err(to_napi_err)?;
+#[napi(object)]
+pub struct NapiFollowListUpdate {
+    pub added: Vec<String>,
+    pub removed: Vec<String>,
+    pub follow_count: u32,
+    pub event_id: Option<String>,
+}
+
+impl From<FollowListUpdate> for NapiFollowListUpdate {
+    fn from(u: FollowListUpdate) -> Self {
+        Self {
+            added: u.added,
+            removed: u.removed,
+            follow_count: u.follow_count,
+            event_id: u.event_id,
+        }
+    }
+}
+
+#[napi(object)]
+pub struct NapiFollowListVersion {
+    pub event_id: String,
+    pub created_at: f64,
+    pub follow_count: u32,
+    pub added: Vec<String>,
+    pub removed: Vec<String>,
+    pub is_current: bool,
+}
+
+impl From<FollowListVersion> for NapiFollowListVersion {
+    fn from(v: FollowListVersion) -> Self {
+        Self {
+            event_id: v.event_id,
+            created_at: v.created_at as f64,
+            follow_count: v.follow_count,
+            added: v.added,
+            removed: v.removed,
+            is_current: v.is_current,
+        }
+    }
+}
+
+#[napi(object)]
+pub struct NapiMuteEntries {
+    pub pubkeys: Vec<String>,
+    pub words: Vec<String>,
+    pub hashtags: Vec<String>,
+    pub threads: Vec<String>,
+}
+
+impl From<MuteEntries> for NapiMuteEntries {
+    fn from(e: MuteEntries) -> Self {
+        Self {
+            pubkeys: e.pubkeys,
+            words: e.words,
+            hashtags: e.hashtags,
+            threads: e.threads,
+        }
+    }
+}
+
+#[napi(object)]
+pub struct NapiMuteList {
+    pub public: NapiMuteEntries,
+    pub private: NapiMuteEntries,
+    pub private_locked: bool,
+    pub event_id: Option<String>,
+    pub created_at: f64,
+}
+
+impl From<MuteList> for NapiMuteList {
+    fn from(l: MuteList) -> Self {
+        Self {
+            public: l.public.into(),
+            private: l.private.into(),
+            private_locked: l.private_locked,
+            event_id: l.event_id,
+            created_at: l.created_at as f64,
+        }
+    }
+}
+
+#[napi(object)]
+pub struct NapiBookmarkEntries {
+    pub event_ids: Vec<String>,
+    pub addresses: Vec<String>,
+    pub hashtags: Vec<String>,
+    pub urls: Vec<String>,
+}
+
+impl From<BookmarkEntries> for NapiBookmarkEntries {
+    fn from(e: BookmarkEntries) -> Self {
+        Self {
+            event_ids: e.event_ids,
+            addresses: e.addresses,
+            hashtags: e.hashtags,
+            urls: e.urls,
+        }
+    }
+}
+
+#[napi(object)]
+pub struct NapiBookmarkList {
+    pub identifier: Option<String>,
+    pub title: Option<String>,
+    pub description: Option<String>,
+    pub image: Option<String>,
+    pub public: NapiBookmarkEntries,
+    pub private: NapiBookmarkEntries,
+    pub private_locked: bool,
+    pub event_id: Option<String>,
+    pub created_at: f64,
+}
+
+impl From<BookmarkList> for NapiBookmarkList {
+    fn from(l: BookmarkList) -> Self {
+        Self {
+            identifier: l.identifier,
+            title: l.title,
+            description: l.description,
+            image: l.image,
+            public: l.public.into(),
+            private: l.private.into(),
+            private_locked: l.private_locked,
+            event_id: l.event_id,
+            created_at: l.created_at as f64,
+        }
+    }
+}
+
+#[napi(object)]
+pub struct NapiNotificationGroup {
+    pub key: String,
+    /// `"mention" | "reply" | "reaction" | "repost" | "quote" | "zap" | "follow"`
+    pub kind: String,
+    pub target_event_id: Option<String>,
+    pub actors: Vec<String>,
+    pub event_ids: Vec<String>,
+    pub preview: Option<String>,
+    pub zap_msats: f64,
+    pub latest_at: f64,
+    pub unread: bool,
+}
+
+#[napi(object)]
+pub struct NapiNotificationPage {
+    pub groups: Vec<NapiNotificationGroup>,
+    pub unread_count: u32,
+    pub oldest_at: Option<f64>,
+}
+
+impl From<NotificationPage> for NapiNotificationPage {
+    fn from(p: NotificationPage) -> Self {
+        Self {
+            groups: p
+                .groups
+                .into_iter()
+                .map(|g| NapiNotificationGroup {
+                    key: g.key,
+                    kind: g.kind.as_str().to_string(),
+                    target_event_id: g.target_event_id,
+                    actors: g.actors,
+                    event_ids: g.event_ids,
+                    preview: g.preview,
+                    zap_msats: g.zap_msats as f64,
+                    latest_at: g.latest_at as f64,
+                    unread: g.unread,
+                })
+                .collect(),
+            unread_count: p.unread_count,
+            oldest_at: p.oldest_at.map(|t| t as f64),
+        }
+    }
+}
+
+#[napi(object)]
+pub struct NapiReactionSummary {
+    pub content: String,
+    pub emoji_url: Option<String>,
+    pub count: u32,
+    pub reactors: Vec<String>,
+    pub reacted_by_me: bool,
+}
+
+#[napi(object)]
+pub struct NapiZapperSummary {
+    pub pubkey: String,
+    pub msats: f64,
+    pub zap_count: u32,
+    pub comment: Option<String>,
+}
+
+#[napi(object)]
+pub struct NapiReactionDetails {
+    pub event_id: String,
+    pub reactions: Vec<NapiReactionSummary>,
+    pub my_reaction: Option<String>,
+    pub zappers: Vec<NapiZapperSummary>,
+    pub total_zap_msats: f64,
+}
+
+impl From<ReactionDetails> for NapiReactionDetails {
+    fn from(d: ReactionDetails) -> Self {
+        Self {
+            event_id: d.event_id,
+            reactions: d
+                .reactions
+                .into_iter()
+                .map(|r| NapiReactionSummary {
+                    content: r.content,
+                    emoji_url: r.emoji_url,
+                    count: r.reactors.len() as u32,
+                    reactors: r.reactors,
+                    reacted_by_me: r.reacted_by_me,
+                })
+                .collect(),
+            my_reaction: d.my_reaction,
+            zappers: d
+                .zappers
+                .into_iter()
+                .map(|z| NapiZapperSummary {
+                    pubkey: z.pubkey,
+                    msats: z.msats as f64,
+                    zap_count: z.zap_count,
+                    comment: z.comment,
+                })
+                .collect(),
+            total_zap_msats: d.total_zap_msats as f64,
+        }
+    }
+}
+
+#[napi(object)]
+pub struct NapiReportPolicy {
+    pub downrank_threshold: u32,
+    pub hide_threshold: u32,
+}
+
+impl From<ReportPolicy> for NapiReportPolicy {
+    fn from(p: ReportPolicy) -> Self {
+        Self {
+            downrank_threshold: p.downrank_threshold,
+            hide_threshold: p.hide_threshold,
+        }
+    }
+}
+
+impl From<NapiReportPolicy> for ReportPolicy {
+    fn from(p: NapiReportPolicy) -> Self {
+        ReportPolicy {
+            downrank_threshold: p.downrank_threshold,
+            hide_threshold: p.hide_threshold,
+        }
+    }
+}
+
+/// A NIP-32 community note.  `context` is `misleading`, `missing_context`,
+/// `factual_error`, `outdated` or `satire`; `status` is
+/// `needs_more_ratings`, `helpful` or `not_helpful`; `myRating` is
+/// `helpful` or `not_helpful`.
+#[napi(object)]
+pub struct NapiBirdwatchNote {
+    pub event_id: String,
+    pub target_event_id: String,
+    pub author: String,
+    pub context: String,
+    pub content: String,
+    pub source_url: Option<String>,
+    pub created_at: f64,
+    pub helpful_count: u32,
+    pub not_helpful_count: u32,
+    pub score: Option<f64>,
+    pub status: String,
+    pub my_rating: Option<String>,
+}
+
+impl From<BirdwatchNote> for NapiBirdwatchNote {
+    fn from(n: BirdwatchNote) -> Self {
+        Self {
+            event_id: n.event_id,
+            target_event_id: n.target_event_id,
+            author: n.author,
+            context: n.context.as_str().to_string(),
+            content: n.content,
+            source_url: n.source_url,
+            created_at: n.created_at as f64,
+            helpful_count: n.helpful_count,
+            not_helpful_count: n.not_helpful_count,
+            score: n.score,
+            status: n.status.as_str().to_string(),
+            my_rating: n.my_rating.map(|r| r.as_str().to_string()),
+        }
+    }
+}
+
+#[napi(object)]
+pub struct NapiBirdwatchSummary {
+    pub target_event_id: String,
+    /// Helpful notes first, then newest first.
+    pub notes: Vec<NapiBirdwatchNote>,
+    pub shown_note_id: Option<String>,
+}
+
+impl From<BirdwatchSummary> for NapiBirdwatchSummary {
+    fn from(s: BirdwatchSummary) -> Self {
+        Self {
+            target_event_id: s.target_event_id,
+            notes: s.notes.into_iter().map(Into::into).collect(),
+            shown_note_id: s.shown_note_id,
+        }
+    }
+}
+
+#[napi(object)]
+pub struct NapiArticle {
+    pub event_id: String,
+    pub pubkey: String,
+    pub identifier: String,
+    pub title: String,
+    pub summary: String,
+    pub image: String,
+    pub published_at: Option<f64>,
+    pub created_at: f64,
+    pub content: String,
+    pub hashtags: Vec<String>,
+    pub draft: bool,
+    pub naddr: String,
+    pub is_protected: bool,
+}
+
+impl From<Article> for NapiArticle {
+    fn from(a: Article) -> Self {
+        Self {
+            event_id: a.event_id,
+            pubkey: a.pubkey,
+            identifier: a.identifier,
+            title: a.title,
+            summary: a.summary,
+            image: a.image,
+            published_at: a.published_at.map(|t| t as f64),
+            created_at: a.created_at as f64,
+            content: a.content,
+            hashtags: a.hashtags,
+            draft: a.draft,
+            naddr: a.naddr,
+            is_protected: a.is_protected,
+        }
+    }
+}
+
+#[napi(object)]
+pub struct NapiArticleInput {
+    /// `d` identifier of the article to replace; omit for a new one.
+    pub identifier: Option<String>,
+    pub title: Option<String>,
+    pub summary: Option<String>,
+    pub image: Option<String>,
+    pub content: String,
+    pub hashtags: Option<Vec<String>>,
+    /// Omit to keep the published version's when editing.
+    pub published_at: Option<f64>,
+}
+
+impl From<NapiArticleInput> for ArticleInput {
+    fn from(i: NapiArticleInput) -> Self {
+        ArticleInput {
+            identifier: i.identifier,
+            title: i.title.unwrap_or_default(),
+            summary: i.summary.unwrap_or_default(),
+            image: i.image.unwrap_or_default(),
+            content: i.content,
+            hashtags: i.hashtags.unwrap_or_default(),
+            published_at: i.published_at.map(|t| t as u64),
+        }
+    }
+}
+
+#[napi(object)]
+pub struct NapiLiveParticipant {
+    pub pubkey: String,
+    pub relay: Option<String>,
+    pub role: String,
+    pub proof: Option<String>,
+}
+
+/// A NIP-53 live activity.  `status` is `planned`, `live` or `ended`.
+#[napi(object)]
+pub struct NapiLiveActivity {
+    pub event_id: String,
+    pub pubkey: String,
+    /// Pubkey of the `Host` participant (or the event author).
+    pub host: String,
+    pub identifier: String,
+    pub coordinate: String,
+    pub title: String,
+    pub summary: String,
+    pub image: String,
+    pub streaming_url: Option<String>,
+    pub recording_url: Option<String>,
+    pub status: String,
+    pub starts_at: Option<f64>,
+    pub ends_at: Option<f64>,
+    pub current_participants: Option<f64>,
+    pub total_participants: Option<f64>,
+    pub participants: Vec<NapiLiveParticipant>,
+    pub hashtags: Vec<String>,
+    pub created_at: f64,
+}
+
+impl From<LiveActivity> for NapiLiveActivity {
+    fn from(a: LiveActivity) -> Self {
+        Self {
+            host: a.host().to_string(),
+            event_id: a.event_id,
+            pubkey: a.pubkey,
+            identifier: a.identifier,
+            coordinate: a.coordinate,
+            title: a.title,
+            summary: a.summary,
+            image: a.image,
+            streaming_url: a.streaming_url,
+            recording_url: a.recording_url,
+            status: a.status.as_str().to_string(),
+            starts_at: a.starts_at.map(|t| t as f64),
+            ends_at: a.ends_at.map(|t| t as f64),
+            current_participants: a.current_participants.map(|n| n as f64),
+            total_participants: a.total_participants.map(|n| n as f64),
+            participants: a
+                .participants
+                .into_iter()
+                .map(|p| NapiLiveParticipant {
+                    pubkey: p.pubkey,
+                    relay: p.relay,
+                    role: p.role,
+                    proof: p.proof,
+                })
+                .collect(),
+            hashtags: a.hashtags,
+            created_at: a.created_at as f64,
+        }
+    }
+}
+
+#[napi(object)]
+pub struct NapiLiveChatMessage {
+    pub event_id: String,
+    pub activity: String,
+    pub pubkey: String,
+    pub content: String,
+    pub created_at: f64,
+    pub reply_to: Option<String>,
+    pub is_protected: bool,
+}
+
+impl From<LiveChatMessage> for NapiLiveChatMessage {
+    fn from(m: LiveChatMessage) -> Self {
+        Self {
+            event_id: m.event_id,
+            activity: m.activity,
+            pubkey: m.pubkey,
+            content: m.content,
+            created_at: m.created_at as f64,
+            reply_to: m.reply_to,
+            is_protected: m.is_protected,
+        }
+    }
+}
+
+#[napi(object)]
+pub struct NapiBadgeThumbnail {
+    pub url: String,
+    pub dimensions: Option<String>,
+}
+
+#[napi(object)]
+pub struct NapiBadgeDefinition {
+    pub event_id: String,
+    pub coordinate: String,
+    pub issuer: String,
+    pub identifier: String,
+    pub name: String,
+    pub description: String,
+    pub image: Option<String>,
+    pub image_dimensions: Option<String>,
+    pub thumbnails: Vec<NapiBadgeThumbnail>,
+    /// Smallest thumbnail at least 64px wide, else the image.
+    pub thumb: Option<String>,
+}
+
+impl From<BadgeDefinition> for NapiBadgeDefinition {
+    fn from(d: BadgeDefinition) -> Self {
+        Self {
+            thumb: d.thumbnail(64).map(str::to_string),
+            event_id: d.event_id,
+            coordinate: d.coordinate,
+            issuer: d.issuer,
+            identifier: d.identifier,
+            name: d.name,
+            description: d.description,
+            image: d.image,
+            image_dimensions: d.image_dimensions,
+            thumbnails: d
+                .thumbnails
+                .into_iter()
+                .map(|t| NapiBadgeThumbnail {
+                    url: t.url,
+                    dimensions: t.dimensions,
+                })
+                .collect(),
+        }
+    }
+}
+
+#[napi(object)]
+pub struct NapiBadge {
+    pub definition: NapiBadgeDefinition,
+    pub award_event_id: String,
+    pub awarded_at: f64,
+    pub on_profile: bool,
+}
+
+impl From<Badge> for NapiBadge {
+    fn from(b: Badge) -> Self {
+        Self {
+            definition: b.definition.into(),
+            award_event_id: b.award_event_id,
+            awarded_at: b.awarded_at as f64,
+            on_profile: b.on_profile,
+        }
+    }
+}
+
+#[napi(object)]
+pub struct NapiBadgeDefinitionInput {
+    /// `d` identifier; reusing one replaces that definition.
+    pub identifier: String,
+    pub name: String,
+    pub description: Option<String>,
+    pub image: Option<String>,
+    pub image_dimensions: Option<String>,
+    pub thumbnails: Option<Vec<NapiBadgeThumbnail>>,
+}
+
+impl From<NapiBadgeDefinitionInput> for BadgeDefinitionInput {
+    fn from(i: NapiBadgeDefinitionInput) -> Self {
+        BadgeDefinitionInput {
+            identifier: i.identifier,
+            name: i.name,
+            description: i.description.unwrap_or_default(),
+            image: i.image,
+            image_dimensions: i.image_dimensions,
+            thumbnails: i
+                .thumbnails
+                .unwrap_or_default()
+                .into_iter()
+                .map(|t| BadgeThumbnail {
+                    url: t.url,
+                    dimensions: t.dimensions,
+                })
+                .collect(),
+        }
+    }
+}
+
+#[napi(object)]
+pub struct NapiRelayRejection {
+    pub url: String,
+    pub reason: String,
+}
+
+#[napi(object)]
+pub struct NapiProtectedPublishResult {
+    pub event_id: String,
+    pub accepted: Vec<String>,
+    pub rejected: Vec<NapiRelayRejection>,
+    /// Relays without NIP-70 + NIP-42 support (or no known NIP-11 document).
+    pub skipped: Vec<String>,
+}
+
+impl From<ProtectedPublishResult> for NapiProtectedPublishResult {
+    fn from(r: ProtectedPublishResult) -> Self {
+        Self {
+            event_id: r.event_id,
+            accepted: r.accepted,
+            rejected: r
+                .rejected
+                .into_iter()
+                .map(|j| NapiRelayRejection {
+                    url: j.url,
+                    reason: j.reason,
+                })
+                .collect(),
+            skipped: r.skipped,
+        }
+    }
+}
+
+#[napi(object)]
+pub struct NapiVanishPreview {
+    pub event_id: String,
+    pub all_relays: bool,
+    pub target_relays: Vec<String>,
+    pub publish_relays: Vec<String>,
+    pub reason: String,
+    pub local_event_count: f64,
+    pub mls_group_count: u32,
+    pub unsigned_event_json: String,
+}
+
+impl From<VanishPreview> for NapiVanishPreview {
+    fn from(p: VanishPreview) -> Self {
+        Self {
+            event_id: p.event_id,
+            all_relays: p.all_relays,
+            target_relays: p.target_relays,
+            publish_relays: p.publish_relays,
+            reason: p.reason,
+            local_event_count: p.local_event_count as f64,
+            mls_group_count: p.mls_group_count,
+            unsigned_event_json: p.unsigned_event_json,
+        }
+    }
+}
+
+#[napi(object)]
+pub struct NapiVanishResult {
+    pub event_id: String,
+    pub accepted: Vec<String>,
+    pub rejected: Vec<NapiRelayRejection>,
+    pub local_events_purged: bool,
+    pub scheduled_posts_cancelled: u32,
+    pub mls_wiped: bool,
+}
+
+impl From<VanishResult> for NapiVanishResult {
+    fn from(r: VanishResult) -> Self {
+        Self {
+            event_id: r.event_id,
+            accepted: r.accepted,
+            rejected: r
+                .rejected
+                .into_iter()
+                .map(|j| NapiRelayRejection {
+                    url: j.url,
+                    reason: j.reason,
+                })
+                .collect(),
+            local_events_purged: r.local_events_purged,
+            scheduled_posts_cancelled: r.scheduled_posts_cancelled,
+            mls_wiped: r.mls_wiped,
+        }
+    }
+}
+
+#[napi(object)]
+pub struct NapiBackupProgress {
+    pub processed: f64,
+    /// Events to import; 0 while exporting.
+    pub total: f64,
+    pub batch: u32,
+}
+
+impl From<BackupProgress> for NapiBackupProgress {
+    fn from(p: BackupProgress) -> Self {
+        Self {
+            processed: p.processed as f64,
+            total: p.total as f64,
+            batch: p.batch,
+        }
+    }
+}
+
+#[napi(object)]
+pub struct NapiBackupImportError {
+    pub line: u32,
+    pub event_id: Option<String>,
+    pub error: String,
+}
+
+#[napi(object)]
+pub struct NapiBackupImportResult {
+    pub total: f64,
+    pub imported: f64,
+    pub failed: f64,
+    pub skipped: f64,
+    pub errors: Vec<NapiBackupImportError>,
+}
+
+impl From<BackupImportResult> for NapiBackupImportResult {
+    fn from(r: BackupImportResult) -> Self {
+        Self {
+            total: r.total as f64,
+            imported: r.imported as f64,
+            failed: r.failed as f64,
+            skipped: r.skipped as f64,
+            errors: r
+                .errors
+                .into_iter()
+                .map(|e| NapiBackupImportError {
+                    line: e.line,
+                    event_id: e.event_id,
+                    error: e.error,
+                })
+                .collect(),
+        }
+    }
+}
+
+#[napi(object)]
+pub struct NapiMigrationProgress {
+    pub processed: f64,
+    pub total: f64,
+    pub published: f64,
+    pub failed: f64,
+}
+
+impl From<MigrationProgress> for NapiMigrationProgress {
+    fn from(p: MigrationProgress) -> Self {
+        Self {
+            processed: p.processed as f64,
+            total: p.total as f64,
+            published: p.published as f64,
+            failed: p.failed as f64,
+        }
+    }
+}
+
+#[napi(object)]
+pub struct NapiMigrationFailure {
+    pub event_id: String,
+    pub reason: String,
+}
+
+#[napi(object)]
+pub struct NapiMigrationReport {
+    pub relay_url: String,
+    pub found: f64,
+    pub already_on_relay: f64,
+    pub resumed: f64,
+    pub published: f64,
+    pub failed: Vec<NapiMigrationFailure>,
+}
+
+impl From<MigrationReport> for NapiMigrationReport {
+    fn from(r: MigrationReport) -> Self {
+        Self {
+            relay_url: r.relay_url,
+            found: r.found as f64,
+            already_on_relay: r.already_on_relay as f64,
+            resumed: r.resumed as f64,
+            published: r.published as f64,
+            failed: r
+                .failed
+                .into_iter()
+                .map(|f| NapiMigrationFailure {
+                    event_id: f.event_id,
+                    reason: f.reason,
+                })
+                .collect(),
+        }
+    }
+}
+
+#[napi(object)]
+pub struct NapiNoteOptions {
+    pub auto_tag: Option<bool>,
+    /// NIP-40: seconds after publishing at which the note expires.
+    pub expires_in_secs: Option<f64>,
+    /// NIP-36 content warning reason (`""` for none).
+    pub content_warning: Option<String>,
+}
+
+impl From<NapiNoteOptions> for NoteOptions {
+    fn from(o: NapiNoteOptions) -> Self {
+        NoteOptions {
+            auto_tag: o.auto_tag.unwrap_or(false),
+            expires_in: o.expires_in_secs.map(|s| s as u64),
+            content_warning: o.content_warning,
+        }
+    }
+}
+
+/// One render segment of note content.  `segmentType` is one of `text`,
+/// `url`, `image`, `video`, `profile`, `event`, `address`, `hashtag`,
+/// `emoji`, `invoice`; only the fields relevant to that type are set.
+#[napi(object)]
+pub struct NapiContentSegment {
+    pub segment_type: String,
+    pub text: Option<String>,
+    pub url: Option<String>,
+    pub uri: Option<String>,
+    pub pubkey: Option<String>,
+    pub event_id: Option<String>,
+    pub kind: Option<u32>,
+    pub identifier: Option<String>,
+    pub relays: Option<Vec<String>>,
+    pub shortcode: Option<String>,
+    pub invoice: Option<String>,
+}
+
+impl NapiContentSegment {
+    fn empty(segment_type: &str) -> Self {
+        Self {
+            segment_type: segment_type.to_string(),
+            text: None,
+            url: None,
+            uri: None,
+            pubkey: None,
+            event_id: None,
+            kind: None,
+            identifier: None,
+            relays: None,
+            shortcode: None,
+            invoice: None,
+        }
+    }
+}
+
+impl From<ContentSegment> for NapiContentSegment {
+    fn from(s: ContentSegment) -> Self {
+        match s {
+            ContentSegment::Text { text } => Self { text: Some(text), ..Self::empty("text") },
+            ContentSegment::Url { url } => Self { url: Some(url), ..Self::empty("url") },
+            ContentSegment::Image { url } => Self { url: Some(url), ..Self::empty("image") },
+            ContentSegment::Video { url } => Self { url: Some(url), ..Self::empty("video") },
+            ContentSegment::Profile { uri, pubkey, relays } => Self {
+                uri: Some(uri),
+                pubkey: Some(pubkey),
+                relays: Some(relays),
+                ..Self::empty("profile")
+            },
+            ContentSegment::Event { uri, event_id, author, kind, relays } => Self {
+                uri: Some(uri),
+                event_id: Some(event_id),
+                pubkey: author,
+                kind: kind.map(u32::from),
+                relays: Some(relays),
+                ..Self::empty("event")
+            },
+            ContentSegment::Address { uri, kind, pubkey, identifier, relays } => Self {
+                uri: Some(uri),
+                kind: Some(kind as u32),
+                pubkey: Some(pubkey),
+                identifier: Some(identifier),
+                relays: Some(relays),
+                ..Self::empty("address")
+            },
+            ContentSegment::Hashtag { tag } => Self { text: Some(tag), ..Self::empty("hashtag") },
+            ContentSegment::Emoji { shortcode, url } => Self {
+                shortcode: Some(shortcode),
+                url: Some(url),
+                ..Self::empty("emoji")
+            },
+            ContentSegment::Invoice { invoice } => Self {
+                invoice: Some(invoice),
+                ..Self::empty("invoice")
+            },
+        }
+    }
+}
+
+// ─── Helper ─────────────────────────────────────────────────────
+
+fn to_napi_err(e: impl std::fmt::Display) -> napi::Error {
+    napi::Error::from_reason(e.to_string())
+}
+
+fn parse_mute_kind(kind: &str) -> Result<MuteKind> {
+    MuteKind::parse(kind).ok_or_else(|| to_napi_err(format!("unknown mute kind: {kind}")))
+}
+
+fn parse_report_type(report_type: &str) -> Result<ReportType> {
+    ReportType::parse(report_type)
+        .ok_or_else(|| to_napi_err(format!("unknown report type: {report_type}")))
+}
+
+fn parse_bookmark_kind(kind: &str) -> Result<BookmarkKind> {
+    BookmarkKind::parse(kind).ok_or_else(|| to_napi_err(format!("unknown bookmark kind: {kind}")))
+}
+
+// ─── Main engine wrapper ────────────────────────────────────────
+
+/// NuruNuru native engine for Node.js.
+///
+/// Create with `NuruNuruNapi.create(secretKeyHex, dbPath)`.
+/// All methods that hit the network return Promises.
+#[napi]
+pub struct NuruNuruNapi {
+    engine: Arc<NuruNuruEngine>,
+}
+
+#[napi]
+impl NuruNuruNapi {
+    // ─── Lifecycle ────────────────────────────────────────────
+
+    /// Create a new engine instance (async factory).
+    ///
+    /// `secret_key_hex` — hex or nsec private key.
+    /// `db_path` — directory for nostrdb (e.g. `"./nurunuru-db"`).
+    #[napi(factory)]
+    pub async fn create(secret_key_hex: String, db_path: String) -> Result<Self> {
+        let keys = Keys::parse(&secret_key_hex).map_err(to_napi_err)?;
+
+        let mut config = NuruNuruConfig::default();
+        config.db_path = db_path;
+
+        let engine = NuruNuruEngine::new(keys, config)
+            .await
+            .map_err(to_napi_err)?;
+
+        Ok(Self { engine })
+    }
+
+    /// Connect to all configured relays.
+    #[napi]
+    pub async fn connect(&self) -> Result<()> {
+        let engine = self.engine.clone();
+        engine.connect().await;
+        Ok(())
+    }
+
+    /// Disconnect from all relays.
+    #[napi]
+    pub async fn disconnect(&self) -> Result<()> {
+        let engine = self.engine.clone();
+        engine.disconnect().await.map_err(to_napi_err)
+    }
+
+    /// Set the current user and load their follow/mute lists.
+    #[napi]
+    pub async fn login(&self, pubkey_hex: String) -> Result<()> {
+        let pk = PublicKey::from_hex(&pubkey_hex).map_err(to_napi_err)?;
+        let engine = self.engine.clone();
+        engine.login(pk).await.map_err(to_napi_err)
+    }
+
+    // ─── Profile ──────────────────────────────────────────────
+
+    /// Fetch a user profile (kind 0 metadata).
+    #[napi]
+    pub async fn fetch_profile(&self, pubkey_hex: String) -> Result<Option<NapiUserProfile>> {
+        let pk = PublicKey::from_hex(&pubkey_hex).map_err(to_napi_err)?;
+        let engine = self.engine.clone();
+        let profile = engine.fetch_profile(pk).await.map_err(to_napi_err)?;
+        Ok(profile.map(NapiUserProfile::from))
+    }
+
+    /// Batch-fetch user profiles. Returns a JSON string:
+    /// `{ [pubkeyHex]: { name, display_name, about, picture, banner, nip05, lud16, website, pubkey } }`.
+    ///
+    /// Called from `/api/profile/batch` to fetch multiple profiles in a single
+    /// relay subscription rather than N individual requests.
+    #[napi]
+    pub async fn fetch_profiles_json(&self, pubkey_hexes: Vec<String>) -> Result<String> {
+        let pubkeys: Vec<PublicKey> = pubkey_hexes
+            .iter()
+            .filter_map(|hex| PublicKey::from_hex(hex).ok())
+            .collect();
+        let engine = self.engine.clone();
+        let profiles = engine.fetch_profiles(&pubkeys).await.map_err(to_napi_err)?;
+        serde_json::to_string(&profiles).map_err(to_napi_err)
+    }
+
+    // ─── Follow List (NIP-02) ─────────────────────────────────
+
+    /// Fetch follow list. Returns pubkey hex strings.
+    #[napi]
+    pub async fn fetch_follow_list(&self, pubkey_hex: String) -> Result<Vec<String>> {
+        let pk = PublicKey::from_hex(&pubkey_hex).map_err(to_napi_err)?;
+        let engine = self.engine.clone();
+        engine.fetch_follow_list(pk).await.map_err(to_napi_err)
+    }
+
+    /// Fetch mute list (kind 10000, NIP-51). Returns pubkey hex strings.
+    ///
+    /// Note: Only returns muted pubkeys (`p` tags, plus private entries for
+    /// the logged-in user). For words, hashtags and threads use
+    /// `fetchFullMuteList`.
+    #[napi]
+    pub async fn fetch_mute_list(&self, pubkey_hex: String) -> Result<Vec<String>> {
+        let pk = PublicKey::from_hex(&pubkey_hex).map_err(to_napi_err)?;
+        let engine = self.engine.clone();
+        engine.fetch_mute_list(pk).await.map_err(to_napi_err)
+    }
+
+    /// Fetch the full mute list (pubkeys, words, hashtags, threads).
+    /// Private entries are decrypted for the logged-in user's own list.
+    #[napi]
+    pub async fn fetch_full_mute_list(&self, pubkey_hex: String) -> Result<NapiMuteList> {
+        let pk = PublicKey::from_hex(&pubkey_hex).map_err(to_napi_err)?;
+        let engine = self.engine.clone();
+        let list = engine.fetch_full_mute_list(pk).await.map_err(to_napi_err)?;
+        Ok(list.into())
+    }
+
+    /// Add an entry to the mute list and re-publish it.
+    ///
+    /// `kind`: `"pubkey" | "word" | "hashtag" | "thread"`.  `isPrivate`
+    /// stores the entry NIP-44 encrypted.
+    #[napi]
+    pub async fn mute(&self, kind: String, value: String, is_private: Option<bool>) -> Result<NapiMuteList> {
+        let kind = parse_mute_kind(&kind)?;
+        let engine = self.engine.clone();
+        let list = engine
+            .mute(kind, &value, is_private.unwrap_or(false))
+            .await
+            .map_err(to_napi_err)?;
+        Ok(list.into())
+    }
+
+    /// Remove an entry (public or private) from the mute list and re-publish it.
+    #[napi]
+    pub async fn unmute(&self, kind: String, value: String) -> Result<NapiMuteList> {
+        let kind = parse_mute_kind(&kind)?;
+        let engine = self.engine.clone();
+        let list = engine.unmute(kind, &value).await.map_err(to_napi_err)?;
+        Ok(list.into())
+    }
+
+    /// Follow a user (publishes updated kind 3).
+    #[napi]
+    pub async fn follow_user(&self, target_pubkey_hex: String) -> Result<()> {
+        let pk = PublicKey::from_hex(&target_pubkey_hex).map_err(to_napi_err)?;
+        let engine = self.engine.clone();
+        engine.follow_user(pk).await.map_err(to_napi_err)
+    }
+
+    /// Unfollow a user (publishes updated kind 3).
+    #[napi]
+    pub async fn unfollow_user(&self, target_pubkey_hex: String) -> Result<()> {
+        let pk = PublicKey::from_hex(&target_pubkey_hex).map_err(to_napi_err)?;
+        let engine = self.engine.clone();
+        engine.unfollow_user(pk).await.map_err(to_napi_err)
+    }
+
+    /// Follow / unfollow several users in one kind-3 update.
+    ///
+    /// Existing petnames, relay hints and content are kept.  Rejects when
+    /// the newest follow list cannot be confirmed from relays.
+    #[napi]
+    pub async fn update_follows(
+        &self,
+        follow_hex: Vec<String>,
+        unfollow_hex: Vec<String>,
+    ) -> Result<NapiFollowListUpdate> {
+        let parse = |list: Vec<String>| -> Result<Vec<PublicKey>> {
+            list.iter()
+                .map(|h| PublicKey::from_hex(h).map_err(to_napi_err))
+                .collect()
+        };
+        let follow = parse(follow_hex)?;
+        let unfollow = parse(unfollow_hex)?;
+        let engine = self.engine.clone();
+        let update = engine
+            .update_follows(&follow, &unfollow)
+            .await
+            .map_err(to_napi_err)?;
+        Ok(update.into())
+    }
+
+    /// Stored follow-list versions, newest first, with added / removed diffs.
+    #[napi]
+    pub async fn list_follow_list_versions(&self) -> Result<Vec<NapiFollowListVersion>> {
+        let engine = self.engine.clone();
+        let versions = engine
+            .list_follow_list_versions()
+            .await
+            .map_err(to_napi_err)?;
+        Ok(versions.into_iter().map(Into::into).collect())
+    }
+
+    /// Republish a stored follow-list version (e.g. "undo unfollow-all").
+    #[napi]
+    pub async fn restore_follow_list(&self, version_event_id: String) -> Result<NapiFollowListUpdate> {
+        let engine = self.engine.clone();
+        let update = engine
+            .restore_follow_list(&version_event_id)
+            .await
+            .map_err(to_napi_err)?;
+        Ok(update.into())
+    }
+
+    // ─── Bookmarks (NIP-51) ───────────────────────────────────
+
+    /// Fetch the default bookmark list (kind 10003).
+    #[napi]
+    pub async fn fetch_bookmarks(&self, pubkey_hex: String) -> Result<NapiBookmarkList> {
+        let pk = PublicKey::from_hex(&pubkey_hex).map_err(to_napi_err)?;
+        let engine = self.engine.clone();
+        let list = engine.fetch_bookmarks(pk).await.map_err(to_napi_err)?;
+        Ok(list.into())
+    }
+
+    /// Fetch named bookmark sets (kind 30003).
+    #[napi]
+    pub async fn fetch_bookmark_sets(&self, pubkey_hex: String) -> Result<Vec<NapiBookmarkList>> {
+        let pk = PublicKey::from_hex(&pubkey_hex).map_err(to_napi_err)?;
+        let engine = self.engine.clone();
+        let sets = engine.fetch_bookmark_sets(pk).await.map_err(to_napi_err)?;
+        Ok(sets.into_iter().map(Into::into).collect())
+    }
+
+    /// Add a bookmark.  `kind`: `"event" | "address" | "hashtag" | "url"`.
+    /// Pass `setIdentifier` to use a named set (created if missing).
+    #[napi]
+    pub async fn add_bookmark(
+        &self,
+        kind: String,
+        value: String,
+        is_private: Option<bool>,
+        set_identifier: Option<String>,
+    ) -> Result<NapiBookmarkList> {
+        let kind = parse_bookmark_kind(&kind)?;
+        let engine = self.engine.clone();
+        let list = engine
+            .add_bookmark(kind, &value, is_private.unwrap_or(false), set_identifier.as_deref())
+            .await
+            .map_err(to_napi_err)?;
+        Ok(list.into())
+    }
+
+    /// Remove a bookmark (public or private).
+    #[napi]
+    pub async fn remove_bookmark(
+        &self,
+        kind: String,
+        value: String,
+        set_identifier: Option<String>,
+    ) -> Result<NapiBookmarkList> {
+        let kind = parse_bookmark_kind(&kind)?;
+        let engine = self.engine.clone();
+        let list = engine
+            .remove_bookmark(kind, &value, set_identifier.as_deref())
+            .await
+            .map_err(to_napi_err)?;
+        Ok(list.into())
+    }
+
+    // ─── Feed & Timeline ──────────────────────────────────────
+
+    /// Get recommended feed (X-algorithm ranking). Returns scored posts.
+    #[napi]
+    pub async fn get_recommended_feed(&self, limit: u32) -> Result<Vec<NapiScoredPost>> {
+        let engine = self.engine.clone();
+        let scored = engine
+            .get_recommended_feed(limit as usize)
+            .await
+            .map_err(to_napi_err)?;
+        Ok(scored.into_iter().map(NapiScoredPost::from).collect())
+    }
+
+    /// Fetch timeline events. Returns JSON strings (one per event).
+    ///
+    /// `author_pubkeys` — optional pubkey hex filter.
+    /// `since_secs` — optional unix timestamp (seconds).
+    #[napi]
+    pub async fn fetch_timeline(
+        &self,
+        author_pubkeys: Option<Vec<String>>,
+        since_secs: Option<f64>,
+        limit: u32,
+    ) -> Result<Vec<String>> {
+        let authors: Option<Vec<PublicKey>> = author_pubkeys.map(|pks| {
+            pks.iter()
+                .filter_map(|hex| PublicKey::from_hex(hex).ok())
+                .collect()
+        });
+        let since = since_secs.map(|s| Timestamp::from(s as u64));
+        let engine = self.engine.clone();
+        let events = engine
+            .fetch_timeline(authors.as_deref(), since, limit as usize)
+            .await
+            .map_err(to_napi_err)?;
+        events
+            .iter()
+            .map(|e| serde_json::to_string(e).map_err(to_napi_err))
+            .collect()
+    }
+
+    /// Fetch engagement data (likes, reposts, replies, zaps) for events.
+    /// `event_id_hexes` — array of event ID hex strings.
+    /// Returns a JSON object `{ [eventIdHex]: NapiEngagementData }`.
+    #[napi]
+    pub async fn fetch_engagement_data(
+        &self,
+        event_id_hexes: Vec<String>,
+    ) -> Result<String> {
+        let event_ids: Vec<EventId> = event_id_hexes
+            .iter()
+            .filter_map(|hex| EventId::from_hex(hex).ok())
+            .collect();
+        let engine = self.engine.clone();
+        let data = engine
+            .fetch_engagement_data(&event_ids)
+            .await
+            .map_err(to_napi_err)?;
+        serde_json::to_string(&data).map_err(to_napi_err)
+    }
+
+    /// Per-emoji reactions with reactors, the user's own reaction and
+    /// zappers sorted by amount for one event.
+    #[napi]
+    pub async fn fetch_reaction_details(&self, event_id_hex: String) -> Result<NapiReactionDetails> {
+        let eid = EventId::from_hex(&event_id_hex).map_err(to_napi_err)?;
+        let engine = self.engine.clone();
+        let details = engine
+            .fetch_reaction_details(eid)
+            .await
+            .map_err(to_napi_err)?;
+        Ok(details.into())
+    }
+
+    // ─── Threads (NIP-10) ─────────────────────────────────────
+
+    /// Reconstruct the thread around an event (root, ancestors, all replies).
+    ///
+    /// Nodes are flattened depth-first; indent by `depth`.  With
+    /// `cacheOnly = true` only nostrdb is read (no relay round-trip).
+    #[napi]
+    pub async fn fetch_thread(
+        &self,
+        event_id_hex: String,
+        cache_only: Option<bool>,
+    ) -> Result<NapiThreadView> {
+        let eid = EventId::from_hex(&event_id_hex).map_err(to_napi_err)?;
+        let engine = self.engine.clone();
+        let view = engine
+            .fetch_thread(eid, cache_only.unwrap_or(false))
+            .await
+            .map_err(to_napi_err)?;
+        Ok(view.into())
+    }
+
+    // ─── Content Parsing ──────────────────────────────────────
+
+    /// Split an event's content into render segments (text, links, media,
+    /// `nostr:` references, hashtags, custom emoji, invoices).
+    #[napi]
+    pub fn parse_content(&self, event_json: String) -> Result<Vec<NapiContentSegment>> {
+        let event: Event = serde_json::from_str(&event_json).map_err(to_napi_err)?;
+        Ok(nurunuru_core::content::tokenize_event(&event)
+            .into_iter()
+            .map(Into::into)
+            .collect())
+    }
+
+    /// `true` if the event carries the NIP-70 `["-"]` (protected) tag.
+    #[napi]
+    pub fn is_protected_event(&self, event_json: String) -> Result<bool> {
+        let event: Event = serde_json::from_str(&event_json).map_err(to_napi_err)?;
+        Ok(nurunuru_core::tags::is_protected(&event))
+    }
+
+    // ─── Publishing ───────────────────────────────────────────
+
+    /// Publish a text note (kind 1). Returns event ID hex.
+    #[napi]
+    pub async fn publish_note(&self, content: String) -> Result<String> {
+        let engine = self.engine.clone();
+        let eid = engine
+            .publish_note(&content, vec![])
+            .await
+            .map_err(to_napi_err)?;
+        Ok(eid.to_hex())
+    }
+
+    /// Publish a text note (kind 1) with extra tags and options.
+    ///
+    /// `options.autoTag` adds `t` / `p` / `q` / `emoji` tags implied by the
+    /// content. Returns event ID hex.
+    #[napi]
+    pub async fn publish_note_with_options(
+        &self,
+        content: String,
+        tags: Option<Vec<Vec<String>>>,
+        options: Option<NapiNoteOptions>,
+    ) -> Result<String> {
+        let tags: Vec<Tag> = tags
+            .unwrap_or_default()
+            .into_iter()
+            .filter_map(|t| Tag::parse(t).ok())
+            .collect();
+        let options: NoteOptions = options.map(Into::into).unwrap_or_default();
+        let engine = self.engine.clone();
+        let eid = engine
+            .publish_note_with_options(&content, tags, &options)
+            .await
+            .map_err(to_napi_err)?;
+        Ok(eid.to_hex())
+    }
+
+    /// Publish a NIP-70 protected note (`["-"]` tag) to the relays among
+    /// `relayUrls` (empty = all) whose NIP-11 document lists NIP-70 and
+    /// NIP-42.  Reports accepted, rejected and skipped relays.
+    ///
+    /// The engine does not fetch NIP-11 itself: call `setRelayInformation`
+    /// for each relay first, or every relay is skipped.
+    #[napi]
+    pub async fn publish_protected_note(
+        &self,
+        content: String,
+        tags: Option<Vec<Vec<String>>>,
+        relay_urls: Option<Vec<String>>,
+    ) -> Result<NapiProtectedPublishResult> {
+        let tags: Vec<Tag> = tags
+            .unwrap_or_default()
+            .into_iter()
+            .filter_map(|t| Tag::parse(t).ok())
+            .collect();
+        let engine = self.engine.clone();
+        let result = engine
+            .publish_protected_note(&content, tags, relay_urls.unwrap_or_default())
+            .await
+            .map_err(to_napi_err)?;
+        Ok(result.into())
+    }
+
+    /// Build an **unsigned** protected note for browser signing (NIP-07);
+    /// publish the signed event with `publishSignedProtectedEvent`.
+    #[napi]
+    pub fn build_protected_note(
+        &self,
+        pubkey_hex: String,
+        content: String,
+        tags: Option<Vec<Vec<String>>>,
+    ) -> Result<String> {
+        let pk = PublicKey::from_hex(&pubkey_hex).map_err(to_napi_err)?;
+        let tags: Vec<Tag> = tags
+            .unwrap_or_default()
+            .into_iter()
+            .filter_map(|t| Tag::parse(t).ok())
+            .collect();
+        let unsigned = self.engine.build_protected_note(pk, &content, tags);
+        serde_json::to_string(&unsigned).map_err(to_napi_err)
+    }
+
+    /// Publish a browser-signed protected note to the same relays as
+    /// `publishProtectedNote` (same `setRelayInformation` requirement).
+    #[napi]
+    pub async fn publish_signed_protected_event(
+        &self,
+        event_json: String,
+        relay_urls: Option<Vec<String>>,
+    ) -> Result<NapiProtectedPublishResult> {
+        let event: Event = Event::from_json(&event_json).map_err(to_napi_err)?;
+        let engine = self.engine.clone();
+        let result = engine
+            .publish_protected_event(event, relay_urls.unwrap_or_default())
+            .await
+            .map_err(to_napi_err)?;
+        Ok(result.into())
+    }
+
+    /// Build an **unsigned** text note for browser signing (NIP-07), with
+    /// the same options as `publishNoteWithOptions`.  Returns the unsigned
+    /// event JSON.
+    #[napi]
+    pub async fn build_note(
+        &self,
+        pubkey_hex: String,
+        content: String,
+        tags: Option<Vec<Vec<String>>>,
+        options: Option<NapiNoteOptions>,
+    ) -> Result<String> {
+        let pk = PublicKey::from_hex(&pubkey_hex).map_err(to_napi_err)?;
+        let tags: Vec<Tag> = tags
+            .unwrap_or_default()
+            .into_iter()
+            .filter_map(|t| Tag::parse(t).ok())
+            .collect();
+        let options: NoteOptions = options.map(Into::into).unwrap_or_default();
+        let engine = self.engine.clone();
+        let unsigned = engine.build_note(pk, &content, tags, &options).await;
+        serde_json::to_string(&unsigned).map_err(to_napi_err)
+    }
+
+    /// Reply to an event (NIP-10). Takes the full parent event JSON.
+    /// Returns the reply event ID hex.
+    #[napi]
+    pub async fn publish_reply(&self, parent_event_json: String, content: String) -> Result<String> {
+        let parent: Event = Event::from_json(&parent_event_json).map_err(to_napi_err)?;
+        let engine = self.engine.clone();
+        let eid = engine
+            .publish_reply(&parent, &content)
+            .await
+            .map_err(to_napi_err)?;
+        Ok(eid.to_hex())
+    }
+
+    /// Quote an event (NIP-18). Takes the full target event JSON.
+    /// Returns the quote event ID hex.
+    #[napi]
+    pub async fn publish_quote(&self, target_event_json: String, content: String) -> Result<String> {
+        let target: Event = Event::from_json(&target_event_json).map_err(to_napi_err)?;
+        let engine = self.engine.clone();
+        let eid = engine
+            .publish_quote(&target, &content)
+            .await
+            .map_err(to_napi_err)?;
+        Ok(eid.to_hex())
+    }
+
+    /// Build an **unsigned** reply (NIP-10) for browser signing (NIP-07).
+    /// Returns the unsigned event JSON.
+    #[napi]
+    pub async fn build_reply(&self, parent_event_json: String, content: String) -> Result<String> {
+        let parent: Event = Event::from_json(&parent_event_json).map_err(to_napi_err)?;
+        let engine = self.engine.clone();
+        let unsigned = engine
+            .build_reply(&parent, &content)
+            .await
+            .map_err(to_napi_err)?;
+        serde_json::to_string(&unsigned).map_err(to_napi_err)
+    }
+
+    /// Build an **unsigned** quote (NIP-18) for browser signing (NIP-07).
+    /// Returns the unsigned event JSON.
+    #[napi]
+    pub async fn build_quote(&self, target_event_json: String, content: String) -> Result<String> {
+        let target: Event = Event::from_json(&target_event_json).map_err(to_napi_err)?;
+        let engine = self.engine.clone();
+        let unsigned = engine
+            .build_quote(&target, &content)
+            .await
+            .map_err(to_napi_err)?;
+        serde_json::to_string(&unsigned).map_err(to_napi_err)
+    }
+
+    /// React to an event (NIP-25). Returns reaction event ID hex.
+    #[napi]
+    pub async fn react(
+        &self,
+        event_id_hex: String,
+        author_pubkey_hex: String,
+        reaction: String,
+    ) -> Result<String> {
+        let eid = EventId::from_hex(&event_id_hex).map_err(to_napi_err)?;
+        let pk = PublicKey::from_hex(&author_pubkey_hex).map_err(to_napi_err)?;
+        let engine = self.engine.clone();
+        let result = engine.react(eid, pk, &reaction).await.map_err(to_napi_err)?;
+        Ok(result.to_hex())
+    }
+
+    /// React to an event of any kind (NIP-25) with its real kind, `a` tag
+    /// and relay hint.  `:shortcode:` reactions take `emojiUrl` or resolve it
+    /// from the user's emoji list.  Returns the reaction event ID hex.
+    #[napi]
+    pub async fn react_to_event(
+        &self,
+        target_event_json: String,
+        reaction: String,
+        emoji_url: Option<String>,
+    ) -> Result<String> {
+        let target: Event = Event::from_json(&target_event_json).map_err(to_napi_err)?;
+        let engine = self.engine.clone();
+        let eid = engine
+            .react_to_event(&target, &reaction, emoji_url.as_deref())
+            .await
+            .map_err(to_napi_err)?;
+        Ok(eid.to_hex())
+    }
+
+    /// Build an **unsigned** reaction for browser signing (NIP-07).
+    /// Returns the unsigned event JSON.
+    #[napi]
+    pub async fn build_reaction(
+        &self,
+        target_event_json: String,
+        reaction: String,
+        emoji_url: Option<String>,
+    ) -> Result<String> {
+        let target: Event = Event::from_json(&target_event_json).map_err(to_napi_err)?;
+        let engine = self.engine.clone();
+        let unsigned = engine
+            .build_reaction(&target, &reaction, emoji_url.as_deref())
+            .await
+            .map_err(to_napi_err)?;
+        serde_json::to_string(&unsigned).map_err(to_napi_err)
+    }
+
+    /// Build an **unsigned** repost (kind 6, or kind 16 for other kinds) for
+    /// browser signing (NIP-07).  Returns the unsigned event JSON.
+    #[napi]
+    pub async fn build_repost(&self, target_event_json: String) -> Result<String> {
+        let target: Event = Event::from_json(&target_event_json).map_err(to_napi_err)?;
+        let engine = self.engine.clone();
+        let unsigned = engine.build_repost(&target).await.map_err(to_napi_err)?;
+        serde_json::to_string(&unsigned).map_err(to_napi_err)
+    }
+
+    /// Repost an event (NIP-18; kind 16 for non-kind-1 targets). Takes full event JSON. Returns repost event ID hex.
+    #[napi]
+    pub async fn repost(&self, event_json: String) -> Result<String> {
+        let event: Event = Event::from_json(&event_json).map_err(to_napi_err)?;
+        let engine = self.engine.clone();
+        let result = engine.repost(&event).await.map_err(to_napi_err)?;
+        Ok(result.to_hex())
+    }
+
+    /// Undo the user's reaction to an event. Returns deletion event ID hex.
+    #[napi]
+    pub async fn unreact(&self, event_id_hex: String) -> Result<String> {
+        let eid = EventId::from_hex(&event_id_hex).map_err(to_napi_err)?;
+        let engine = self.engine.clone();
+        let result = engine.unreact(eid).await.map_err(to_napi_err)?;
+        Ok(result.to_hex())
+    }
+
+    /// Undo the user's repost of an event. Returns deletion event ID hex.
+    #[napi]
+    pub async fn unrepost(&self, event_id_hex: String) -> Result<String> {
+        let eid = EventId::from_hex(&event_id_hex).map_err(to_napi_err)?;
+        let engine = self.engine.clone();
+        let result = engine.unrepost(eid).await.map_err(to_napi_err)?;
+        Ok(result.to_hex())
+    }
+
+    /// Delete an event (NIP-09). Returns deletion event ID hex.
+    #[napi]
+    pub async fn delete_event(
+        &self,
+        event_id_hex: String,
+        reason: Option<String>,
+    ) -> Result<String> {
+        let eid = EventId::from_hex(&event_id_hex).map_err(to_napi_err)?;
+        let engine = self.engine.clone();
+        let result = engine
+            .delete_event(eid, reason.as_deref())
+            .await
+            .map_err(to_napi_err)?;
+        Ok(result.to_hex())
+    }
+
+    /// Delete several events and / or `kind:pubkey:d` coordinates in one
+    /// NIP-09 request. Returns deletion event ID hex.
+    #[napi]
+    pub async fn delete_events(
+        &self,
+        event_id_hexes: Vec<String>,
+        coordinates: Option<Vec<String>>,
+        reason: Option<String>,
+    ) -> Result<String> {
+        let ids = event_id_hexes
+            .iter()
+            .map(|hex| EventId::from_hex(hex).map_err(to_napi_err))
+            .collect::<Result<Vec<_>>>()?;
+        let coordinates = coordinates
+            .unwrap_or_default()
+            .iter()
+            .map(|c| Coordinate::parse(c).map_err(to_napi_err))
+            .collect::<Result<Vec<_>>>()?;
+        let engine = self.engine.clone();
+        let result = engine
+            .delete_events(&ids, &coordinates, reason.as_deref())
+            .await
+            .map_err(to_napi_err)?;
+        Ok(result.to_hex())
+    }
+
+    /// Publish an already-signed Nostr event to all connected relays.
+    ///
+    /// `event_json` — full NIP-01 signed event as a JSON string (must include `sig`).
+    ///
+    /// The Rust engine validates the signature before broadcasting.
+    /// Returns the event ID hex on success.
+    ///
+    /// Called from `/api/publish` to broadcast browser-signed events via the
+    /// Rust relay pool without exposing the user's private key to the server.
+    #[napi]
+    pub async fn publish_event(&self, event_json: String) -> Result<String> {
+        let event: Event = Event::from_json(&event_json).map_err(to_napi_err)?;
+        let engine = self.engine.clone();
+        let eid = engine.publish_raw_event(event).await.map_err(to_napi_err)?;
+        Ok(eid.to_hex())
+    }
+
+    // ─── Scheduled Posts ──────────────────────────────────────
+
+    /// Schedule a kind-1 note to be signed and published at `publish_at`
+    /// (unix seconds). The engine signs when the post falls due.
+    ///
+    /// `tags` — list of tag arrays: `[["t","nostr"],["p","<pk>"]]`
+    #[napi]
+    pub async fn schedule_note(
+        &self,
+        content: String,
+        tags: Vec<Vec<String>>,
+        publish_at: f64,
+    ) -> Result<NapiScheduledPost> {
+        let tags: Vec<Tag> = tags.into_iter().filter_map(|t| Tag::parse(t).ok()).collect();
+        let engine = self.engine.clone();
+        let post = engine
+            .schedule_post(Kind::TextNote, &content, tags, Timestamp::from(publish_at as u64))
+            .await
+            .map_err(to_napi_err)?;
+        Ok(post.into())
+    }
+
+    /// Schedule an already-signed event JSON (NIP-07) to be broadcast at
+    /// `publish_at` (unix seconds). The signature is verified up front.
+    #[napi]
+    pub async fn schedule_signed_event(
+        &self,
+        event_json: String,
+        publish_at: f64,
+    ) -> Result<NapiScheduledPost> {
+        let event: Event = Event::from_json(&event_json).map_err(to_napi_err)?;
+        let engine = self.engine.clone();
+        let post = engine
+            .schedule_signed_event(event, Timestamp::from(publish_at as u64))
+            .await
+            .map_err(to_napi_err)?;
+        Ok(post.into())
+    }
+
+    /// List scheduled posts ordered by publish time.
+    #[napi]
+    pub async fn list_scheduled_posts(&self) -> Result<Vec<NapiScheduledPost>> {
+        let engine = self.engine.clone();
+        let posts = engine.list_scheduled_posts().await;
+        Ok(posts.into_iter().map(NapiScheduledPost::from).collect())
+    }
+
+    /// Cancel a pending scheduled post.
+    #[napi]
+    pub async fn cancel_scheduled_post(&self, id: String) -> Result<()> {
+        let engine = self.engine.clone();
+        engine.cancel_scheduled_post(&id).await.map_err(to_napi_err)
+    }
+
+    /// Move a pending or failed scheduled post to a new publish time.
+    #[napi]
+    pub async fn reschedule_post(&self, id: String, publish_at: f64) -> Result<NapiScheduledPost> {
+        let engine = self.engine.clone();
+        let post = engine
+            .reschedule_post(&id, Timestamp::from(publish_at as u64))
+            .await
+            .map_err(to_napi_err)?;
+        Ok(post.into())
+    }
+
+    /// Publish all due scheduled posts now. Returns how many were published.
+    #[napi]
+    pub async fn publish_due_scheduled_posts(&self) -> Result<u32> {
+        let engine = self.engine.clone();
+        let count = engine
+            .publish_due_scheduled_posts()
+            .await
+            .map_err(to_napi_err)?;
+        Ok(count as u32)
+    }
+
+    // ─── Notifications ────────────────────────────────────────
+
+    /// Grouped notifications between `sinceSecs` and `untilSecs`.
+    #[napi]
+    pub async fn fetch_notifications(
+        &self,
+        since_secs: Option<f64>,
+        until_secs: Option<f64>,
+        limit: u32,
+    ) -> Result<NapiNotificationPage> {
+        let since = since_secs.map(|s| Timestamp::from(s as u64));
+        let until = until_secs.map(|s| Timestamp::from(s as u64));
+        let engine = self.engine.clone();
+        let page = engine
+            .fetch_notifications(since, until, limit as usize)
+            .await
+            .map_err(to_napi_err)?;
+        Ok(page.into())
+    }
+
+    /// Notifications since the previous call, for polling.
+    #[napi]
+    pub async fn fetch_new_notifications(&self, limit: u32) -> Result<NapiNotificationPage> {
+        let engine = self.engine.clone();
+        let page = engine
+            .fetch_new_notifications(limit as usize)
+            .await
+            .map_err(to_napi_err)?;
+        Ok(page.into())
+    }
+
+    /// Mark notification events as read.
+    #[napi]
+    pub async fn mark_notifications_read(&self, event_ids: Vec<String>) -> Result<()> {
+        let engine = self.engine.clone();
+        engine
+            .mark_notifications_read(&event_ids)
+            .await
+            .map_err(to_napi_err)
+    }
+
+    /// Mark every notification up to now as read.
+    #[napi]
+    pub async fn mark_all_notifications_read(&self) -> Result<()> {
+        let engine = self.engine.clone();
+        engine
+            .mark_all_notifications_read()
+            .await
+            .map_err(to_napi_err)
+    }
+
+    // ─── Reports (NIP-56) ─────────────────────────────────────
+
+    /// Report an event by `authorPubkeyHex`.  `reportType` is `nudity`,
+    /// `malware`, `profanity`, `illegal`, `spam`, `impersonation` or
+    /// `other`.  Returns the report event ID hex.
+    #[napi]
+    pub async fn report_event(
+        &self,
+        event_id_hex: String,
+        author_pubkey_hex: String,
+        report_type: String,
+        reason: Option<String>,
+    ) -> Result<String> {
+        let event = EventId::from_hex(&event_id_hex).map_err(to_napi_err)?;
+        let author = PublicKey::from_hex(&author_pubkey_hex).map_err(to_napi_err)?;
+        let report_type = parse_report_type(&report_type)?;
+        let engine = self.engine.clone();
+        let eid = engine
+            .report_event(event, author, report_type, reason.as_deref().unwrap_or(""))
+            .await
+            .map_err(to_napi_err)?;
+        Ok(eid.to_hex())
+    }
+
+    /// Report an account.  Returns the report event ID hex.
+    #[napi]
+    pub async fn report_user(
+        &self,
+        pubkey_hex: String,
+        report_type: String,
+        reason: Option<String>,
+    ) -> Result<String> {
+        let pk = PublicKey::from_hex(&pubkey_hex).map_err(to_napi_err)?;
+        let report_type = parse_report_type(&report_type)?;
+        let engine = self.engine.clone();
+        let eid = engine
+            .report_user(pk, report_type, reason.as_deref().unwrap_or(""))
+            .await
+            .map_err(to_napi_err)?;
+        Ok(eid.to_hex())
+    }
+
+    /// Build an **unsigned** report of an account (or of one of its
+    /// events) for browser signing (NIP-07).
+    #[napi]
+    pub fn build_report(
+        &self,
+        pubkey_hex: String,
+        target_pubkey_hex: String,
+        event_id_hex: Option<String>,
+        report_type: String,
+        reason: Option<String>,
+    ) -> Result<String> {
+        let pk = PublicKey::from_hex(&pubkey_hex).map_err(to_napi_err)?;
+        let target = PublicKey::from_hex(&target_pubkey_hex).map_err(to_napi_err)?;
+        let event = event_id_hex
+            .map(|hex| EventId::from_hex(&hex).map_err(to_napi_err))
+            .transpose()?;
+        let report_type = parse_report_type(&report_type)?;
+        let unsigned = self.engine.build_report(
+            pk,
+            target,
+            event,
+            report_type,
+            reason.as_deref().unwrap_or(""),
+        );
+        serde_json::to_string(&unsigned).map_err(to_napi_err)
+    }
+
+    // ─── Community Notes (NIP-32) ─────────────────────────────
+
+    /// Community notes on events, one summary per event that has any.
+    /// `shownNoteId` is the note to display under the event.
+    #[napi]
+    pub async fn fetch_birdwatch_notes(
+        &self,
+        event_id_hexes: Vec<String>,
+        cache_only: Option<bool>,
+    ) -> Result<Vec<NapiBirdwatchSummary>> {
+        let event_ids: Vec<EventId> = event_id_hexes
+            .iter()
+            .filter_map(|hex| EventId::from_hex(hex).ok())
+            .collect();
+        let engine = self.engine.clone();
+        let summaries = engine
+            .fetch_birdwatch_notes(&event_ids, cache_only.unwrap_or(false))
+            .await
+            .map_err(to_napi_err)?;
+        Ok(summaries.into_iter().map(Into::into).collect())
+    }
+
+    /// Write a community note on an event.  Unknown contexts fall back to
+    /// `missing_context`, as in `lib/nostr.js`.
+    #[napi]
+    pub async fn create_birdwatch_note(
+        &self,
+        target_event_id_hex: String,
+        context: String,
+        content: String,
+        source_url: Option<String>,
+    ) -> Result<NapiBirdwatchNote> {
+        let target = EventId::from_hex(&target_event_id_hex).map_err(to_napi_err)?;
+        let context = BirdwatchContext::parse(&context).unwrap_or_default();
+        let engine = self.engine.clone();
+        let note = engine
+            .create_birdwatch_note(target, context, &content, source_url.as_deref())
+            .await
+            .map_err(to_napi_err)?;
+        Ok(note.into())
+    }
+
+    /// Build an **unsigned** community note for browser signing (NIP-07).
+    #[napi]
+    pub async fn build_birdwatch_note(
+        &self,
+        pubkey_hex: String,
+        target_event_id_hex: String,
+        context: String,
+        content: String,
+        source_url: Option<String>,
+    ) -> Result<String> {
+        let pk = PublicKey::from_hex(&pubkey_hex).map_err(to_napi_err)?;
+        let target = EventId::from_hex(&target_event_id_hex).map_err(to_napi_err)?;
+        let context = BirdwatchContext::parse(&context).unwrap_or_default();
+        let engine = self.engine.clone();
+        let unsigned = engine
+            .build_birdwatch_note(pk, target, context, &content, source_url.as_deref())
+            .await
+            .map_err(to_napi_err)?;
+        serde_json::to_string(&unsigned).map_err(to_napi_err)
+    }
+
+    /// Rate a community note `helpful` or `not_helpful`.  Returns the
+    /// rating event ID hex.
+    #[napi]
+    pub async fn rate_birdwatch_note(&self, note_event_id_hex: String, rating: String) -> Result<String> {
+        let note = EventId::from_hex(&note_event_id_hex).map_err(to_napi_err)?;
+        let rating = BirdwatchRating::parse(&rating)
+            .ok_or_else(|| to_napi_err(format!("unknown rating: {rating}")))?;
+        let engine = self.engine.clone();
+        let eid = engine
+            .rate_birdwatch_note(note, rating)
+            .await
+            .map_err(to_napi_err)?;
+        Ok(eid.to_hex())
+    }
+
+    /// Build an **unsigned** rating for browser signing (NIP-07).
+    #[napi]
+    pub async fn build_birdwatch_rating(
+        &self,
+        pubkey_hex: String,
+        note_event_id_hex: String,
+        rating: String,
+    ) -> Result<String> {
+        let pk = PublicKey::from_hex(&pubkey_hex).map_err(to_napi_err)?;
+        let note = EventId::from_hex(&note_event_id_hex).map_err(to_napi_err)?;
+        let rating = BirdwatchRating::parse(&rating)
+            .ok_or_else(|| to_napi_err(format!("unknown rating: {rating}")))?;
+        let engine = self.engine.clone();
+        let unsigned = engine
+            .build_birdwatch_rating(pk, note, rating)
+            .await
+            .map_err(to_napi_err)?;
+        serde_json::to_string(&unsigned).map_err(to_napi_err)
+    }
+
+    // ─── Long-form Articles (NIP-23) ──────────────────────────
+
+    /// Publish a new article, or replace the one named `input.identifier`.
+    #[napi]
+    pub async fn publish_article(&self, input: NapiArticleInput) -> Result<NapiArticle> {
+        let engine = self.engine.clone();
+        let article = engine
+            .publish_article(&input.into())
+            .await
+            .map_err(to_napi_err)?;
+        Ok(article.into())
+    }
+
+    /// Save (or replace) a kind-30024 draft.
+    #[napi]
+    pub async fn save_article_draft(&self, input: NapiArticleInput) -> Result<NapiArticle> {
+        let engine = self.engine.clone();
+        let article = engine
+            .save_article_draft(&input.into())
+            .await
+            .map_err(to_napi_err)?;
+        Ok(article.into())
+    }
+
+    /// Build an **unsigned** article (or draft) for browser signing (NIP-07).
+    /// Returns the unsigned event JSON.
+    #[napi]
+    pub async fn build_article(
+        &self,
+        pubkey_hex: String,
+        input: NapiArticleInput,
+        draft: Option<bool>,
+    ) -> Result<String> {
+        let pk = PublicKey::from_hex(&pubkey_hex).map_err(to_napi_err)?;
+        let engine = self.engine.clone();
+        let unsigned = engine
+            .build_article(pk, &input.into(), draft.unwrap_or(false))
+            .await
+            .map_err(to_napi_err)?;
+        serde_json::to_string(&unsigned).map_err(to_napi_err)
+    }
+
+    /// Resolve an `naddr` to the newest version of its article.
+    #[napi]
+    pub async fn fetch_article(
+        &self,
+        naddr: String,
+        cache_only: Option<bool>,
+    ) -> Result<Option<NapiArticle>> {
+        let engine = self.engine.clone();
+        let article = engine
+            .fetch_article(&naddr, cache_only.unwrap_or(false))
+            .await
+            .map_err(to_napi_err)?;
+        Ok(article.map(Into::into))
+    }
+
+    /// A user's articles, newest first.
+    #[napi]
+    pub async fn fetch_articles(
+        &self,
+        pubkey_hex: String,
+        limit: u32,
+        cache_only: Option<bool>,
+    ) -> Result<Vec<NapiArticle>> {
+        let pk = PublicKey::from_hex(&pubkey_hex).map_err(to_napi_err)?;
+        let engine = self.engine.clone();
+        let articles = engine
+            .fetch_articles(pk, limit as usize, cache_only.unwrap_or(false))
+            .await
+            .map_err(to_napi_err)?;
+        Ok(articles.into_iter().map(Into::into).collect())
+    }
+
+    /// The logged-in user's article drafts, newest first.
+    #[napi]
+    pub async fn fetch_article_drafts(&self, limit: u32) -> Result<Vec<NapiArticle>> {
+        let engine = self.engine.clone();
+        let drafts = engine
+            .fetch_article_drafts(limit as usize)
+            .await
+            .map_err(to_napi_err)?;
+        Ok(drafts.into_iter().map(Into::into).collect())
+    }
+
+    // ─── DMs (NIP-17) ─────────────────────────────────────────
+
+    /// Send an encrypted DM via NIP-17 gift wrapping.
+    #[napi]
+    pub async fn send_dm(&self, recipient_hex: String, content: String) -> Result<()> {
+        let pk = PublicKey::from_hex(&recipient_hex).map_err(to_napi_err)?;
+        let engine = self.engine.clone();
+        engine.send_dm(pk, &content).await.map_err(to_napi_err)
+    }
+
+    /// Send a NIP-17 DM that expires (NIP-40) `ttlSecs` from now.
+    #[napi]
+    pub async fn send_expiring_dm(
+        &self,
+        recipient_hex: String,
+        content: String,
+        ttl_secs: f64,
+    ) -> Result<()> {
+        let pk = PublicKey::from_hex(&recipient_hex).map_err(to_napi_err)?;
+        let engine = self.engine.clone();
+        engine
+            .send_expiring_dm(pk, &content, ttl_secs as u64)
+            .await
+            .map_err(to_napi_err)
+    }
+
+    /// Fetch DMs as JSON strings.
+    #[napi]
+    pub async fn fetch_dms(
+        &self,
+        since_secs: Option<f64>,
+        limit: u32,
+    ) -> Result<Vec<String>> {
+        let since = since_secs.map(|s| Timestamp::from(s as u64));
+        let engine = self.engine.clone();
+        let events = engine
+            .fetch_dms(since, limit as usize)
+            .await
+            .map_err(to_napi_err)?;
+        events
+            .iter()
+            .map(|e| serde_json::to_string(e).map_err(to_napi_err))
+            .collect()
+    }
 
-        let mut config = NuruNuruConfig::default();
-        config.db_path = db_path;
+    // ─── Live Activities (NIP-53) ─────────────────────────────
 
-        let engine = NuruNuruEngine::new(keys, config)
+    /// Live activities with a status in `statuses` (`planned` / `live` /
+    /// `ended`; all if omitted): live first, then planned, then ended.
+    #[napi]
+    pub async fn fetch_live_activities(
+        &self,
+        statuses: Option<Vec<String>>,
+        limit: u32,
+        cache_only: Option<bool>,
+    ) -> Result<Vec<NapiLiveActivity>> {
+        let statuses = statuses
+            .unwrap_or_default()
+            .iter()
+            .map(|s| {
+                LiveStatus::parse(s).ok_or_else(|| to_napi_err(format!("unknown live status: {s}")))
+            })
+            .collect::<Result<Vec<_>>>()?;
+        let engine = self.engine.clone();
+        let activities = engine
+            .fetch_live_activities(&statuses, limit as usize, cache_only.unwrap_or(false))
             .await
             .map_err(to_napi_err)?;
-
-        Ok(Self { engine })
+        Ok(activities.into_iter().map(Into::into).collect())
     }
 
-    /// Connect to all configured relays.
+    /// The activity at `coordinate` (`30311:<pubkey>:<d>`).
     #[napi]
-    pub async fn connect(&self) -> Result<()> {
+    pub async fn fetch_live_activity(
+        &self,
+        coordinate: String,
+        cache_only: Option<bool>,
+    ) -> Result<Option<NapiLiveActivity>> {
         let engine = self.engine.clone();
-        engine.connect().await;
-        Ok(())
+        let activity = engine
+            .fetch_live_activity(&coordinate, cache_only.unwrap_or(false))
+            .await
+            .map_err(to_napi_err)?;
+        Ok(activity.map(Into::into))
     }
 
-    /// Disconnect from all relays.
+    /// Recent chat of an activity, oldest first.
     #[napi]
-    pub async fn disconnect(&self) -> Result<()> {
+    pub async fn fetch_live_chat(
+        &self,
+        coordinate: String,
+        limit: u32,
+        cache_only: Option<bool>,
+    ) -> Result<Vec<NapiLiveChatMessage>> {
         let engine = self.engine.clone();
-        engine.disconnect().await.map_err(to_napi_err)
+        let messages = engine
+            .fetch_live_chat(&coordinate, limit as usize, cache_only.unwrap_or(false))
+            .await
+            .map_err(to_napi_err)?;
+        Ok(messages.into_iter().map(Into::into).collect())
     }
 
-    /// Set the current user and load their follow/mute lists.
+    /// Subscribe to new chat messages of an activity.  Returns a
+    /// subscription ID for `pollLiveChat` / `unsubscribeStream`.
     #[napi]
-    pub async fn login(&self, pubkey_hex: String) -> Result<()> {
-        let pk = PublicKey::from_hex(&pubkey_hex).map_err(to_napi_err)?;
+    pub async fn subscribe_live_chat(&self, coordinate: String) -> Result<String> {
         let engine = self.engine.clone();
-        engine.login(pk).await.map_err(to_napi_err)
+        engine
+            .subscribe_live_chat(&coordinate)
+            .await
+            .map_err(to_napi_err)
     }
 
-    // ─── Profile ──────────────────────────────────────────────
+    /// Drain up to `maxCount` buffered chat messages.
+    #[napi]
+    pub async fn poll_live_chat(
+        &self,
+        subscription_id: String,
+        max_count: u32,
+    ) -> Vec<NapiLiveChatMessage> {
+        let engine = self.engine.clone();
+        engine
+            .poll_live_chat(&subscription_id, max_count as usize)
+            .await
+            .into_iter()
+            .map(Into::into)
+            .collect()
+    }
 
-    /// Fetch a user profile (kind 0 metadata).
+    /// Post a chat message to an activity.  Returns the event ID hex.
     #[napi]
-    pub async fn fetch_profile(&self, pubkey_hex: String) -> Result<Option<NapiUserProfile>> {
-        let pk = PublicKey::from_hex(&pubkey_hex).map_err(to_napi_err)?;
+    pub async fn send_live_chat(&self, coordinate: String, content: String) -> Result<String> {
         let engine = self.engine.clone();
-        let profile = engine.fetch_profile(pk).await.map_err(to_napi_err)?;
-        Ok(profile.map(NapiUserProfile::from))
+        let eid = engine
+            .send_live_chat(&coordinate, &content)
+            .await
+            .map_err(to_napi_err)?;
+        Ok(eid.to_hex())
     }
 
-    /// Batch-fetch user profiles. Returns a JSON string:
-    /// `{ [pubkeyHex]: { name, display_name, about, picture, banner, nip05, lud16, website, pubkey } }`.
-    ///
-    /// Called from `/api/profile/batch` to fetch multiple profiles in a single
-    /// relay subscription rather than N individual requests.
+    /// Build an **unsigned** chat message for browser signing (NIP-07).
     #[napi]
-    pub async fn fetch_profiles_json(&self, pubkey_hexes: Vec<String>) -> Result<String> {
-        let pubkeys: Vec<PublicKey> = pubkey_hexes
-            .iter()
-            .filter_map(|hex| PublicKey::from_hex(hex).ok())
-            .collect();
+    pub async fn build_live_chat(
+        &self,
+        pubkey_hex: String,
+        coordinate: String,
+        content: String,
+    ) -> Result<String> {
+        let pk = PublicKey::from_hex(&pubkey_hex).map_err(to_napi_err)?;
         let engine = self.engine.clone();
-        let profiles = engine.fetch_profiles(&pubkeys).await.map_err(to_napi_err)?;
-        serde_json::to_string(&profiles).map_err(to_napi_err)
+        let unsigned = engine
+            .build_live_chat(pk, &coordinate, &content)
+            .await
+            .map_err(to_napi_err)?;
+        serde_json::to_string(&unsigned).map_err(to_napi_err)
     }
 
-    // ─── Follow List (NIP-02) ─────────────────────────────────
+    // ─── Badges (NIP-58) ──────────────────────────────────────
 
-    /// Fetch follow list. Returns pubkey hex strings.
+    /// Badges a user shows on their profile, in their order.  Entries whose
+    /// award is missing or forged are dropped.
     #[napi]
-    pub async fn fetch_follow_list(&self, pubkey_hex: String) -> Result<Vec<String>> {
+    pub async fn fetch_profile_badges(
+        &self,
+        pubkey_hex: String,
+        cache_only: Option<bool>,
+    ) -> Result<Vec<NapiBadge>> {
         let pk = PublicKey::from_hex(&pubkey_hex).map_err(to_napi_err)?;
         let engine = self.engine.clone();
-        engine.fetch_follow_list(pk).await.map_err(to_napi_err)
+        let list = engine
+            .fetch_profile_badges(pk, cache_only.unwrap_or(false))
+            .await
+            .map_err(to_napi_err)?;
+        Ok(list.into_iter().map(Into::into).collect())
     }
 
-    /// Fetch mute list (kind 10000, NIP-51). Returns pubkey hex strings.
-    ///
-    /// Note: Only returns muted pubkeys (`p` tags). For the full mute list
-    /// structure (eventIds, hashtags, words), use `queryLocal` with kind 10000
-    /// to inspect all tag types from the cached event.
+    /// Every verified badge awarded to a user, newest first.  `onProfile`
+    /// marks the ones already shown.
     #[napi]
-    pub async fn fetch_mute_list(&self, pubkey_hex: String) -> Result<Vec<String>> {
+    pub async fn fetch_received_badges(
+        &self,
+        pubkey_hex: String,
+        limit: u32,
+        cache_only: Option<bool>,
+    ) -> Result<Vec<NapiBadge>> {
         let pk = PublicKey::from_hex(&pubkey_hex).map_err(to_napi_err)?;
         let engine = self.engine.clone();
-        engine.fetch_mute_list(pk).await.map_err(to_napi_err)
+        let list = engine
+            .fetch_received_badges(pk, limit as usize, cache_only.unwrap_or(false))
+            .await
+            .map_err(to_napi_err)?;
+        Ok(list.into_iter().map(Into::into).collect())
     }
 
-    /// Follow a user (publishes updated kind 3).
+    /// Replace the profile badges with these awards, in order.  Used to
+    /// accept, reorder and remove badges.
     #[napi]
-    pub async fn follow_user(&self, target_pubkey_hex: String) -> Result<()> {
-        let pk = PublicKey::from_hex(&target_pubkey_hex).map_err(to_napi_err)?;
+    pub async fn set_profile_badges(&self, award_event_ids: Vec<String>) -> Result<Vec<NapiBadge>> {
         let engine = self.engine.clone();
-        engine.follow_user(pk).await.map_err(to_napi_err)
+        let list = engine
+            .set_profile_badges(&award_event_ids)
+            .await
+            .map_err(to_napi_err)?;
+        Ok(list.into_iter().map(Into::into).collect())
     }
 
-    /// Unfollow a user (publishes updated kind 3).
+    /// Add an award to the end of the profile badges.
     #[napi]
-    pub async fn unfollow_user(&self, target_pubkey_hex: String) -> Result<()> {
-        let pk = PublicKey::from_hex(&target_pubkey_hex).map_err(to_napi_err)?;
+    pub async fn accept_badge(&self, award_event_id: String) -> Result<Vec<NapiBadge>> {
         let engine = self.engine.clone();
-        engine.unfollow_user(pk).await.map_err(to_napi_err)
+        let list = engine
+            .accept_badge(&award_event_id)
+            .await
+            .map_err(to_napi_err)?;
+        Ok(list.into_iter().map(Into::into).collect())
     }
 
-    // ─── Feed & Timeline ──────────────────────────────────────
-
-    /// Get recommended feed (X-algorithm ranking). Returns scored posts.
+    /// Build an **unsigned** profile badges list for browser signing (NIP-07).
     #[napi]
-    pub async fn get_recommended_feed(&self, limit: u32) -> Result<Vec<NapiScoredPost>> {
+    pub async fn build_profile_badges(
+        &self,
+        pubkey_hex: String,
+        award_event_ids: Vec<String>,
+    ) -> Result<String> {
+        let pk = PublicKey::from_hex(&pubkey_hex).map_err(to_napi_err)?;
         let engine = self.engine.clone();
-        let scored = engine
-            .get_recommended_feed(limit as usize)
+        let unsigned = engine
+            .build_profile_badges(pk, &award_event_ids)
             .await
             .map_err(to_napi_err)?;
-        Ok(scored.into_iter().map(NapiScoredPost::from).collect())
+        serde_json::to_string(&unsigned).map_err(to_napi_err)
     }
 
-    /// Fetch timeline events. Returns JSON strings (one per event).
-    ///
-    /// `author_pubkeys` — optional pubkey hex filter.
-    /// `since_secs` — optional unix timestamp (seconds).
+    /// Create (or replace) a badge definition.
     #[napi]
-    pub async fn fetch_timeline(
+    pub async fn create_badge_definition(
         &self,
-        author_pubkeys: Option<Vec<String>>,
-        since_secs: Option<f64>,
-        limit: u32,
-    ) -> Result<Vec<String>> {
-        let authors: Option<Vec<PublicKey>> = author_pubkeys.map(|pks| {
-            pks.iter()
-                .filter_map(|hex| PublicKey::from_hex(hex).ok())
-                .collect()
-        });
-        let since = since_secs.map(|s| Timestamp::from(s as u64));
+        input: NapiBadgeDefinitionInput,
+    ) -> Result<NapiBadgeDefinition> {
         let engine = self.engine.clone();
-        let events = engine
-            .fetch_timeline(authors.as_deref(), since, limit as usize)
+        let definition = engine
+            .create_badge_definition(&input.into())
             .await
             .map_err(to_napi_err)?;
-        events
-            .iter()
-            .map(|e| serde_json::to_string(e).map_err(to_napi_err))
-            .collect()
+        Ok(definition.into())
     }
 
-    /// Fetch engagement data (likes, reposts, replies, zaps) for events.
-    /// `event_id_hexes` — array of event ID hex strings.
-    /// Returns a JSON object `{ [eventIdHex]: NapiEngagementData }`.
+    /// Build an **unsigned** badge definition for browser signing (NIP-07).
     #[napi]
-    pub async fn fetch_engagement_data(
+    pub async fn build_badge_definition(
         &self,
-        event_id_hexes: Vec<String>,
+        pubkey_hex: String,
+        input: NapiBadgeDefinitionInput,
     ) -> Result<String> {
-        let event_ids: Vec<EventId> = event_id_hexes
-            .iter()
-            .filter_map(|hex| EventId::from_hex(hex).ok())
-            .collect();
+        let pk = PublicKey::from_hex(&pubkey_hex).map_err(to_napi_err)?;
         let engine = self.engine.clone();
-        let data = engine
-            .fetch_engagement_data(&event_ids)
+        let unsigned = engine
+            .build_badge_definition(pk, &input.into())
             .await
             .map_err(to_napi_err)?;
-        serde_json::to_string(&data).map_err(to_napi_err)
+        serde_json::to_string(&unsigned).map_err(to_napi_err)
     }
 
-    // ─── Publishing ───────────────────────────────────────────
-
-    /// Publish a text note (kind 1). Returns event ID hex.
+    /// Award your badge at `coordinate` (`30009:<pubkey>:<d>`) to
+    /// `recipientHexes`.  Returns the event ID hex.
     #[napi]
-    pub async fn publish_note(&self, content: String) -> Result<String> {
+    pub async fn award_badge(&self, coordinate: String, recipient_hexes: Vec<String>) -> Result<String> {
+        let recipients = recipient_hexes
+            .iter()
+            .map(|hex| PublicKey::from_hex(hex).map_err(to_napi_err))
+            .collect::<Result<Vec<_>>>()?;
         let engine = self.engine.clone();
         let eid = engine
-            .publish_note(&content, vec![])
+            .award_badge(&coordinate, &recipients)
             .await
             .map_err(to_napi_err)?;
         Ok(eid.to_hex())
     }
 
-    /// React to an event (NIP-25). Returns reaction event ID hex.
+    /// Build an **unsigned** badge award for browser signing (NIP-07).
     #[napi]
-    pub async fn react(
+    pub async fn build_badge_award(
         &self,
-        event_id_hex: String,
-        author_pubkey_hex: String,
-        reaction: String,
+        pubkey_hex: String,
+        coordinate: String,
+        recipient_hexes: Vec<String>,
     ) -> Result<String> {
-        let eid = EventId::from_hex(&event_id_hex).map_err(to_napi_err)?;
-        let pk = PublicKey::from_hex(&author_pubkey_hex).map_err(to_napi_err)?;
+        let pk = PublicKey::from_hex(&pubkey_hex).map_err(to_napi_err)?;
+        let recipients = recipient_hexes
+            .iter()
+            .map(|hex| PublicKey::from_hex(hex).map_err(to_napi_err))
+            .collect::<Result<Vec<_>>>()?;
         let engine = self.engine.clone();
-        let result = engine.react(eid, pk, &reaction).await.map_err(to_napi_err)?;
-        Ok(result.to_hex())
+        let unsigned = engine
+            .build_badge_award(pk, &coordinate, &recipients)
+            .await
+            .map_err(to_napi_err)?;
+        serde_json::to_string(&unsigned).map_err(to_napi_err)
     }
 
-    /// Repost an event (NIP-18). Takes full event JSON. Returns repost event ID hex.
-    #[napi]
-    pub async fn repost(&self, event_json: String) -> Result<String> {
-        let event: Event = Event::from_json(&event_json).map_err(to_napi_err)?;
-        let engine = self.engine.clone();
-        let result = engine.repost(&event).await.map_err(to_napi_err)?;
-        Ok(result.to_hex())
-    }
+    // ─── Request to Vanish (NIP-62) ───────────────────────────
 
-    /// Delete an event (NIP-09). Returns deletion event ID hex.
+    /// Prepare a request asking `relayUrls` (empty = every relay) to delete
+    /// all of the user's events and return the confirmation summary.
+    /// Nothing is sent yet.
     #[napi]
-    pub async fn delete_event(
+    pub async fn prepare_vanish(
         &self,
-        event_id_hex: String,
+        relay_urls: Option<Vec<String>>,
         reason: Option<String>,
-    ) -> Result<String> {
-        let eid = EventId::from_hex(&event_id_hex).map_err(to_napi_err)?;
+    ) -> Result<NapiVanishPreview> {
         let engine = self.engine.clone();
-        let result = engine
-            .delete_event(eid, reason.as_deref())
+        let preview = engine
+            .prepare_vanish(&relay_urls.unwrap_or_default(), reason.as_deref().unwrap_or(""))
             .await
             .map_err(to_napi_err)?;
-        Ok(result.to_hex())
+        Ok(preview.into())
     }
 
-    /// Publish an already-signed Nostr event to all connected relays.
-    ///
-    /// `event_json` — full NIP-01 signed event as a JSON string (must include `sig`).
-    ///
-    /// The Rust engine validates the signature before broadcasting.
-    /// Returns the event ID hex on success.
-    ///
-    /// Called from `/api/publish` to broadcast browser-signed events via the
-    /// Rust relay pool without exposing the user's private key to the server.
+    /// Sign and send the prepared request `eventIdHex`, then purge the
+    /// user's events and MLS state locally.
     #[napi]
-    pub async fn publish_event(&self, event_json: String) -> Result<String> {
-        let event: Event = Event::from_json(&event_json).map_err(to_napi_err)?;
+    pub async fn confirm_vanish(&self, event_id_hex: String) -> Result<NapiVanishResult> {
+        let eid = EventId::from_hex(&event_id_hex).map_err(to_napi_err)?;
         let engine = self.engine.clone();
-        let eid = engine.publish_raw_event(event).await.map_err(to_napi_err)?;
-        Ok(eid.to_hex())
+        let result = engine.confirm_vanish(&eid).await.map_err(to_napi_err)?;
+        Ok(result.into())
     }
 
-    // ─── DMs (NIP-17) ─────────────────────────────────────────
-
-    /// Send an encrypted DM via NIP-17 gift wrapping.
+    /// Send the prepared request signed in the browser (NIP-07), then
+    /// purge locally.
     #[napi]
-    pub async fn send_dm(&self, recipient_hex: String, content: String) -> Result<()> {
-        let pk = PublicKey::from_hex(&recipient_hex).map_err(to_napi_err)?;
+    pub async fn publish_vanish_request(&self, event_json: String) -> Result<NapiVanishResult> {
+        let event: Event = Event::from_json(&event_json).map_err(to_napi_err)?;
         let engine = self.engine.clone();
-        engine.send_dm(pk, &content).await.map_err(to_napi_err)
+        let result = engine
+            .publish_vanish_request(event)
+            .await
+            .map_err(to_napi_err)?;
+        Ok(result.into())
     }
 
-    /// Fetch DMs as JSON strings.
-    #[napi]
-    pub async fn fetch_dms(
+    // ─── Event Backup ─────────────────────────────────────────
+
+    /// Every event by the user as JSONL (oldest first), paging through
+    /// nostrdb and each connected relay.  `kinds` empty = all.
+    /// `onProgress` is called after every page.
+    #[napi(ts_args_type = "kinds?: number[], sinceSecs?: number, untilSecs?: number, onProgress?: (progress: NapiBackupProgress) => void")]
+    pub async fn export_events(
         &self,
+        kinds: Option<Vec<u32>>,
         since_secs: Option<f64>,
-        limit: u32,
-    ) -> Result<Vec<String>> {
-        let since = since_secs.map(|s| Timestamp::from(s as u64));
+        until_secs: Option<f64>,
+        on_progress: Option<ThreadsafeFunction<NapiBackupProgress, ErrorStrategy::Fatal>>,
+    ) -> Result<String> {
+        let options = BackupOptions {
+            kinds: kinds.unwrap_or_default().into_iter().map(|k| k as u16).collect(),
+            since: since_secs.map(|s| s as u64),
+            until: until_secs.map(|s| s as u64),
+        };
         let engine = self.engine.clone();
         let events = engine
-            .fetch_dms(since, limit as usize)
+            .fetch_all_user_events(&options, |p| {
+                if let Some(f) = &on_progress {
+                    f.call(p.into(), ThreadsafeFunctionCallMode::NonBlocking);
+                }
+            })
             .await
             .map_err(to_napi_err)?;
-        events
-            .iter()
-            .map(|e| serde_json::to_string(e).map_err(to_napi_err))
-            .collect()
+        Ok(nurunuru_core::backup::to_jsonl(&events))
+    }
+
+    /// Import a backup (JSONL or a JSON array).  Events with a bad
+    /// signature are rejected.  `relayUrls` empty stores them in nostrdb;
+    /// otherwise they are rebroadcast to those relays.
+    #[napi(ts_args_type = "data: string, relayUrls?: string[], onProgress?: (progress: NapiBackupProgress) => void")]
+    pub async fn import_events(
+        &self,
+        data: String,
+        relay_urls: Option<Vec<String>>,
+        on_progress: Option<ThreadsafeFunction<NapiBackupProgress, ErrorStrategy::Fatal>>,
+    ) -> Result<NapiBackupImportResult> {
+        let engine = self.engine.clone();
+        let result = engine
+            .import_events(&data, &relay_urls.unwrap_or_default(), |p| {
+                if let Some(f) = &on_progress {
+                    f.call(p.into(), ThreadsafeFunctionCallMode::NonBlocking);
+                }
+            })
+            .await
+            .map_err(to_napi_err)?;
+        Ok(result.into())
+    }
+
+    // ─── Relay Migration ──────────────────────────────────────
+
+    /// Rebroadcast the user's history (`kinds` empty = all) to `url`,
+    /// skipping events it already has.  Rate limited and resumable.
+    #[napi(ts_args_type = "url: string, kinds?: number[], sinceSecs?: number, onProgress?: (progress: NapiMigrationProgress) => void")]
+    pub async fn migrate_history_to_relay(
+        &self,
+        url: String,
+        kinds: Option<Vec<u32>>,
+        since_secs: Option<f64>,
+        on_progress: Option<ThreadsafeFunction<NapiMigrationProgress, ErrorStrategy::Fatal>>,
+    ) -> Result<NapiMigrationReport> {
+        let kinds: Vec<u16> = kinds.unwrap_or_default().into_iter().map(|k| k as u16).collect();
+        let engine = self.engine.clone();
+        let report = engine
+            .migrate_history_to_relay(&url, &kinds, since_secs.map(|s| s as u64), |p| {
+                if let Some(f) = &on_progress {
+                    f.call(p.into(), ThreadsafeFunctionCallMode::NonBlocking);
+                }
+            })
+            .await
+            .map_err(to_napi_err)?;
+        Ok(report.into())
     }
 
     // ─── Search (NIP-50) ──────────────────────────────────────
@@ -487,6 +2666,44 @@ impl NuruNuruNapi {
         Ok(())
     }
 
+    /// How NIP-36 warned content is shown: `show`, `blur` or `hide`.
+    #[napi]
+    pub async fn content_warning_policy(&self) -> String {
+        let engine = self.engine.clone();
+        engine.content_warning_policy().await.as_str().to_string()
+    }
+
+    /// Set how NIP-36 warned content is shown (`show`, `blur` or `hide`);
+    /// `hide` also drops it from the recommended feed.
+    #[napi]
+    pub async fn set_content_warning_policy(&self, policy: String) -> Result<()> {
+        let policy = ContentWarningPolicy::parse(&policy)
+            .ok_or_else(|| to_napi_err(format!("unknown content warning policy: {policy}")))?;
+        let engine = self.engine.clone();
+        engine
+            .set_content_warning_policy(policy)
+            .await
+            .map_err(to_napi_err)
+    }
+
+    /// Report thresholds for the recommended feed and timelines.
+    #[napi]
+    pub async fn report_policy(&self) -> NapiReportPolicy {
+        let engine = self.engine.clone();
+        engine.report_policy().await.into()
+    }
+
+    /// Set how many reports from followed accounts down-rank / hide a
+    /// post (`0` turns a step off).
+    #[napi]
+    pub async fn set_report_policy(&self, policy: NapiReportPolicy) -> Result<()> {
+        let engine = self.engine.clone();
+        engine
+            .set_report_policy(policy.into())
+            .await
+            .map_err(to_napi_err)
+    }
+
     // ─── Custom Emoji (NIP-30) ────────────────────────────────
 
     /// Fetch custom emoji set. Returns array of `[shortcode, url]` pairs.
@@ -538,6 +2755,19 @@ impl NuruNuruNapi {
         engine.remove_relay(&url).await.map_err(to_napi_err)
     }
 
+    /// Record a relay's NIP-11 information document (JSON) for
+    /// capability-based relay selection, e.g. NIP-70 publishing.  The
+    /// engine never fetches NIP-11 itself, so call this for each relay
+    /// before publishing protected notes.
+    #[napi]
+    pub async fn set_relay_information(&self, url: String, nip11_json: String) -> Result<()> {
+        let engine = self.engine.clone();
+        engine
+            .set_relay_information(&url, &nip11_json)
+            .await
+            .map_err(to_napi_err)
+    }
+
     /// Disconnect then reconnect to all configured relays.
     #[napi]
     pub async fn reconnect(&self) -> Result<()> {